@@ -0,0 +1,79 @@
+//! Genera las tablas del analizador sintáctico a partir de `gramatica.txt`
+//!
+//! Escribe `$OUT_DIR/tabla_slr.rs`, que incluye `sintactico::tabla_slr`, así que
+//! cada `cargo build` usa la gramática actual. Un error de la gramática o un
+//! conflicto sin declarar con `%resolver` hace fallar la compilación; las
+//! advertencias de la validación salen como `warning` de cargo.
+//!
+//! `GRAMATICA_ALGORITMO` (`slr` por omisión, `lalr` o `lr1`) elige la construcción.
+
+// El módulo `gramatica` solo usa std, así que se compila tal cual dentro del script
+#[allow(dead_code)]
+#[path = "src/gramatica/mod.rs"]
+mod gramatica;
+
+use gramatica::escritor;
+use gramatica::slr::{self, Algoritmo};
+use gramatica::validacion::{self, Severidad};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::{env, fs, process};
+
+fn main() {
+    println!("cargo:rerun-if-changed=gramatica.txt");
+    println!("cargo:rerun-if-changed=src/gramatica");
+    println!("cargo:rerun-if-env-changed=GRAMATICA_ALGORITMO");
+
+    if let Err(e) = generar() {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+fn generar() -> Result<(), String> {
+    let algoritmo = match env::var("GRAMATICA_ALGORITMO") {
+        Ok(nombre) => Algoritmo::desde_nombre(&nombre)
+            .ok_or_else(|| format!("GRAMATICA_ALGORITMO='{}' no es slr, lalr ni lr1", nombre))?,
+        Err(_) => Algoritmo::Slr,
+    };
+
+    let contenido = fs::read_to_string("gramatica.txt")
+        .map_err(|e| format!("No se pudo leer gramatica.txt: {}", e))?;
+    let gramatica = gramatica::parser::parsear_gramatica(&contenido)
+        .map_err(|e| format!("Error al parsear gramatica.txt: {}", e))?;
+
+    let problemas = validacion::validar_gramatica(&gramatica);
+    for problema in problemas.iter().filter(|p| p.severidad == Severidad::Advertencia) {
+        println!("cargo:warning=gramatica.txt: {}", problema);
+    }
+    let errores: Vec<String> = problemas.iter()
+        .filter(|p| p.severidad == Severidad::Error)
+        .map(|p| p.to_string())
+        .collect();
+    if !errores.is_empty() {
+        return Err(format!("gramatica.txt tiene {} error(es):\n{}", errores.len(), errores.join("\n")));
+    }
+
+    let tablas = slr::generar_tablas(&gramatica, algoritmo);
+    for &indice in &tablas.resoluciones_sin_usar {
+        println!("cargo:warning=gramatica.txt: %resolver {} no aplicó a ningún conflicto", gramatica.resoluciones[indice].terminal);
+    }
+    let conflictos: Vec<String> = tablas.conflictos_sin_declarar()
+        .map(|c| c.describir(&gramatica))
+        .collect();
+    if !conflictos.is_empty() {
+        return Err(format!(
+            "gramatica.txt tiene {} conflicto(s) {} sin declarar con %resolver:\n\n{}",
+            conflictos.len(), algoritmo.nombre(), conflictos.join("\n\n")
+        ));
+    }
+
+    let destino = Path::new(&env::var("OUT_DIR").map_err(|e| e.to_string())?).join("tabla_slr.rs");
+    let archivo = File::create(&destino)
+        .map_err(|e| format!("No se pudo crear {}: {}", destino.display(), e))?;
+    let mut salida = BufWriter::new(archivo);
+    escritor::escribir_tablas(&mut salida, &tablas, &gramatica.producciones, algoritmo)
+        .and_then(|_| salida.flush())
+        .map_err(|e| format!("No se pudo escribir {}: {}", destino.display(), e))
+}
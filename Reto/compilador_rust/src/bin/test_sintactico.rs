@@ -8,39 +8,39 @@ fn main() {
     // Test 1: Programa simple sin variables ni funciones
     println!("--- Test 1: Programa mínimo ---");
     let tokens1 = vec![
-        Token { tipo: TipoToken::Programa, valor: "programa".to_string(), linea: 1 },
-        Token { tipo: TipoToken::Id, valor: "test".to_string(), linea: 1 },
-        Token { tipo: TipoToken::PuntoYComa, valor: ";".to_string(), linea: 1 },
-        Token { tipo: TipoToken::Inicio, valor: "inicio".to_string(), linea: 2 },
-        Token { tipo: TipoToken::LlaveAbre, valor: "{".to_string(), linea: 2 },
-        Token { tipo: TipoToken::LlaveCierra, valor: "}".to_string(), linea: 3 },
-        Token { tipo: TipoToken::Fin, valor: "fin".to_string(), linea: 3 },
+        Token::new(TipoToken::Programa, "programa", 1, 1, 0),
+        Token::new(TipoToken::Id, "test", 1, 1, 0),
+        Token::new(TipoToken::PuntoYComa, ";", 1, 1, 0),
+        Token::new(TipoToken::Inicio, "inicio", 2, 1, 0),
+        Token::new(TipoToken::LlaveAbre, "{", 2, 1, 0),
+        Token::new(TipoToken::LlaveCierra, "}", 3, 1, 0),
+        Token::new(TipoToken::Fin, "fin", 3, 1, 0),
     ];
 
     match sintactico::analyze(&tokens1, 1) {
         Ok(_) => println!("\n✓ Test 1 PASADO\n"),
-        Err(e) => eprintln!("\n✗ Test 1 FALLIDO: {}\n", e),
+        Err(errores) => eprintln!("\n✗ Test 1 FALLIDO: {} error(es), primero: {}\n", errores.len(), errores[0]),
     }
 
     // Test 2: Programa con una asignación simple
     println!("\n--- Test 2: Programa con asignación ---");
     let tokens2 = vec![
-        Token { tipo: TipoToken::Programa, valor: "programa".to_string(), linea: 1 },
-        Token { tipo: TipoToken::Id, valor: "test".to_string(), linea: 1 },
-        Token { tipo: TipoToken::PuntoYComa, valor: ";".to_string(), linea: 1 },
-        Token { tipo: TipoToken::Inicio, valor: "inicio".to_string(), linea: 2 },
-        Token { tipo: TipoToken::LlaveAbre, valor: "{".to_string(), linea: 2 },
-        Token { tipo: TipoToken::Id, valor: "x".to_string(), linea: 3 },
-        Token { tipo: TipoToken::Asignacion, valor: "=".to_string(), linea: 3 },
-        Token { tipo: TipoToken::CteEnt, valor: "5".to_string(), linea: 3 },
-        Token { tipo: TipoToken::PuntoYComa, valor: ";".to_string(), linea: 3 },
-        Token { tipo: TipoToken::LlaveCierra, valor: "}".to_string(), linea: 4 },
-        Token { tipo: TipoToken::Fin, valor: "fin".to_string(), linea: 4 },
+        Token::new(TipoToken::Programa, "programa", 1, 1, 0),
+        Token::new(TipoToken::Id, "test", 1, 1, 0),
+        Token::new(TipoToken::PuntoYComa, ";", 1, 1, 0),
+        Token::new(TipoToken::Inicio, "inicio", 2, 1, 0),
+        Token::new(TipoToken::LlaveAbre, "{", 2, 1, 0),
+        Token::new(TipoToken::Id, "x", 3, 1, 0),
+        Token::new(TipoToken::Asignacion, "=", 3, 1, 0),
+        Token::new(TipoToken::CteEnt, "5", 3, 1, 0),
+        Token::new(TipoToken::PuntoYComa, ";", 3, 1, 0),
+        Token::new(TipoToken::LlaveCierra, "}", 4, 1, 0),
+        Token::new(TipoToken::Fin, "fin", 4, 1, 0),
     ];
 
     match sintactico::analyze(&tokens2, 1) {
         Ok(_) => println!("✓ Test 2 PASADO\n"),
-        Err(e) => eprintln!("✗ Test 2 FALLIDO: {}\n", e),
+        Err(errores) => eprintln!("✗ Test 2 FALLIDO: {} error(es), primero: {}\n", errores.len(), errores[0]),
     }
 }
 
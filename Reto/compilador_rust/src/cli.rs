@@ -0,0 +1,387 @@
+//! # Interfaz de Línea de Comandos
+//!
+//! Interpreta los argumentos del binario `compilador_rust`:
+//!
+//! ```text
+//! compilador_rust <subcomando> <archivo> [opciones]
+//! compilador_rust <archivo.txt> [opciones]      # equivale a `run`
+//! ```
+//!
+//! Cada argumento se interpreta completo (no se buscan subcadenas), así que
+//! rutas como `mis-validaciones.txt` llegan intactas.
+//!
+//! ## Códigos de salida
+//! | Código | Significado                                      |
+//! | ------ | ------------------------------------------------ |
+//! | 0      | Éxito                                            |
+//! | 1      | Uso incorrecto, error de E/S o archivo objeto inválido |
+//! | 2      | Error léxico                                     |
+//! | 3      | Error sintáctico                                 |
+//! | 4      | Error semántico                                  |
+//! | 5      | Error de ejecución en la máquina virtual         |
+
+use std::path::{Path, PathBuf};
+use crate::diagnostico::{Diagnostico, Fase};
+use crate::intermedio::distribucion::{DistribucionMemoria, TipoSegmento};
+use crate::vm::Desbordamiento;
+
+/// Códigos de salida del proceso (documentados en `AYUDA`)
+pub mod codigos_salida {
+    pub const EXITO: i32 = 0;
+    pub const USO: i32 = 1;
+    pub const LEXICO: i32 = 2;
+    pub const SINTACTICO: i32 = 3;
+    pub const SEMANTICO: i32 = 4;
+    pub const EJECUCION: i32 = 5;
+}
+
+/// Texto de ayuda de `--help`
+pub const AYUDA: &str = "\
+Uso: compilador_rust <subcomando> <archivo> [opciones]
+     compilador_rust <archivo.txt> [opciones]        (equivale a `run`)
+
+Subcomandos:
+  check         Analiza el fuente (léxico, sintáctico y semántico) sin ejecutar
+  compile       Compila el fuente y guarda el programa objeto
+  run           Ejecuta un fuente o un programa objeto (.obj / .objb)
+  dump-tokens   Muestra los tokens del fuente
+  dump-quads    Muestra los cuádruplos de un fuente o programa objeto
+  dump-tables   Muestra funciones, constantes y strings de un fuente o programa objeto
+
+Opciones:
+  -o <ruta>         Archivo de salida de `compile`
+  --emit <lista>    Qué genera `compile`, separado por comas: obj, objb, quads, tokens
+                    (por defecto obj; con varios, -o da el nombre base)
+  --binario         Igual que --emit objb
+  --memoria <lista> Casillas por tipo de cada segmento, separado por comas
+                    (p. ej. global=10000,temporal=5000; por defecto 2000 cada uno).
+                    Un programa objeto solo corre con la misma distribución
+  --desbordamiento <modo>
+                    Qué hace `run` si un entero no cabe en 32 bits:
+                    error (por defecto, error de ejecución) o envolver
+  -v, -vv, -vvv     Nivel de detalle (también --verbose)
+  -h, --help        Muestra esta ayuda
+
+Códigos de salida:
+  0 éxito, 1 uso/E-S/objeto inválido, 2 error léxico, 3 error sintáctico,
+  4 error semántico, 5 error de ejecución";
+
+/// Subcomando solicitado
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Subcomando {
+    Check,
+    Compile,
+    Run,
+    DumpTokens,
+    DumpQuads,
+    DumpTables,
+    Ayuda,
+}
+
+impl Subcomando {
+    fn desde_str(s: &str) -> Option<Self> {
+        match s {
+            "check" => Some(Subcomando::Check),
+            "compile" => Some(Subcomando::Compile),
+            "run" => Some(Subcomando::Run),
+            "dump-tokens" => Some(Subcomando::DumpTokens),
+            "dump-quads" => Some(Subcomando::DumpQuads),
+            "dump-tables" => Some(Subcomando::DumpTables),
+            _ => None,
+        }
+    }
+}
+
+/// Artefacto que puede generar `compile --emit`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Emision {
+    /// Programa objeto en texto (`.obj`)
+    Obj,
+    /// Programa objeto binario (`.objb`)
+    Objb,
+    /// Listado de cuádruplos (`.quads`)
+    Cuadruplos,
+    /// Listado de tokens (`.tokens`)
+    Tokens,
+}
+
+impl Emision {
+    fn desde_str(s: &str) -> Option<Self> {
+        match s {
+            "obj" => Some(Emision::Obj),
+            "objb" => Some(Emision::Objb),
+            "quads" => Some(Emision::Cuadruplos),
+            "tokens" => Some(Emision::Tokens),
+            _ => None,
+        }
+    }
+
+    /// Extensión del archivo generado
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Emision::Obj => "obj",
+            Emision::Objb => "objb",
+            Emision::Cuadruplos => "quads",
+            Emision::Tokens => "tokens",
+        }
+    }
+}
+
+/// Opciones ya interpretadas de la línea de comandos
+#[derive(Debug, Clone, PartialEq)]
+pub struct Opciones {
+    pub subcomando: Subcomando,
+    pub entrada: PathBuf,
+    pub salida: Option<PathBuf>,
+    pub emitir: Vec<Emision>,
+    pub nivel_verbose: usize,
+    pub distribucion: DistribucionMemoria,
+    pub desbordamiento: Desbordamiento,
+}
+
+impl Opciones {
+    /// Ruta donde `compile` escribe un artefacto
+    ///
+    /// Con un solo artefacto se respeta `-o` tal cual; con varios, `-o` (o el
+    /// fuente si no se indicó) da el nombre base y cada uno usa su extensión
+    pub fn ruta_salida(&self, emision: Emision) -> PathBuf {
+        match &self.salida {
+            Some(salida) if self.emitir.len() == 1 => salida.clone(),
+            Some(salida) => salida.with_extension(emision.extension()),
+            None => self.entrada.with_extension(emision.extension()),
+        }
+    }
+}
+
+/// Interpreta los argumentos (sin el nombre del ejecutable)
+pub fn parsear_argumentos(args: &[String]) -> Result<Opciones, String> {
+    let mut subcomando = None;
+    let mut entrada: Option<PathBuf> = None;
+    let mut salida = None;
+    let mut emitir = Vec::new();
+    let mut nivel_verbose = 0;
+    let mut distribucion = DistribucionMemoria::default();
+    let mut desbordamiento = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-h" | "--help" => subcomando = Some(Subcomando::Ayuda),
+            "-v" | "--verbose" => nivel_verbose = nivel_verbose.max(1),
+            "-vv" => nivel_verbose = nivel_verbose.max(2),
+            "-vvv" => nivel_verbose = 3,
+            "-o" => {
+                let ruta = iter.next().ok_or("La opción '-o' requiere una ruta")?;
+                salida = Some(PathBuf::from(ruta));
+            }
+            "--emit" => {
+                let lista = iter.next().ok_or("La opción '--emit' requiere una lista (obj, objb, quads, tokens)")?;
+                for nombre in lista.split(',') {
+                    let emision = Emision::desde_str(nombre.trim())
+                        .ok_or_else(|| format!("Valor desconocido para --emit: '{}'", nombre))?;
+                    if !emitir.contains(&emision) {
+                        emitir.push(emision);
+                    }
+                }
+            }
+            "--memoria" => {
+                let lista = iter.next().ok_or("La opción '--memoria' requiere una lista (p. ej. global=10000)")?;
+                distribucion = parsear_distribucion(lista, distribucion)?;
+            }
+            "--desbordamiento" => {
+                let modo = iter.next().ok_or("La opción '--desbordamiento' requiere un modo (error, envolver)")?;
+                desbordamiento = Some(Desbordamiento::desde_nombre(modo)
+                    .ok_or_else(|| format!("Valor desconocido para --desbordamiento: '{}'", modo))?);
+            }
+            "--binario" => {
+                if !emitir.contains(&Emision::Objb) {
+                    emitir.push(Emision::Objb);
+                }
+            }
+            opcion if opcion.starts_with('-') && opcion.len() > 1 => {
+                return Err(format!("Opción desconocida: '{}'", opcion));
+            }
+            valor => {
+                if subcomando.is_none() && entrada.is_none()
+                    && let Some(s) = Subcomando::desde_str(valor)
+                {
+                    subcomando = Some(s);
+                } else if entrada.is_none() {
+                    entrada = Some(PathBuf::from(valor));
+                } else {
+                    return Err(format!("Argumento inesperado: '{}'", valor));
+                }
+            }
+        }
+    }
+
+    let subcomando = subcomando.unwrap_or(Subcomando::Run);
+    if subcomando == Subcomando::Ayuda {
+        return Ok(Opciones {
+            subcomando,
+            entrada: PathBuf::new(),
+            salida: None,
+            emitir: Vec::new(),
+            nivel_verbose,
+            distribucion,
+            desbordamiento: desbordamiento.unwrap_or_default(),
+        });
+    }
+
+    let entrada = entrada.ok_or("Falta el archivo de entrada")?;
+
+    if subcomando != Subcomando::Compile && (salida.is_some() || !emitir.is_empty()) {
+        return Err("Las opciones '-o', '--emit' y '--binario' solo aplican a 'compile'".to_string());
+    }
+    if subcomando != Subcomando::Run && desbordamiento.is_some() {
+        return Err("La opción '--desbordamiento' solo aplica a 'run'".to_string());
+    }
+    if subcomando == Subcomando::Compile && emitir.is_empty() {
+        emitir.push(Emision::Obj);
+    }
+
+    let desbordamiento = desbordamiento.unwrap_or_default();
+    Ok(Opciones { subcomando, entrada, salida, emitir, nivel_verbose, distribucion, desbordamiento })
+}
+
+/// Aplica una lista `segmento=casillas,...` de `--memoria` sobre una distribución
+fn parsear_distribucion(lista: &str, mut distribucion: DistribucionMemoria) -> Result<DistribucionMemoria, String> {
+    for par in lista.split(',') {
+        let (nombre, valor) = par.split_once('=')
+            .ok_or_else(|| format!("Valor inválido para --memoria: '{}' (se espera segmento=casillas)", par))?;
+        let segmento = TipoSegmento::desde_nombre(nombre.trim())
+            .ok_or_else(|| format!("Segmento desconocido para --memoria: '{}' (global, local, temporal, constante)", nombre))?;
+        let casillas = valor.trim().parse::<usize>()
+            .map_err(|_| format!("Número de casillas inválido para --memoria: '{}'", valor))?;
+        distribucion = distribucion.con_casillas(segmento, casillas);
+    }
+    distribucion.validar()?;
+    Ok(distribucion)
+}
+
+/// Código de salida para los diagnósticos de una compilación fallida:
+/// el de la fase más temprana con errores
+pub fn codigo_salida_diagnosticos(diagnosticos: &[Diagnostico]) -> i32 {
+    match diagnosticos.iter().map(|d| d.fase()).min() {
+        Some(Fase::Lexica) => codigos_salida::LEXICO,
+        Some(Fase::Sintactica) => codigos_salida::SINTACTICO,
+        Some(Fase::Semantica) | None => codigos_salida::SEMANTICO,
+    }
+}
+
+/// Encabezado con que se reportan los diagnósticos de una compilación fallida:
+/// el de la misma fase que decide el código de salida
+pub fn encabezado_diagnosticos(diagnosticos: &[Diagnostico]) -> &'static str {
+    match diagnosticos.iter().map(|d| d.fase()).min() {
+        Some(Fase::Lexica) => "✗✗✗ Error en el análisis léxico ✗✗✗",
+        Some(Fase::Sintactica) => "✗✗✗ Error en el análisis sintáctico ✗✗✗",
+        Some(Fase::Semantica) | None => "✗✗✗ Error en el análisis semántico ✗✗✗",
+    }
+}
+
+/// Nombre del programa a partir de la ruta del fuente (`dir/fib.txt` → `fib`)
+pub fn nombre_programa(ruta: &Path) -> String {
+    ruta.file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("programa")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostico::codigos;
+
+    fn parsear(args: &[&str]) -> Result<Opciones, String> {
+        let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+        parsear_argumentos(&args)
+    }
+
+    #[test]
+    fn test_ruta_con_guion_v_no_se_altera() {
+        let opciones = parsear(&["check", "mis-validaciones.txt", "-vv"]).unwrap();
+
+        assert_eq!(opciones.subcomando, Subcomando::Check);
+        assert_eq!(opciones.entrada, PathBuf::from("mis-validaciones.txt"));
+        assert_eq!(opciones.nivel_verbose, 2);
+    }
+
+    #[test]
+    fn test_archivo_sin_subcomando_es_run() {
+        let opciones = parsear(&["-v", "programa.txt"]).unwrap();
+
+        assert_eq!(opciones.subcomando, Subcomando::Run);
+        assert_eq!(opciones.nivel_verbose, 1);
+        assert!(opciones.emitir.is_empty());
+    }
+
+    #[test]
+    fn test_compile_emit_y_salida() {
+        let opciones = parsear(&["compile", "dir/fib.txt"]).unwrap();
+        assert_eq!(opciones.emitir, vec![Emision::Obj]);
+        assert_eq!(opciones.ruta_salida(Emision::Obj), PathBuf::from("dir/fib.obj"));
+
+        let opciones = parsear(&["compile", "fib.txt", "-o", "out/prog.bin", "--emit", "objb"]).unwrap();
+        assert_eq!(opciones.ruta_salida(Emision::Objb), PathBuf::from("out/prog.bin"));
+
+        let opciones = parsear(&["compile", "fib.txt", "--emit", "obj,quads", "-o", "out/prog"]).unwrap();
+        assert_eq!(opciones.emitir, vec![Emision::Obj, Emision::Cuadruplos]);
+        assert_eq!(opciones.ruta_salida(Emision::Cuadruplos), PathBuf::from("out/prog.quads"));
+    }
+
+    #[test]
+    fn test_errores_de_uso() {
+        assert!(parsear(&["check"]).unwrap_err().contains("Falta el archivo"));
+        assert!(parsear(&["run", "a.obj", "-o", "b"]).unwrap_err().contains("solo aplican a 'compile'"));
+        assert!(parsear(&["compile", "a.txt", "--emit", "exe"]).unwrap_err().contains("'exe'"));
+        assert!(parsear(&["check", "a.txt", "--rapido"]).unwrap_err().contains("'--rapido'"));
+        assert!(parsear(&["check", "a.txt", "b.txt"]).is_err());
+        assert_eq!(parsear(&["--help"]).unwrap().subcomando, Subcomando::Ayuda);
+    }
+
+    #[test]
+    fn test_opcion_memoria() {
+        assert_eq!(parsear(&["run", "a.txt"]).unwrap().distribucion, DistribucionMemoria::default());
+
+        let opciones = parsear(&["run", "a.txt", "--memoria", "global=10000,temporal=5000"]).unwrap();
+        assert_eq!(opciones.distribucion.casillas_de(TipoSegmento::Global), 10000);
+        assert_eq!(opciones.distribucion.casillas_de(TipoSegmento::Local), 2000);
+        assert_eq!(opciones.distribucion.casillas_de(TipoSegmento::Temporal), 5000);
+
+        assert!(parsear(&["run", "a.txt", "--memoria", "pila=10"]).unwrap_err().contains("'pila'"));
+        assert!(parsear(&["run", "a.txt", "--memoria", "local=0"]).unwrap_err().contains("no tiene casillas"));
+        assert!(parsear(&["run", "a.txt", "--memoria", "global"]).is_err());
+    }
+
+    #[test]
+    fn test_opcion_desbordamiento() {
+        assert_eq!(parsear(&["run", "a.txt"]).unwrap().desbordamiento, Desbordamiento::Error);
+        assert_eq!(
+            parsear(&["run", "a.obj", "--desbordamiento", "envolver"]).unwrap().desbordamiento,
+            Desbordamiento::Envolver
+        );
+
+        assert!(parsear(&["run", "a.txt", "--desbordamiento", "saturar"]).unwrap_err().contains("'saturar'"));
+        assert!(parsear(&["check", "a.txt", "--desbordamiento", "error"]).unwrap_err().contains("solo aplica a 'run'"));
+    }
+
+    #[test]
+    fn test_codigo_salida_por_fase() {
+        let lexico = Diagnostico::error(codigos::SIMBOLO_NO_RECONOCIDO, "x");
+        let sintactico = Diagnostico::error(codigos::TOKEN_INESPERADO, "x");
+        let semantico = Diagnostico::error(codigos::NO_DECLARADO, "x");
+
+        assert_eq!(codigo_salida_diagnosticos(std::slice::from_ref(&lexico)), 2);
+        assert_eq!(codigo_salida_diagnosticos(&[semantico.clone(), sintactico]), 3);
+        assert_eq!(codigo_salida_diagnosticos(&[semantico]), 4);
+    }
+
+    #[test]
+    fn test_encabezado_por_fase() {
+        let sintactico = Diagnostico::error(codigos::TOKEN_INESPERADO, "x");
+        let semantico = Diagnostico::no_declarado("x");
+
+        assert!(encabezado_diagnosticos(std::slice::from_ref(&semantico)).contains("semántico"));
+        assert!(encabezado_diagnosticos(&[semantico, sintactico]).contains("sintáctico"));
+    }
+}
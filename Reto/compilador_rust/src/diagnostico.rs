@@ -0,0 +1,444 @@
+//! # Diagnósticos
+//!
+//! Errores estructurados que reportan las fases del compilador (léxico,
+//! sintáctico y semántico). Cada diagnóstico tiene severidad, código,
+//! mensaje, un span primario en el código fuente, spans secundarios con
+//! etiqueta y notas adicionales.
+//!
+//! La CLI los muestra al estilo de rustc, con la línea del código fuente
+//! y el fragmento culpable subrayado:
+//!
+//! ```text
+//! error[E0301]: Error semántico: Variable 'w' no declarada
+//!  --> programa.txt:5:9
+//!   |
+//! 5 |     x = w + 1;
+//!   |         ^
+//!   = nota: declare el identificador antes de usarlo
+//! ```
+
+use std::fmt;
+
+/// Códigos de diagnóstico agrupados por fase
+pub mod codigos {
+    /// Léxico: símbolo no reconocido
+    pub const SIMBOLO_NO_RECONOCIDO: &str = "E0100";
+    /// Léxico: comentario de bloque sin cerrar
+    pub const COMENTARIO_SIN_CERRAR: &str = "E0101";
+    /// Léxico: constante char mal formada o con escape desconocido
+    pub const CHAR_INVALIDO: &str = "E0102";
+    /// Léxico: letrero sin cerrar o con escape desconocido
+    pub const LETRERO_INVALIDO: &str = "E0103";
+
+    /// Sintáctico: token inesperado
+    pub const TOKEN_INESPERADO: &str = "E0200";
+    /// Sintáctico: fin de archivo inesperado
+    pub const FIN_INESPERADO: &str = "E0201";
+
+    /// Semántico: error genérico
+    pub const SEMANTICO: &str = "E0300";
+    /// Semántico: variable o función no declarada
+    pub const NO_DECLARADO: &str = "E0301";
+    /// Semántico: declaración duplicada
+    pub const DOBLE_DECLARACION: &str = "E0302";
+    /// Semántico: tipos incompatibles
+    pub const TIPOS_INCOMPATIBLES: &str = "E0303";
+    /// Semántico: uso inválido de arreglos (índices o dimensiones)
+    pub const ARREGLO_INVALIDO: &str = "E0304";
+    /// Semántico: un segmento de memoria virtual se quedó sin direcciones
+    pub const MEMORIA_AGOTADA: &str = "E0305";
+
+    /// Error interno del compilador
+    pub const INTERNO: &str = "E0900";
+}
+
+/// Severidad de un diagnóstico
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severidad {
+    Error,
+    Advertencia,
+    Nota,
+}
+
+/// Fase del compilador que reporta un diagnóstico (en orden de ejecución)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Fase {
+    Lexica,
+    Sintactica,
+    Semantica,
+}
+
+impl fmt::Display for Severidad {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severidad::Error => write!(f, "error"),
+            Severidad::Advertencia => write!(f, "advertencia"),
+            Severidad::Nota => write!(f, "nota"),
+        }
+    }
+}
+
+/// Región del código fuente
+///
+/// `inicio` y `fin` son offsets en bytes (fin exclusivo); `linea` y `columna`
+/// (1-indexed, columna en caracteres) corresponden a `inicio`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub inicio: usize,
+    pub fin: usize,
+    pub linea: usize,
+    pub columna: usize,
+}
+
+impl Span {
+    /// Crea un nuevo span
+    pub fn new(inicio: usize, fin: usize, linea: usize, columna: usize) -> Self {
+        Span { inicio, fin, linea, columna }
+    }
+
+    /// Span que cubre desde el inicio del primero hasta el fin del último
+    pub fn unir(&self, otro: &Span) -> Span {
+        let (primero, ultimo) = if self.inicio <= otro.inicio { (self, otro) } else { (otro, self) };
+        Span {
+            inicio: primero.inicio,
+            fin: primero.fin.max(ultimo.fin),
+            linea: primero.linea,
+            columna: primero.columna,
+        }
+    }
+}
+
+/// Diagnóstico estructurado reportado por el compilador
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostico {
+    /// Severidad (error, advertencia o nota)
+    pub severidad: Severidad,
+
+    /// Código estable del diagnóstico (ver `codigos`)
+    pub codigo: &'static str,
+
+    /// Mensaje principal
+    pub mensaje: String,
+
+    /// Ubicación principal del problema (None si no hay ubicación conocida)
+    pub span: Option<Span>,
+
+    /// Ubicaciones relacionadas con su etiqueta
+    pub spans_secundarios: Vec<(Span, String)>,
+
+    /// Notas adicionales (sugerencias, contexto)
+    pub notas: Vec<String>,
+}
+
+impl Diagnostico {
+    /// Crea un diagnóstico de error sin ubicación
+    pub fn error(codigo: &'static str, mensaje: impl Into<String>) -> Self {
+        Diagnostico {
+            severidad: Severidad::Error,
+            codigo,
+            mensaje: mensaje.into(),
+            span: None,
+            spans_secundarios: Vec::new(),
+            notas: Vec::new(),
+        }
+    }
+
+    /// Asigna el span primario
+    pub fn con_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Asigna el span primario solo si aún no tiene uno: la ubicación más
+    /// específica (la del operando) gana sobre la de la construcción completa
+    pub fn con_span_por_omision(mut self, span: Option<Span>) -> Self {
+        if self.span.is_none() {
+            self.span = span;
+        }
+        self
+    }
+
+    /// Agrega un span secundario con etiqueta
+    pub fn con_span_secundario(mut self, span: Span, etiqueta: impl Into<String>) -> Self {
+        self.spans_secundarios.push((span, etiqueta.into()));
+        self
+    }
+
+    /// Agrega una nota
+    pub fn con_nota(mut self, nota: impl Into<String>) -> Self {
+        self.notas.push(nota.into());
+        self
+    }
+
+    /// Fase que reportó el diagnóstico, según el grupo de su código
+    /// (E01xx léxico, E02xx sintáctico, el resto semántico)
+    pub fn fase(&self) -> Fase {
+        if self.codigo.starts_with("E01") {
+            Fase::Lexica
+        } else if self.codigo.starts_with("E02") {
+            Fase::Sintactica
+        } else {
+            Fase::Semantica
+        }
+    }
+
+    /// Error semántico sin un código más específico (E0300)
+    pub fn semantico(mensaje: impl Into<String>) -> Self {
+        Diagnostico::error(codigos::SEMANTICO, mensaje)
+    }
+
+    /// Variable o función no declarada (E0301)
+    pub fn no_declarado(mensaje: impl Into<String>) -> Self {
+        Diagnostico::error(codigos::NO_DECLARADO, mensaje)
+            .con_nota("declare el identificador antes de usarlo")
+    }
+
+    /// Declaración duplicada (E0302)
+    pub fn doble_declaracion(mensaje: impl Into<String>) -> Self {
+        Diagnostico::error(codigos::DOBLE_DECLARACION, mensaje)
+    }
+
+    /// Tipos incompatibles según el cubo semántico (E0303)
+    pub fn tipos_incompatibles(mensaje: impl Into<String>) -> Self {
+        Diagnostico::error(codigos::TIPOS_INCOMPATIBLES, mensaje)
+            .con_nota("revise el cubo semántico para las combinaciones de tipos válidas")
+    }
+
+    /// Uso inválido de arreglos (E0304)
+    pub fn arreglo_invalido(mensaje: impl Into<String>) -> Self {
+        Diagnostico::error(codigos::ARREGLO_INVALIDO, mensaje)
+    }
+
+    /// Un segmento de memoria virtual se quedó sin direcciones (E0305)
+    pub fn memoria_agotada(mensaje: impl Into<String>) -> Self {
+        Diagnostico::error(codigos::MEMORIA_AGOTADA, mensaje)
+            .con_nota("amplíe el segmento con --memoria (p. ej. --memoria global=20000)")
+    }
+
+    /// Error interno del compilador: un invariante de las pilas o tablas no se
+    /// cumplió (E0900)
+    pub fn interno(mensaje: impl Into<String>) -> Self {
+        Diagnostico::error(codigos::INTERNO, mensaje)
+    }
+
+    /// Renderiza el diagnóstico con la línea del código fuente y el fragmento subrayado
+    pub fn renderizar(&self, fuente: &str, archivo: &str) -> String {
+        let mut salida = format!("{}[{}]: {}\n", self.severidad, self.codigo, self.mensaje);
+
+        let Some(span) = self.span else {
+            for nota in &self.notas {
+                salida.push_str(&format!("  = nota: {}\n", nota));
+            }
+            return salida;
+        };
+
+        // Ancho del margen según el número de línea más grande
+        let max_linea = self.spans_secundarios
+            .iter()
+            .map(|(s, _)| s.linea)
+            .chain(std::iter::once(span.linea))
+            .max()
+            .unwrap_or(span.linea);
+        let ancho = max_linea.to_string().len();
+        let margen = " ".repeat(ancho);
+
+        salida.push_str(&format!("{}--> {}:{}:{}\n", margen, archivo, span.linea, span.columna));
+        salida.push_str(&format!("{} |\n", margen));
+
+        // Cada línea del fuente se muestra una vez con todas sus marcas debajo
+        let mut marcas: Vec<Marca> = std::iter::once(Marca { span, marca: '^', relleno: '~', etiqueta: "" })
+            .chain(self.spans_secundarios.iter().map(|(secundario, etiqueta)| Marca {
+                span: *secundario,
+                marca: '-',
+                relleno: '-',
+                etiqueta,
+            }))
+            .collect();
+        marcas.sort_by_key(|m| (m.span.linea, m.span.columna));
+        for grupo in marcas.chunk_by(|a, b| a.span.linea == b.span.linea) {
+            salida.push_str(&renderizar_linea(fuente, grupo, ancho));
+        }
+
+        for nota in &self.notas {
+            salida.push_str(&format!("{} = nota: {}\n", margen, nota));
+        }
+
+        salida
+    }
+}
+
+/// Subrayado de un span con su etiqueta (vacía en el span primario)
+struct Marca<'a> {
+    span: Span,
+    marca: char,
+    relleno: char,
+    etiqueta: &'a str,
+}
+
+/// Renderiza una línea del código fuente con los spans de `marcas` (todos en
+/// esa línea, ordenados por columna) subrayados
+///
+/// Las marcas que no se traslapan comparten renglón; una etiqueta cierra su
+/// renglón, así que la siguiente marca empieza uno nuevo.
+fn renderizar_linea(fuente: &str, marcas: &[Marca], ancho: usize) -> String {
+    let linea = marcas[0].span.linea;
+    let texto_linea = fuente.lines().nth(linea.saturating_sub(1)).unwrap_or("");
+    let caracteres: Vec<char> = texto_linea.chars().collect();
+
+    // (contenido, columnas ocupadas, cerrado por una etiqueta)
+    let mut renglones: Vec<(String, usize, bool)> = Vec::new();
+    for marca in marcas {
+        let span = &marca.span;
+
+        // El subrayado no pasa del final de la línea (spans multilínea)
+        let inicio_col = span.columna.saturating_sub(1);
+        let largo_fragmento = fuente
+            .get(span.inicio..span.fin.min(fuente.len()))
+            .map(|s| s.lines().next().unwrap_or("").chars().count())
+            .unwrap_or(0);
+        let disponible = caracteres.len().saturating_sub(inicio_col);
+        let largo = largo_fragmento.min(disponible).max(1);
+
+        let indice = match renglones.iter().position(|(_, ocupado, cerrado)| !cerrado && *ocupado <= inicio_col) {
+            Some(indice) => indice,
+            None => {
+                renglones.push((String::new(), 0, false));
+                renglones.len() - 1
+            }
+        };
+        let (contenido, ocupado, cerrado) = &mut renglones[indice];
+
+        // La sangría conserva los tabuladores de la línea para alinear el subrayado
+        contenido.extend((*ocupado..inicio_col).map(|i| if caracteres.get(i) == Some(&'\t') { '\t' } else { ' ' }));
+        contenido.push(marca.marca);
+        contenido.extend(std::iter::repeat_n(marca.relleno, largo - 1));
+        *ocupado = inicio_col + largo;
+        if !marca.etiqueta.is_empty() {
+            contenido.push_str(&format!(" {}", marca.etiqueta));
+            *cerrado = true;
+        }
+    }
+
+    let mut salida = format!("{:>ancho$} | {}\n", linea, texto_linea, ancho = ancho);
+    for (contenido, _, _) in renglones {
+        salida.push_str(&format!("{} | {}\n", " ".repeat(ancho), contenido));
+    }
+    salida
+}
+
+impl fmt::Display for Diagnostico {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]: {}", self.severidad, self.codigo, self.mensaje)?;
+        if let Some(span) = self.span {
+            write!(f, " (línea {}, columna {})", span.linea, span.columna)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_renderizar_subrayado() {
+        let fuente = "programa p;\nx = w + 1;\n";
+        // `w` está en el byte 16, línea 2, columna 5
+        let diag = Diagnostico::error(codigos::NO_DECLARADO, "Variable 'w' no declarada")
+            .con_span(Span::new(16, 17, 2, 5));
+
+        let texto = diag.renderizar(fuente, "prueba.txt");
+        assert!(texto.starts_with("error[E0301]: Variable 'w' no declarada\n"));
+        assert!(texto.contains(" --> prueba.txt:2:5\n"));
+        assert!(texto.contains("2 | x = w + 1;\n"));
+        assert!(texto.contains("  |     ^\n"));
+    }
+
+    #[test]
+    fn test_renderizar_span_largo_y_notas() {
+        let fuente = "x = 3.5;";
+        let diag = Diagnostico::error(codigos::TIPOS_INCOMPATIBLES, "tipos incompatibles")
+            .con_span(Span::new(0, 7, 1, 1))
+            .con_span_secundario(Span::new(4, 7, 1, 5), "flotante")
+            .con_nota("revise el cubo semántico");
+
+        let texto = diag.renderizar(fuente, "a.txt");
+        assert!(texto.contains("1 | x = 3.5;\n  | ^~~~~~~\n  |     --- flotante\n"), "{}", texto);
+        assert!(texto.contains("  = nota: revise el cubo semántico\n"));
+    }
+
+    #[test]
+    fn test_renderizar_marcas_en_la_misma_linea() {
+        // Sin traslape: la línea aparece una vez y ambas marcas en un renglón
+        let fuente = "programa p;\nx = w + 1;\n";
+        let diag = Diagnostico::error(codigos::TIPOS_INCOMPATIBLES, "tipos incompatibles")
+            .con_span(Span::new(12, 13, 2, 1))
+            .con_span_secundario(Span::new(16, 21, 2, 5), "en esta expresión");
+
+        let texto = diag.renderizar(fuente, "a.txt");
+        assert_eq!(texto.matches("x = w + 1;").count(), 1, "{}", texto);
+        assert!(texto.contains("2 | x = w + 1;\n  | ^   ----- en esta expresión\n"), "{}", texto);
+    }
+
+    #[test]
+    fn test_codigos_semanticos() {
+        use crate::{lexico, sintactico};
+
+        let primer_error = |cuerpo: &str| {
+            let fuente = format!("programa p; vars n : entero; vars v[3] : entero; vars b : booleano; {}", cuerpo);
+            let tokens = lexico::analyze(&fuente, 0).unwrap();
+            sintactico::analyze(&tokens, 0).err().unwrap().remove(0)
+        };
+
+        let diag = primer_error("inicio { n = w; } fin");
+        assert_eq!(diag.codigo, codigos::NO_DECLARADO);
+        assert_eq!(diag.notas, ["declare el identificador antes de usarlo"]);
+
+        assert_eq!(primer_error("vars n : flotante; inicio { } fin").codigo, codigos::DOBLE_DECLARACION);
+        assert_eq!(primer_error("inicio { n = b; } fin").codigo, codigos::TIPOS_INCOMPATIBLES);
+        assert_eq!(primer_error("inicio { n = v; } fin").codigo, codigos::ARREGLO_INVALIDO);
+        assert_eq!(primer_error("inicio { n[0] = 1; } fin").codigo, codigos::ARREGLO_INVALIDO);
+        assert_eq!(primer_error("inicio { rompe; } fin").codigo, codigos::SEMANTICO);
+
+        // Un arreglo que no cabe en su segmento, aunque el producto de sus
+        // dimensiones desborde
+        for declaracion in ["vars g[3000] : entero;", "vars g[4294967296][4294967296] : entero;"] {
+            let diag = primer_error(&format!("{} inicio {{ }} fin", declaracion));
+            assert_eq!(diag.codigo, codigos::MEMORIA_AGOTADA, "{}", diag.mensaje);
+            assert_eq!(diag.fase(), Fase::Semantica);
+        }
+    }
+
+    #[test]
+    fn test_spans_de_compilacion() {
+        use crate::{lexico, sintactico};
+
+        // Error léxico: apunta al símbolo
+        let diag = lexico::analyze("programa p;\ninicio { @ } fin", 0).unwrap_err();
+        assert_eq!(diag.codigo, codigos::SIMBOLO_NO_RECONOCIDO);
+        assert_eq!(diag.span, Some(Span::new(21, 22, 2, 10)));
+
+        // Error semántico: apunta al identificador no declarado
+        let fuente = "programa p; vars x : entero;\ninicio { x = w + 1; } fin";
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+        let diag = sintactico::analyze(&tokens, 0).err().unwrap().remove(0);
+        assert_eq!(diag.codigo, codigos::NO_DECLARADO);
+        let span = diag.span.unwrap();
+        assert_eq!((span.linea, span.columna), (2, 14));
+        assert_eq!(&fuente[span.inicio..span.fin], "w");
+
+        // Error sintáctico: apunta al token inesperado
+        let tokens = lexico::analyze("programa p;\ninicio { x = ; } fin", 0).unwrap();
+        let diag = sintactico::analyze(&tokens, 0).err().unwrap().remove(0);
+        assert_eq!(diag.codigo, codigos::TOKEN_INESPERADO);
+        assert_eq!(diag.span.map(|s| (s.linea, s.columna)), Some((2, 14)));
+    }
+
+    #[test]
+    fn test_unir_spans() {
+        let a = Span::new(4, 5, 1, 5);
+        let b = Span::new(8, 11, 1, 9);
+        assert_eq!(a.unir(&b), Span::new(4, 11, 1, 5));
+        assert_eq!(b.unir(&a), Span::new(4, 11, 1, 5));
+    }
+}
@@ -0,0 +1,137 @@
+//! # Escritor de tablas
+//!
+//! Escribe las tablas ACTION/GOTO y la lista de producciones como código Rust
+//! (`lazy_static`). Lo usa `build.rs` para generar `$OUT_DIR/tabla_slr.rs`, que
+//! `sintactico::tabla_slr` incluye. Las entradas se escriben ordenadas para que
+//! la misma gramática produzca siempre el mismo archivo.
+
+use crate::gramatica::{Produccion, Simbolo};
+use crate::gramatica::slr::{Accion, Algoritmo, TablasSLR};
+use std::io::{self, Write};
+
+/// Escribe las tablas en `salida` como código Rust
+pub fn escribir_tablas(
+    salida: &mut impl Write,
+    tablas: &TablasSLR,
+    producciones: &[Produccion],
+    algoritmo: Algoritmo,
+) -> io::Result<()> {
+    // Encabezado
+    writeln!(salida, "// ==========================================")?;
+    writeln!(salida, "// ARCHIVO GENERADO AUTOMÁTICAMENTE")?;
+    writeln!(salida, "// Generado a partir de gramatica.txt ({})", algoritmo.nombre())?;
+    writeln!(salida, "// NO EDITAR MANUALMENTE")?;
+    writeln!(salida, "// ==========================================\n")?;
+
+    writeln!(salida, "use lazy_static::lazy_static;")?;
+    writeln!(salida, "use std::collections::HashMap;\n")?;
+
+    // Definir el enum Accion
+    writeln!(salida, "/// Acción en la tabla ACTION")?;
+    writeln!(salida, "#[derive(Debug, Clone, PartialEq)]")?;
+    writeln!(salida, "pub enum Accion {{")?;
+    writeln!(salida, "    Shift(usize),   // Desplazar al estado N")?;
+    writeln!(salida, "    Reduce(usize),  // Reducir por la producción N")?;
+    writeln!(salida, "    Accept,         // Aceptar")?;
+    writeln!(salida, "}}\n")?;
+
+    // Definir la estructura Regla
+    writeln!(salida, "/// Representa una regla de producción")?;
+    writeln!(salida, "#[derive(Debug, Clone)]")?;
+    writeln!(salida, "pub struct Regla {{")?;
+    writeln!(salida, "    #[allow(dead_code)]")?;
+    writeln!(salida, "    pub id: usize,")?;
+    writeln!(salida, "    pub cabeza: String,")?;
+    writeln!(salida, "    pub longitud_cuerpo: usize,")?;
+    writeln!(salida, "}}\n")?;
+
+    // Inicializar las tablas con lazy_static
+    writeln!(salida, "lazy_static! {{")?;
+
+    // Escribir TABLA_ACTION
+    let mut action: Vec<_> = tablas.action.iter().collect();
+    action.sort_by(|a, b| a.0.cmp(b.0));
+    writeln!(salida, "    /// Tabla ACTION: (estado, terminal) -> Acción")?;
+    writeln!(salida, "    pub static ref TABLA_ACTION: HashMap<(usize, String), Accion> = {{")?;
+    writeln!(salida, "        let mut m = HashMap::new();")?;
+    for ((estado, terminal), accion) in action {
+        let accion_str = match accion {
+            Accion::Shift(n) => format!("Accion::Shift({})", n),
+            Accion::Reduce(n) => format!("Accion::Reduce({})", n),
+            Accion::Accept => "Accion::Accept".to_string(),
+        };
+        writeln!(
+            salida,
+            "        m.insert(({}, \"{}\".to_string()), {});",
+            estado, terminal, accion_str
+        )?;
+    }
+    writeln!(salida, "        m")?;
+    writeln!(salida, "    }};\n")?;
+
+    // Escribir TABLA_GOTO
+    let mut goto: Vec<_> = tablas.goto.iter().collect();
+    goto.sort_by(|a, b| a.0.cmp(b.0));
+    writeln!(salida, "    /// Tabla GOTO: (estado, no-terminal) -> estado_destino")?;
+    writeln!(salida, "    pub static ref TABLA_GOTO: HashMap<(usize, String), usize> = {{")?;
+    writeln!(salida, "        let mut m = HashMap::new();")?;
+    for ((estado, no_terminal), destino) in goto {
+        writeln!(
+            salida,
+            "        m.insert(({}, \"{}\".to_string()), {});",
+            estado, no_terminal, destino
+        )?;
+    }
+    writeln!(salida, "        m")?;
+    writeln!(salida, "    }};\n")?;
+
+    // Escribir PRODUCCIONES
+    writeln!(salida, "    /// Lista de producciones de la gramática")?;
+    writeln!(salida, "    pub static ref PRODUCCIONES: Vec<Regla> = vec![")?;
+    for prod in producciones {
+        let cabeza = match &prod.cabeza {
+            Simbolo::Terminal(s) | Simbolo::NoTerminal(s) => s,
+        };
+        writeln!(
+            salida,
+            "        Regla {{ id: {}, cabeza: \"{}\".to_string(), longitud_cuerpo: {} }},",
+            prod.numero, cabeza, prod.cuerpo.len()
+        )?;
+    }
+    writeln!(salida, "    ];")?;
+
+    writeln!(salida, "}}")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gramatica::{parser, slr};
+
+    #[test]
+    fn test_escritura_ordenada_y_estable() {
+        let gramatica = parser::parsear_gramatica("<E> → <E> + id\n<E> → id\n").unwrap();
+        let escribir = || {
+            let tablas = slr::generar_tablas(&gramatica, Algoritmo::Slr);
+            let mut salida = Vec::new();
+            escribir_tablas(&mut salida, &tablas, &gramatica.producciones, Algoritmo::Slr).unwrap();
+            String::from_utf8(salida).unwrap()
+        };
+
+        let texto = escribir();
+        assert_eq!(texto, escribir());
+        assert!(texto.contains("(SLR(1))"));
+        assert!(texto.contains("m.insert((0, \"id\".to_string()), Accion::Shift("), "{}", texto);
+        assert!(texto.contains("Regla { id: 1, cabeza: \"<E>\".to_string(), longitud_cuerpo: 3 },"));
+
+        // ACTION en orden de estado
+        let estados: Vec<usize> = texto.lines()
+            .take_while(|l| !l.contains("TABLA_GOTO"))
+            .filter_map(|l| l.trim().strip_prefix("m.insert(("))
+            .map(|l| l.split(',').next().unwrap().parse().unwrap())
+            .collect();
+        assert!(estados.windows(2).all(|par| par[0] <= par[1]));
+    }
+}
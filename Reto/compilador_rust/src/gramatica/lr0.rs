@@ -43,20 +43,18 @@ pub fn clausura(items: &EstadoLR0, gramatica: &Gramatica) -> EstadoLR0 {
             let produccion = &gramatica.producciones[item.regla_id];
 
             // Si el punto no está al final, ver qué símbolo sigue
-            if let Some(simbolo) = item.simbolo_siguiente(produccion) {
-                // Si es un no-terminal, agregar todas sus producciones con punto al inicio
-                if let Simbolo::NoTerminal(nt) = simbolo {
-                    for prod in &gramatica.producciones {
-                        if let Simbolo::NoTerminal(cabeza_nt) = &prod.cabeza {
-                            if cabeza_nt == nt {
-                                let nuevo_item = ItemLR0 {
-                                    regla_id: prod.numero,
-                                    punto: 0,
-                                };
-                                if clausura.insert(nuevo_item) {
-                                    agregados = true;
-                                }
-                            }
+            // Si es un no-terminal, agregar todas sus producciones con punto al inicio
+            if let Some(Simbolo::NoTerminal(nt)) = item.simbolo_siguiente(produccion) {
+                for prod in &gramatica.producciones {
+                    if let Simbolo::NoTerminal(cabeza_nt) = &prod.cabeza
+                        && cabeza_nt == nt
+                    {
+                        let nuevo_item = ItemLR0 {
+                            regla_id: prod.numero,
+                            punto: 0,
+                        };
+                        if clausura.insert(nuevo_item) {
+                            agregados = true;
                         }
                     }
                 }
@@ -80,14 +78,14 @@ pub fn ir_a(estado: &EstadoLR0, simbolo: &Simbolo, gramatica: &Gramatica) -> Est
         let produccion = &gramatica.producciones[item.regla_id];
 
         // Verificar si el símbolo después del punto coincide con el dado
-        if let Some(sig_simbolo) = item.simbolo_siguiente(produccion) {
-            if sig_simbolo == simbolo {
-                // Mover el punto una posición adelante
-                nuevo_estado.insert(ItemLR0 {
-                    regla_id: item.regla_id,
-                    punto: item.punto + 1,
-                });
-            }
+        if let Some(sig_simbolo) = item.simbolo_siguiente(produccion)
+            && sig_simbolo == simbolo
+        {
+            // Mover el punto una posición adelante
+            nuevo_estado.insert(ItemLR0 {
+                regla_id: item.regla_id,
+                punto: item.punto + 1,
+            });
         }
     }
 
@@ -157,6 +155,13 @@ fn obtener_todos_simbolos(gramatica: &Gramatica) -> Vec<Simbolo> {
     simbolos.extend(gramatica.simbolos_terminales.iter().cloned());
     simbolos.extend(gramatica.simbolos_no_terminales.iter().cloned());
 
+    // Orden fijo para que la numeración de los estados sea reproducible
+    simbolos.sort_by(|a, b| match (a, b) {
+        (Simbolo::Terminal(x), Simbolo::Terminal(y)) | (Simbolo::NoTerminal(x), Simbolo::NoTerminal(y)) => x.cmp(y),
+        (Simbolo::Terminal(_), Simbolo::NoTerminal(_)) => std::cmp::Ordering::Less,
+        (Simbolo::NoTerminal(_), Simbolo::Terminal(_)) => std::cmp::Ordering::Greater,
+    });
+
     simbolos
 }
 
@@ -186,7 +191,7 @@ pub fn item_to_string(item: &ItemLR0, gramatica: &Gramatica) -> String {
 
     // Si el punto está al final
     if item.punto >= prod.cuerpo.len() {
-        cuerpo_str.push_str("•");
+        cuerpo_str.push('•');
     }
 
     format!("{} → {}", cabeza, cuerpo_str.trim())
@@ -0,0 +1,264 @@
+//! # Autómatas LR(1) y LALR(1)
+//!
+//! Un ítem LR(1) es un ítem LR(0) más un terminal de lookahead: `[A → α • β, a]`
+//! solo permite reducir `A → αβ` cuando el siguiente token es `a`, en lugar de con
+//! todo FOLLOW(A) como en SLR. El autómata LR(1) canónico distingue estados con el
+//! mismo núcleo LR(0) pero distintos lookaheads; LALR(1) fusiona esos estados
+//! (mismo número de estados que LR(0)) conservando la unión de los lookaheads.
+
+use crate::gramatica::{Gramatica, Simbolo};
+use crate::gramatica::lr0::ItemLR0;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// Ítem LR(1): producción con punto y terminal de lookahead
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ItemLR1 {
+    pub regla_id: usize,
+    pub punto: usize,
+    pub lookahead: String,
+}
+
+impl ItemLR1 {
+    /// Ítem LR(0) sin el lookahead
+    pub fn nucleo(&self) -> ItemLR0 {
+        ItemLR0 { regla_id: self.regla_id, punto: self.punto }
+    }
+}
+
+/// Un estado LR(1) es un conjunto ordenado de ítems (el orden hace estable la numeración)
+pub type EstadoLR1 = BTreeSet<ItemLR1>;
+
+/// Transiciones: (estado, símbolo) -> estado_destino
+pub type TransicionesLR1 = HashMap<(usize, Simbolo), usize>;
+
+/// FIRST(β a): terminales con que puede empezar la secuencia seguida del lookahead
+pub fn first_de_secuencia(
+    simbolos: &[Simbolo],
+    lookahead: &str,
+    first_sets: &HashMap<String, HashSet<String>>,
+) -> BTreeSet<String> {
+    let mut resultado = BTreeSet::new();
+
+    for simbolo in simbolos {
+        match simbolo {
+            Simbolo::Terminal(t) => {
+                resultado.insert(t.clone());
+                return resultado;
+            }
+            Simbolo::NoTerminal(nt) => {
+                let first = &first_sets[nt];
+                resultado.extend(first.iter().filter(|s| *s != "ε").cloned());
+                if !first.contains("ε") {
+                    return resultado;
+                }
+            }
+        }
+    }
+
+    // Toda la secuencia puede ser ε
+    resultado.insert(lookahead.to_string());
+    resultado
+}
+
+/// Calcula la clausura de un conjunto de ítems LR(1)
+///
+/// Para cada ítem [A → α•Bβ, a] y cada producción B → γ, agrega [B → •γ, b]
+/// para cada b en FIRST(βa)
+pub fn clausura(
+    items: &EstadoLR1,
+    gramatica: &Gramatica,
+    first_sets: &HashMap<String, HashSet<String>>,
+) -> EstadoLR1 {
+    let mut clausura = items.clone();
+    let mut pendientes: Vec<ItemLR1> = items.iter().cloned().collect();
+
+    while let Some(item) = pendientes.pop() {
+        let produccion = &gramatica.producciones[item.regla_id];
+
+        if let Some(Simbolo::NoTerminal(nt)) = produccion.cuerpo.get(item.punto) {
+            let lookaheads = first_de_secuencia(&produccion.cuerpo[item.punto + 1..], &item.lookahead, first_sets);
+
+            for prod in &gramatica.producciones {
+                if let Simbolo::NoTerminal(cabeza_nt) = &prod.cabeza
+                    && cabeza_nt == nt
+                {
+                    for lookahead in &lookaheads {
+                        let nuevo_item = ItemLR1 { regla_id: prod.numero, punto: 0, lookahead: lookahead.clone() };
+                        if clausura.insert(nuevo_item.clone()) {
+                            pendientes.push(nuevo_item);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    clausura
+}
+
+/// Calcula el estado resultante de avanzar el punto sobre un símbolo
+pub fn ir_a(
+    estado: &EstadoLR1,
+    simbolo: &Simbolo,
+    gramatica: &Gramatica,
+    first_sets: &HashMap<String, HashSet<String>>,
+) -> EstadoLR1 {
+    let nuevo_estado: EstadoLR1 = estado.iter()
+        .filter(|item| gramatica.producciones[item.regla_id].cuerpo.get(item.punto) == Some(simbolo))
+        .map(|item| ItemLR1 { punto: item.punto + 1, ..item.clone() })
+        .collect();
+
+    if nuevo_estado.is_empty() {
+        nuevo_estado
+    } else {
+        clausura(&nuevo_estado, gramatica, first_sets)
+    }
+}
+
+/// Construye el autómata LR(1) canónico
+pub fn construir_automata(
+    gramatica: &Gramatica,
+    first_sets: &HashMap<String, HashSet<String>>,
+) -> (Vec<EstadoLR1>, TransicionesLR1) {
+    let item_inicial = ItemLR1 { regla_id: 0, punto: 0, lookahead: "$".to_string() };
+    let estado_inicial = clausura(&BTreeSet::from([item_inicial]), gramatica, first_sets);
+
+    let mut estados = vec![estado_inicial.clone()];
+    let mut indices: HashMap<EstadoLR1, usize> = HashMap::from([(estado_inicial, 0)]);
+    let mut transiciones = HashMap::new();
+    let mut siguiente_pendiente = 0;
+
+    // Procesar en orden de creación para que la numeración sea determinista
+    while siguiente_pendiente < estados.len() {
+        let idx_estado = siguiente_pendiente;
+        siguiente_pendiente += 1;
+
+        // Solo los símbolos que aparecen después de algún punto
+        let simbolos: BTreeSet<(bool, String)> = estados[idx_estado].iter()
+            .filter_map(|item| gramatica.producciones[item.regla_id].cuerpo.get(item.punto))
+            .map(|simbolo| match simbolo {
+                Simbolo::Terminal(t) => (true, t.clone()),
+                Simbolo::NoTerminal(nt) => (false, nt.clone()),
+            })
+            .collect();
+
+        for (es_terminal, nombre) in simbolos {
+            let simbolo = if es_terminal { Simbolo::Terminal(nombre) } else { Simbolo::NoTerminal(nombre) };
+            let nuevo_estado = ir_a(&estados[idx_estado], &simbolo, gramatica, first_sets);
+
+            let destino = match indices.get(&nuevo_estado) {
+                Some(&idx_existente) => idx_existente,
+                None => {
+                    let idx_nuevo = estados.len();
+                    indices.insert(nuevo_estado.clone(), idx_nuevo);
+                    estados.push(nuevo_estado);
+                    idx_nuevo
+                }
+            };
+            transiciones.insert((idx_estado, simbolo), destino);
+        }
+    }
+
+    (estados, transiciones)
+}
+
+/// Fusiona los estados LR(1) que comparten núcleo LR(0) (construcción LALR(1))
+///
+/// Los estados fusionados conservan la unión de los lookaheads; las transiciones
+/// siguen siendo consistentes porque estados con el mismo núcleo van a destinos
+/// con el mismo núcleo.
+pub fn fusionar_nucleos(estados: &[EstadoLR1], transiciones: &TransicionesLR1) -> (Vec<EstadoLR1>, TransicionesLR1) {
+    let mut grupos: BTreeMap<BTreeSet<(usize, usize)>, usize> = BTreeMap::new();
+    let mut grupo_de = Vec::with_capacity(estados.len());
+    let mut fusionados: Vec<EstadoLR1> = Vec::new();
+
+    for estado in estados {
+        let nucleo: BTreeSet<(usize, usize)> = estado.iter().map(|item| (item.regla_id, item.punto)).collect();
+        let idx = *grupos.entry(nucleo).or_insert_with(|| {
+            fusionados.push(BTreeSet::new());
+            fusionados.len() - 1
+        });
+        fusionados[idx].extend(estado.iter().cloned());
+        grupo_de.push(idx);
+    }
+
+    let transiciones_fusionadas = transiciones.iter()
+        .map(|((origen, simbolo), destino)| ((grupo_de[*origen], simbolo.clone()), grupo_de[*destino]))
+        .collect();
+
+    (fusionados, transiciones_fusionadas)
+}
+
+/// Construye el autómata LALR(1) fusionando el LR(1) canónico
+pub fn construir_automata_lalr(
+    gramatica: &Gramatica,
+    first_sets: &HashMap<String, HashSet<String>>,
+) -> (Vec<EstadoLR1>, TransicionesLR1) {
+    let (estados, transiciones) = construir_automata(gramatica, first_sets);
+    fusionar_nucleos(&estados, &transiciones)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gramatica::{first_follow, lr0, parser, slr};
+
+    /// Número de estados y de conflictos con SLR, LALR(1) y LR(1)
+    fn comparar(fuente: &str) -> [(usize, usize); 3] {
+        let gramatica = parser::parsear_gramatica(fuente).unwrap();
+        let first = first_follow::calcular_first_sets(&gramatica);
+        let follow = first_follow::calcular_follow_sets(&gramatica, &first);
+
+        let (estados_lr0, transiciones_lr0) = lr0::construir_automata(&gramatica);
+        let tablas_slr = slr::generar_tablas_slr(&estados_lr0, &transiciones_lr0, &gramatica, &follow);
+
+        let (estados_lr1, transiciones_lr1) = construir_automata(&gramatica, &first);
+        let tablas_lr1 = slr::generar_tablas_lr1(&estados_lr1, &transiciones_lr1, &gramatica);
+
+        let (estados_lalr, transiciones_lalr) = fusionar_nucleos(&estados_lr1, &transiciones_lr1);
+        let tablas_lalr = slr::generar_tablas_lr1(&estados_lalr, &transiciones_lalr, &gramatica);
+
+        [
+            (estados_lr0.len(), tablas_slr.conflictos.len()),
+            (estados_lalr.len(), tablas_lalr.conflictos.len()),
+            (estados_lr1.len(), tablas_lr1.conflictos.len()),
+        ]
+    }
+
+    #[test]
+    fn test_first_de_secuencia() {
+        let gramatica = parser::parsear_gramatica("<S> → <A> b\n<A> → a\n<A> → ε\n").unwrap();
+        let first = first_follow::calcular_first_sets(&gramatica);
+        let nt = |s: &str| Simbolo::NoTerminal(s.to_string());
+
+        assert_eq!(first_de_secuencia(&[nt("<A>")], "$", &first), BTreeSet::from(["a".to_string(), "$".to_string()]));
+        assert_eq!(first_de_secuencia(&[nt("<S>")], "$", &first), BTreeSet::from(["a".to_string(), "b".to_string()]));
+    }
+
+    #[test]
+    fn test_lalr_resuelve_lo_que_slr_no() {
+        // Asignación por apuntadores del Dragon Book: no es SLR(1) pero sí LALR(1)
+        let [slr, lalr, lr1] = comparar("<S> → <L> = <R>\n<S> → <R>\n<L> → * <R>\n<L> → id\n<R> → <L>\n");
+
+        assert_eq!(slr, (10, 1));
+        assert_eq!(lalr, (10, 0));
+        assert_eq!(lr1, (14, 0));
+    }
+
+    #[test]
+    fn test_lr1_resuelve_lo_que_lalr_no() {
+        // Fusionar los núcleos de `c •` mezcla los lookaheads d/e: conflicto reduce/reduce
+        let [_, lalr, lr1] = comparar("\
+<S> → a <A> d
+<S> → b <B> d
+<S> → a <B> e
+<S> → b <A> e
+<A> → c
+<B> → c
+");
+
+        assert_eq!(lalr.1, 2);
+        assert_eq!(lr1.1, 0);
+        assert!(lr1.0 > lalr.0);
+    }
+}
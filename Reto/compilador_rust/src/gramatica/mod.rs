@@ -1,9 +1,13 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 // Modulos
 pub mod parser;
 pub mod first_follow;
 pub mod lr0;
+pub mod lr1;
+pub mod slr;
+pub mod validacion;
+pub mod escritor;
 
 // Simbolo en la gramatica
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -20,6 +24,37 @@ pub struct Produccion {
     pub cuerpo: Vec<Simbolo>,
 }
 
+// Acción preferida por una resolución declarada
+#[derive(Debug, Clone, PartialEq)]
+pub enum Preferencia {
+    Shift,
+    Reduce(usize), // número de la producción
+}
+
+// Resolución de conflicto declarada en la gramática:
+// `%resolver <terminal> shift` o `%resolver <terminal> <A> → α`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resolucion {
+    pub terminal: String,
+    pub preferencia: Preferencia,
+}
+
+// Asociatividad de un nivel de precedencia
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Asociatividad {
+    Izquierda,    // %left
+    Derecha,      // %right
+    NoAsociativa, // %nonassoc
+}
+
+// Precedencia de un terminal: las líneas `%left`/`%right`/`%nonassoc`
+// posteriores en el archivo tienen mayor nivel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Precedencia {
+    pub nivel: usize,
+    pub asociatividad: Asociatividad,
+}
+
 // Gramatica
 #[derive(Debug, Clone, Default)]
 pub struct Gramatica {
@@ -27,4 +62,16 @@ pub struct Gramatica {
     pub simbolos_terminales: HashSet<Simbolo>,
     pub simbolos_no_terminales: HashSet<Simbolo>,
     pub regla0: String,
+    pub resoluciones: Vec<Resolucion>,
+    pub precedencias: HashMap<String, Precedencia>,
+}
+
+impl Gramatica {
+    // Precedencia de una producción: la del último terminal de su cuerpo que la declare
+    pub fn precedencia_produccion(&self, numero: usize) -> Option<Precedencia> {
+        self.producciones[numero].cuerpo.iter().rev().find_map(|simbolo| match simbolo {
+            Simbolo::Terminal(t) => self.precedencias.get(t).copied(),
+            Simbolo::NoTerminal(_) => None,
+        })
+    }
 }
\ No newline at end of file
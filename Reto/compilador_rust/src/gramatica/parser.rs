@@ -1,10 +1,15 @@
-use crate::gramatica::{Gramatica, Simbolo, Produccion};
+use crate::gramatica::{Asociatividad, Gramatica, Precedencia, Simbolo, Produccion, Preferencia, Resolucion};
 
 /// Parsea el contenido de un archivo de gramática y retorna la gramática aumentada
+///
+/// Además de producciones acepta directivas `%resolver` (ver `parsear_resolucion`)
+/// y de precedencia `%left`, `%right` y `%nonassoc` (ver `parsear_precedencia`)
 pub fn parsear_gramatica(contenido: &str) -> Result<Gramatica, String> {
     let mut gramatica = Gramatica::default();
     let mut primera_cabeza: Option<String> = None;
     let mut producciones = Vec::new();
+    let mut resoluciones = Vec::new();
+    let mut precedencias = Vec::new();
 
     // Procesar cada línea del archivo
     for linea in contenido.lines() {
@@ -15,6 +20,19 @@ pub fn parsear_gramatica(contenido: &str) -> Result<Gramatica, String> {
             continue;
         }
 
+        // Las directivas se resuelven cuando ya se conocen todas las producciones
+        if let Some(directiva) = linea.strip_prefix("%resolver") {
+            resoluciones.push(directiva.trim().to_string());
+            continue;
+        }
+        if ["%left", "%right", "%nonassoc"].iter().any(|d| linea.split_whitespace().next() == Some(*d)) {
+            precedencias.push(linea.to_string());
+            continue;
+        }
+        if linea.starts_with('%') {
+            return Err(format!("Directiva desconocida: {}", linea));
+        }
+
         // Dividir en cabeza → cuerpo
         let partes: Vec<&str> = linea.split("→").collect();
         if partes.len() != 2 {
@@ -60,9 +78,87 @@ pub fn parsear_gramatica(contenido: &str) -> Result<Gramatica, String> {
         });
     }
 
+    for (i, directiva) in precedencias.iter().enumerate() {
+        parsear_precedencia(directiva, i + 1, &mut gramatica)?;
+    }
+
+    for directiva in resoluciones {
+        let resolucion = parsear_resolucion(&directiva, &gramatica)?;
+        gramatica.resoluciones.push(resolucion);
+    }
+
     Ok(gramatica)
 }
 
+/// Parsea una directiva `%resolver` (sin el prefijo):
+///
+/// - `%resolver <terminal> shift`: con ese lookahead se prefiere el shift
+/// - `%resolver <terminal> <A> → α`: con ese lookahead se prefiere reducir `A → α`
+fn parsear_resolucion(directiva: &str, gramatica: &Gramatica) -> Result<Resolucion, String> {
+    let (terminal, resto) = directiva.split_once(char::is_whitespace)
+        .ok_or_else(|| format!("%resolver requiere un terminal y una acción: '%resolver {}'", directiva))?;
+    let resto = resto.trim();
+
+    if !gramatica.simbolos_terminales.contains(&Simbolo::Terminal(terminal.to_string())) && terminal != "$" {
+        return Err(format!("%resolver: '{}' no es un terminal de la gramática", terminal));
+    }
+
+    let preferencia = if resto == "shift" {
+        Preferencia::Shift
+    } else {
+        let (cabeza, cuerpo) = resto.split_once('→')
+            .ok_or_else(|| format!("%resolver: se esperaba 'shift' o una producción: '{}'", resto))?;
+        let cabeza = Simbolo::NoTerminal(cabeza.trim().to_string());
+        let cuerpo: Vec<Simbolo> = match cuerpo.trim() {
+            "ε" => Vec::new(),
+            cuerpo => cuerpo.split_whitespace()
+                .map(|token| if token.starts_with('<') && token.ends_with('>') {
+                    Simbolo::NoTerminal(token.to_string())
+                } else {
+                    Simbolo::Terminal(token.to_string())
+                })
+                .collect(),
+        };
+
+        let produccion = gramatica.producciones.iter()
+            .find(|p| p.cabeza == cabeza && p.cuerpo == cuerpo)
+            .ok_or_else(|| format!("%resolver: la producción '{}' no existe", resto))?;
+        Preferencia::Reduce(produccion.numero)
+    };
+
+    Ok(Resolucion { terminal: terminal.to_string(), preferencia })
+}
+
+/// Parsea una línea `%left`, `%right` o `%nonassoc` seguida de terminales
+///
+/// Todos los terminales de la línea comparten el nivel; cada línea posterior
+/// tiene mayor precedencia (como en yacc)
+fn parsear_precedencia(directiva: &str, nivel: usize, gramatica: &mut Gramatica) -> Result<(), String> {
+    let mut partes = directiva.split_whitespace();
+    let asociatividad = match partes.next() {
+        Some("%left") => Asociatividad::Izquierda,
+        Some("%right") => Asociatividad::Derecha,
+        _ => Asociatividad::NoAsociativa,
+    };
+
+    let terminales: Vec<&str> = partes.collect();
+    if terminales.is_empty() {
+        return Err(format!("{} requiere al menos un terminal", directiva));
+    }
+
+    for terminal in terminales {
+        if !gramatica.simbolos_terminales.contains(&Simbolo::Terminal(terminal.to_string())) {
+            return Err(format!("{}: '{}' no es un terminal de la gramática", directiva, terminal));
+        }
+        let precedencia = Precedencia { nivel, asociatividad };
+        if gramatica.precedencias.insert(terminal.to_string(), precedencia).is_some() {
+            return Err(format!("La precedencia de '{}' se declaró más de una vez", terminal));
+        }
+    }
+
+    Ok(())
+}
+
 /// Parsea el cuerpo de una producción y retorna un vector de símbolos
 fn parsear_cuerpo(cuerpo_str: &str, gramatica: &mut Gramatica) -> Result<Vec<Simbolo>, String> {
     // Producción vacía (epsilon)
@@ -0,0 +1,530 @@
+//! # Tablas SLR
+//!
+//! Construye las tablas ACTION y GOTO a partir del autómata LR(0) y de los
+//! conjuntos FOLLOW (SLR), o a partir de un autómata LR(1)/LALR(1) usando los
+//! lookaheads de cada ítem (`generar_tablas_lr1`). Cada celda de ACTION junta
+//! primero todas sus acciones candidatas; si hay más de una distinta es un conflicto:
+//!
+//! - **shift/reduce**: un ítem `A → α • t β` y un ítem completo `B → γ •` con `t ∈ FOLLOW(B)`
+//! - **reduce/reduce**: dos ítems completos cuyos FOLLOW comparten el terminal
+//!
+//! Un conflicto se resuelve con la directiva `%resolver` de la gramática; si no hay,
+//! un shift/reduce se decide con `%left`/`%right`/`%nonassoc` cuando el terminal y la
+//! producción tienen precedencia (y entonces no se reporta). Si tampoco, como yacc:
+//! shift antes que reduce y la producción con menor número entre reducciones. Los
+//! conflictos resueltos por omisión se reportan para que el generador pueda fallar
+//! en modo estricto.
+
+use crate::gramatica::{Asociatividad, Gramatica, Preferencia, Simbolo};
+use crate::gramatica::{first_follow, lr1};
+use crate::gramatica::lr0::{self, EstadoLR0, ItemLR0};
+use crate::gramatica::lr1::EstadoLR1;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+/// Representa una acción en la tabla ACTION
+#[derive(Debug, Clone, PartialEq)]
+pub enum Accion {
+    Shift(usize),      // Desplazar al estado N
+    Reduce(usize),     // Reducir por la producción N
+    Accept,            // Aceptar
+}
+
+impl fmt::Display for Accion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Accion::Shift(n) => write!(f, "shift {}", n),
+            Accion::Reduce(n) => write!(f, "reduce {}", n),
+            Accion::Accept => write!(f, "accept"),
+        }
+    }
+}
+
+/// Tabla ACTION: (estado, terminal) -> Acción
+pub type TablaAction = HashMap<(usize, String), Accion>;
+
+/// Tabla GOTO: (estado, no-terminal) -> estado_destino
+pub type TablaGoto = HashMap<(usize, String), usize>;
+
+/// Cómo se eligió la acción de una celda en conflicto
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModoResolucion {
+    /// Con una directiva `%resolver` (índice en `Gramatica::resoluciones`)
+    Declarada(usize),
+    /// Sin directiva: shift antes que reduce, la producción menor entre reducciones
+    PorOmision,
+}
+
+/// Conflicto en una celda de la tabla ACTION
+#[derive(Debug, Clone)]
+pub struct Conflicto {
+    pub estado: usize,
+    /// Lookahead de la celda
+    pub terminal: String,
+    /// Acciones en competencia (sin repetir)
+    pub acciones: Vec<Accion>,
+    /// Ítems LR(0) del estado que producen esas acciones
+    pub items: Vec<ItemLR0>,
+    /// Acción que quedó en la tabla
+    pub elegida: Accion,
+    pub resolucion: ModoResolucion,
+}
+
+impl Conflicto {
+    /// `shift/reduce` o `reduce/reduce`
+    pub fn tipo(&self) -> &'static str {
+        if self.acciones.iter().any(|a| matches!(a, Accion::Shift(_))) {
+            "shift/reduce"
+        } else {
+            "reduce/reduce"
+        }
+    }
+
+    /// Reporte legible: estado, lookahead, ítems involucrados y producciones en competencia
+    pub fn describir(&self, gramatica: &Gramatica) -> String {
+        let mut texto = format!(
+            "conflicto {} en el estado {} con '{}':\n",
+            self.tipo(), self.estado, self.terminal
+        );
+        for item in &self.items {
+            texto.push_str(&format!("    {}\n", lr0::item_to_string(item, gramatica)));
+        }
+        for accion in &self.acciones {
+            texto.push_str(&format!("  - {}\n", describir_accion(accion, gramatica)));
+        }
+        let como = match self.resolucion {
+            ModoResolucion::Declarada(_) => "resuelto por %resolver",
+            ModoResolucion::PorOmision => "resuelto por omisión",
+        };
+        texto.push_str(&format!("  {}: {}", como, describir_accion(&self.elegida, gramatica)));
+        texto
+    }
+}
+
+/// Construcción de las tablas ACTION/GOTO
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Algoritmo {
+    Slr,
+    Lalr,
+    Lr1,
+}
+
+impl Algoritmo {
+    pub const TODOS: [Algoritmo; 3] = [Algoritmo::Slr, Algoritmo::Lalr, Algoritmo::Lr1];
+
+    /// `slr`, `lalr` o `lr1`
+    pub fn desde_nombre(nombre: &str) -> Option<Self> {
+        match nombre {
+            "slr" => Some(Algoritmo::Slr),
+            "lalr" => Some(Algoritmo::Lalr),
+            "lr1" => Some(Algoritmo::Lr1),
+            _ => None,
+        }
+    }
+
+    pub fn nombre(self) -> &'static str {
+        match self {
+            Algoritmo::Slr => "SLR(1)",
+            Algoritmo::Lalr => "LALR(1)",
+            Algoritmo::Lr1 => "LR(1)",
+        }
+    }
+}
+
+/// Calcula FIRST/FOLLOW, el autómata del algoritmo y sus tablas
+pub fn generar_tablas(gramatica: &Gramatica, algoritmo: Algoritmo) -> TablasSLR {
+    let first_sets = first_follow::calcular_first_sets(gramatica);
+    match algoritmo {
+        Algoritmo::Slr => {
+            let follow_sets = first_follow::calcular_follow_sets(gramatica, &first_sets);
+            let (estados, transiciones) = lr0::construir_automata(gramatica);
+            generar_tablas_slr(&estados, &transiciones, gramatica, &follow_sets)
+        }
+        Algoritmo::Lalr => {
+            let (estados, transiciones) = lr1::construir_automata_lalr(gramatica, &first_sets);
+            generar_tablas_lr1(&estados, &transiciones, gramatica)
+        }
+        Algoritmo::Lr1 => {
+            let (estados, transiciones) = lr1::construir_automata(gramatica, &first_sets);
+            generar_tablas_lr1(&estados, &transiciones, gramatica)
+        }
+    }
+}
+
+/// Resultado de `generar_tablas_slr` / `generar_tablas_lr1`
+#[derive(Debug, Clone, Default)]
+pub struct TablasSLR {
+    /// Número de estados del autómata
+    pub estados: usize,
+    pub action: TablaAction,
+    pub goto: TablaGoto,
+    /// Conflictos ordenados por estado y terminal
+    pub conflictos: Vec<Conflicto>,
+    /// Directivas `%resolver` que no aplicaron a ningún conflicto (índices)
+    pub resoluciones_sin_usar: Vec<usize>,
+}
+
+impl TablasSLR {
+    /// Conflictos que ninguna directiva `%resolver` declaró como intencionales
+    pub fn conflictos_sin_declarar(&self) -> impl Iterator<Item = &Conflicto> {
+        self.conflictos.iter().filter(|c| c.resolucion == ModoResolucion::PorOmision)
+    }
+}
+
+/// Genera las tablas ACTION y GOTO para el analizador SLR
+///
+/// Un ítem completo `A → α •` reduce con cada terminal de FOLLOW(A)
+pub fn generar_tablas_slr(
+    estados: &[EstadoLR0],
+    transiciones: &HashMap<(usize, Simbolo), usize>,
+    gramatica: &Gramatica,
+    follow_sets: &HashMap<String, HashSet<String>>,
+) -> TablasSLR {
+    let estados: Vec<ItemsConLookahead> = estados.iter()
+        .map(|estado| estado.iter()
+            .map(|item| {
+                let produccion = &gramatica.producciones[item.regla_id];
+                let lookaheads = if item.es_completo(produccion) {
+                    follow_sets.get(&extraer_string(&produccion.cabeza)).cloned().unwrap_or_default()
+                } else {
+                    HashSet::new()
+                };
+                (item.clone(), lookaheads)
+            })
+            .collect())
+        .collect();
+
+    construir_tablas(&estados, transiciones, gramatica)
+}
+
+/// Genera las tablas ACTION y GOTO a partir de un autómata LR(1) o LALR(1)
+///
+/// Un ítem completo `[A → α •, a]` reduce solo con su lookahead `a`
+pub fn generar_tablas_lr1(
+    estados: &[EstadoLR1],
+    transiciones: &HashMap<(usize, Simbolo), usize>,
+    gramatica: &Gramatica,
+) -> TablasSLR {
+    let estados: Vec<ItemsConLookahead> = estados.iter()
+        .map(|estado| {
+            // Agrupar los ítems por núcleo LR(0)
+            let mut por_nucleo: HashMap<ItemLR0, HashSet<String>> = HashMap::new();
+            for item in estado {
+                por_nucleo.entry(item.nucleo()).or_default().insert(item.lookahead.clone());
+            }
+            por_nucleo.into_iter().collect()
+        })
+        .collect();
+
+    construir_tablas(&estados, transiciones, gramatica)
+}
+
+/// Ítems de un estado con los terminales con que reduce cada uno (solo los completos)
+type ItemsConLookahead = Vec<(ItemLR0, HashSet<String>)>;
+
+/// Llena ACTION y GOTO y resuelve los conflictos; común a SLR, LALR(1) y LR(1)
+fn construir_tablas(
+    estados: &[ItemsConLookahead],
+    transiciones: &HashMap<(usize, Simbolo), usize>,
+    gramatica: &Gramatica,
+) -> TablasSLR {
+    let mut tablas = TablasSLR { estados: estados.len(), ..TablasSLR::default() };
+    let mut resoluciones_usadas = HashSet::new();
+
+    // Para cada estado
+    for (idx_estado, estado) in estados.iter().enumerate() {
+        // Candidatas por terminal, con los ítems que las producen
+        let mut candidatas: BTreeMap<String, Vec<(Accion, ItemLR0)>> = BTreeMap::new();
+
+        // Para cada ítem en el estado
+        for (item, lookaheads) in estado {
+            let produccion = &gramatica.producciones[item.regla_id];
+
+            // Verificar si el punto está al final (ítem completo)
+            if item.es_completo(produccion) {
+                // Ítem [A → α•]
+
+                // Caso especial: Regla aumentada [S' → S•]
+                if item.regla_id == 0 {
+                    candidatas.entry("$".to_string()).or_default().push((Accion::Accept, item.clone()));
+                } else {
+                    // Para cada lookahead, ACTION[i, t] = Reduce(regla)
+                    for terminal in lookaheads {
+                        candidatas.entry(terminal.clone()).or_default()
+                            .push((Accion::Reduce(item.regla_id), item.clone()));
+                    }
+                }
+            } else {
+                // El punto no está al final, hay un símbolo después
+                let simbolo_siguiente = &produccion.cuerpo[item.punto];
+
+                // Si hay una transición con este símbolo
+                if let Some(&estado_destino) = transiciones.get(&(idx_estado, simbolo_siguiente.clone())) {
+                    match simbolo_siguiente {
+                        Simbolo::Terminal(t) => {
+                            candidatas.entry(t.clone()).or_default()
+                                .push((Accion::Shift(estado_destino), item.clone()));
+                        }
+                        Simbolo::NoTerminal(nt) => {
+                            tablas.goto.insert((idx_estado, nt.clone()), estado_destino);
+                        }
+                    }
+                }
+            }
+        }
+        for (terminal, candidatas) in candidatas {
+            let mut acciones: Vec<Accion> = Vec::new();
+            for (accion, _) in &candidatas {
+                if !acciones.contains(accion) {
+                    acciones.push(accion.clone());
+                }
+            }
+
+            if acciones.len() == 1 {
+                tablas.action.insert((idx_estado, terminal), acciones.remove(0));
+                continue;
+            }
+
+            // Conflicto: ordenar para un reporte estable (shift primero, luego por producción)
+            acciones.sort_by_key(orden_accion);
+
+            let declarada = resolucion_declarada(gramatica, &terminal, &acciones);
+
+            // Sin %resolver, la precedencia declarada decide sin reportar conflicto;
+            // `%nonassoc` deja la celda vacía (error de sintaxis)
+            if declarada.is_none()
+                && let Some(decision) = decidir_por_precedencia(gramatica, &terminal, &acciones)
+            {
+                if let Some(accion) = decision {
+                    tablas.action.insert((idx_estado, terminal), accion);
+                }
+                continue;
+            }
+
+            let mut items: Vec<ItemLR0> = candidatas.into_iter().map(|(_, item)| item).collect();
+            items.sort_by_key(|item| (item.regla_id, item.punto));
+            items.dedup();
+
+            let (elegida, resolucion) = match declarada {
+                Some((indice, accion)) => {
+                    resoluciones_usadas.insert(indice);
+                    (accion, ModoResolucion::Declarada(indice))
+                }
+                None => (acciones[0].clone(), ModoResolucion::PorOmision),
+            };
+
+            tablas.action.insert((idx_estado, terminal.clone()), elegida.clone());
+            tablas.conflictos.push(Conflicto {
+                estado: idx_estado,
+                terminal,
+                acciones,
+                items,
+                elegida,
+                resolucion,
+            });
+        }
+    }
+
+    tablas.resoluciones_sin_usar = (0..gramatica.resoluciones.len())
+        .filter(|i| !resoluciones_usadas.contains(i))
+        .collect();
+
+    tablas
+}
+
+/// Busca una directiva `%resolver` para el terminal cuya acción esté entre las candidatas
+fn resolucion_declarada(gramatica: &Gramatica, terminal: &str, acciones: &[Accion]) -> Option<(usize, Accion)> {
+    gramatica.resoluciones.iter().enumerate()
+        .filter(|(_, r)| r.terminal == terminal)
+        .find_map(|(indice, r)| {
+            let accion = match r.preferencia {
+                Preferencia::Shift => acciones.iter().find(|a| matches!(a, Accion::Shift(_)))?.clone(),
+                Preferencia::Reduce(n) => acciones.iter().find(|a| **a == Accion::Reduce(n))?.clone(),
+            };
+            Some((indice, accion))
+        })
+}
+
+/// Decide un shift/reduce con `%left`, `%right` y `%nonassoc`
+///
+/// Solo aplica a un shift contra una sola reducción, cuando el terminal y la
+/// producción (su último terminal con precedencia) tienen precedencia declarada.
+/// `Some(None)` significa que la celda queda como error (`%nonassoc`).
+fn decidir_por_precedencia(gramatica: &Gramatica, terminal: &str, acciones: &[Accion]) -> Option<Option<Accion>> {
+    let [shift @ Accion::Shift(_), reduce @ Accion::Reduce(produccion)] = acciones else {
+        return None;
+    };
+    let del_terminal = gramatica.precedencias.get(terminal)?;
+    let de_produccion = gramatica.precedencia_produccion(*produccion)?;
+
+    let decision = match de_produccion.nivel.cmp(&del_terminal.nivel) {
+        Ordering::Greater => Some(reduce.clone()),
+        Ordering::Less => Some(shift.clone()),
+        Ordering::Equal => match del_terminal.asociatividad {
+            Asociatividad::Izquierda => Some(reduce.clone()),
+            Asociatividad::Derecha => Some(shift.clone()),
+            Asociatividad::NoAsociativa => None,
+        },
+    };
+    Some(decision)
+}
+
+/// Orden de preferencia por omisión: accept, shift, y reducciones por número de producción
+fn orden_accion(accion: &Accion) -> (usize, usize) {
+    match accion {
+        Accion::Accept => (0, 0),
+        Accion::Shift(n) => (1, *n),
+        Accion::Reduce(n) => (2, *n),
+    }
+}
+
+/// `shift 12` o `reduce 40: <A> → α`
+fn describir_accion(accion: &Accion, gramatica: &Gramatica) -> String {
+    match accion {
+        Accion::Reduce(n) => {
+            let produccion = &gramatica.producciones[*n];
+            let cuerpo: Vec<String> = produccion.cuerpo.iter().map(extraer_string).collect();
+            let cuerpo = if cuerpo.is_empty() { "ε".to_string() } else { cuerpo.join(" ") };
+            format!("reduce {}: {} → {}", n, extraer_string(&produccion.cabeza), cuerpo)
+        }
+        otra => otra.to_string(),
+    }
+}
+
+/// Extrae el string de un símbolo (terminal o no-terminal)
+fn extraer_string(simbolo: &Simbolo) -> String {
+    match simbolo {
+        Simbolo::Terminal(s) | Simbolo::NoTerminal(s) => s.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gramatica::{first_follow, parser};
+
+    fn tablas_de(fuente: &str) -> (Gramatica, TablasSLR) {
+        let gramatica = parser::parsear_gramatica(fuente).unwrap();
+        let first = first_follow::calcular_first_sets(&gramatica);
+        let follow = first_follow::calcular_follow_sets(&gramatica, &first);
+        let (estados, transiciones) = lr0::construir_automata(&gramatica);
+        let tablas = generar_tablas_slr(&estados, &transiciones, &gramatica, &follow);
+        (gramatica, tablas)
+    }
+
+    const SINO_COLGANTE: &str = "\
+<S> → si <S> <SINO>
+<S> → x
+<SINO> → sino <S>
+<SINO> → ε
+";
+
+    #[test]
+    fn test_gramatica_sin_conflictos() {
+        let (_, tablas) = tablas_de("<E> → <E> + id\n<E> → id\n");
+        assert!(tablas.conflictos.is_empty());
+        assert!(tablas.action.values().any(|a| *a == Accion::Accept));
+    }
+
+    #[test]
+    fn test_reporta_shift_reduce() {
+        let (gramatica, tablas) = tablas_de(SINO_COLGANTE);
+
+        assert_eq!(tablas.conflictos.len(), 1);
+        let conflicto = &tablas.conflictos[0];
+        assert_eq!(conflicto.tipo(), "shift/reduce");
+        assert_eq!(conflicto.terminal, "sino");
+        assert_eq!(conflicto.resolucion, ModoResolucion::PorOmision);
+        assert!(matches!(conflicto.elegida, Accion::Shift(_)));
+
+        let reporte = conflicto.describir(&gramatica);
+        assert!(reporte.contains(&format!("estado {} con 'sino'", conflicto.estado)), "{}", reporte);
+        assert!(reporte.contains("<SINO> → • sino <S>"), "{}", reporte);
+        assert!(reporte.contains("reduce 4: <SINO> → ε"), "{}", reporte);
+        assert_eq!(tablas.conflictos_sin_declarar().count(), 1);
+    }
+
+    #[test]
+    fn test_reporta_reduce_reduce() {
+        let (_, tablas) = tablas_de("<S> → <A> z\n<S> → <B> z\n<A> → x\n<B> → x\n");
+
+        assert_eq!(tablas.conflictos.len(), 1);
+        assert_eq!(tablas.conflictos[0].tipo(), "reduce/reduce");
+        assert_eq!(tablas.conflictos[0].acciones, vec![Accion::Reduce(3), Accion::Reduce(4)]);
+        assert_eq!(tablas.conflictos[0].elegida, Accion::Reduce(3));
+    }
+
+    #[test]
+    fn test_resolucion_declarada() {
+        let (gramatica, tablas) = tablas_de(&format!("{}%resolver sino <SINO> → ε\n%resolver x shift\n", SINO_COLGANTE));
+
+        let conflicto = &tablas.conflictos[0];
+        assert_eq!(conflicto.resolucion, ModoResolucion::Declarada(0));
+        assert_eq!(conflicto.elegida, Accion::Reduce(4));
+        assert_eq!(tablas.conflictos_sin_declarar().count(), 0);
+        assert_eq!(tablas.resoluciones_sin_usar, vec![1]);
+        assert!(conflicto.describir(&gramatica).contains("resuelto por %resolver: reduce 4"));
+    }
+
+    const AMBIGUA: &str = "\
+<E> → <E> + <E>
+<E> → <E> * <E>
+<E> → <E> ^ <E>
+<E> → <E> < <E>
+<E> → id
+";
+
+    /// Acción de la celda en el estado con el ítem completo de `produccion`
+    fn accion_tras(gramatica: &Gramatica, tablas: &TablasSLR, produccion: usize, terminal: &str) -> Option<Accion> {
+        let (estados, _) = lr0::construir_automata(gramatica);
+        let estado = estados.iter()
+            .position(|e| e.contains(&ItemLR0 { regla_id: produccion, punto: 3 }))
+            .unwrap();
+        tablas.action.get(&(estado, terminal.to_string())).cloned()
+    }
+
+    #[test]
+    fn test_precedencia_resuelve_gramatica_ambigua() {
+        let (_, sin_precedencia) = tablas_de(AMBIGUA);
+        assert_eq!(sin_precedencia.conflictos.len(), 16);
+
+        let (gramatica, tablas) = tablas_de(&format!("%nonassoc <\n%left +\n%left *\n%right ^\n{}", AMBIGUA));
+        assert!(tablas.conflictos.is_empty());
+
+        // E + E • : reduce con + (izquierda) y antes de < (menor); shift con * y ^ (mayores)
+        assert_eq!(accion_tras(&gramatica, &tablas, 1, "+"), Some(Accion::Reduce(1)));
+        assert_eq!(accion_tras(&gramatica, &tablas, 1, "<"), Some(Accion::Reduce(1)));
+        assert!(matches!(accion_tras(&gramatica, &tablas, 1, "*"), Some(Accion::Shift(_))));
+        // E ^ E • : shift con ^ (derecha)
+        assert!(matches!(accion_tras(&gramatica, &tablas, 3, "^"), Some(Accion::Shift(_))));
+        // E < E • con < : %nonassoc deja la celda vacía
+        assert_eq!(accion_tras(&gramatica, &tablas, 4, "<"), None);
+    }
+
+    #[test]
+    fn test_resolver_tiene_prioridad_sobre_precedencia() {
+        let (_, tablas) = tablas_de(&format!("%left +\n{}%resolver + shift\n", AMBIGUA));
+
+        // Con + la directiva gana (y se reporta); con los demás terminales sin precedencia, omisión
+        assert!(tablas.conflictos.iter().filter(|c| c.terminal == "+")
+            .all(|c| matches!(c.resolucion, ModoResolucion::Declarada(0))));
+        assert!(tablas.resoluciones_sin_usar.is_empty());
+    }
+
+    #[test]
+    fn test_directivas_invalidas() {
+        for (fuente, error) in [
+            ("<S> → x\n%resolver y shift\n", "no es un terminal"),
+            ("<S> → x\n%resolver x <S> → y\n", "no existe"),
+            ("<S> → x\n%resolver x reduce\n", "se esperaba 'shift'"),
+            ("<S> → x\n%izquierda x\n", "Directiva desconocida"),
+            ("<S> → x\n%left y\n", "no es un terminal"),
+            ("<S> → x\n%left\n", "al menos un terminal"),
+            ("<S> → x\n%left x\n%right x\n", "más de una vez"),
+        ] {
+            let resultado = parser::parsear_gramatica(fuente).unwrap_err();
+            assert!(resultado.contains(error), "{}", resultado);
+        }
+    }
+}
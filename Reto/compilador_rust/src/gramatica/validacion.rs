@@ -0,0 +1,249 @@
+//! # Validación de la gramática
+//!
+//! `parsear_gramatica` acepta cualquier símbolo, así que un nombre mal escrito
+//! crea en silencio un no-terminal desconectado. `validar_gramatica` revisa:
+//!
+//! - **Errores**: no-terminales usados pero sin producciones, no-terminales
+//!   improductivos (no derivan ninguna cadena de terminales) y producciones duplicadas
+//! - **Advertencias**: no-terminales inalcanzables desde el símbolo inicial y
+//!   nombres que solo difieren en acentos o mayúsculas
+
+use crate::gramatica::{Gramatica, Simbolo};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt;
+
+/// Gravedad de un problema de la gramática
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severidad {
+    Error,
+    Advertencia,
+}
+
+/// Problema encontrado al validar la gramática
+#[derive(Debug, Clone, PartialEq)]
+pub struct Problema {
+    pub severidad: Severidad,
+    pub mensaje: String,
+}
+
+impl fmt::Display for Problema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.severidad {
+            Severidad::Error => write!(f, "error: {}", self.mensaje),
+            Severidad::Advertencia => write!(f, "advertencia: {}", self.mensaje),
+        }
+    }
+}
+
+/// Valida la gramática y regresa los problemas encontrados (errores primero)
+pub fn validar_gramatica(gramatica: &Gramatica) -> Vec<Problema> {
+    // La regla aumentada no se reporta: depende por completo del símbolo inicial
+    let definidos: BTreeSet<&str> = gramatica.producciones.iter()
+        .map(|p| nombre(&p.cabeza))
+        .filter(|cabeza| *cabeza != gramatica.regla0)
+        .collect();
+    let parecidos = nombres_parecidos(gramatica);
+    let sugerencia = |nombre: &str| match parecidos.get(nombre) {
+        Some(otros) => format!(" (¿quisiste decir {}?)", otros.join(" o ")),
+        None => String::new(),
+    };
+
+    let mut problemas = Vec::new();
+    let error = |mensaje: String| Problema { severidad: Severidad::Error, mensaje };
+    let advertencia = |mensaje: String| Problema { severidad: Severidad::Advertencia, mensaje };
+
+    // No-terminales usados sin producciones
+    let mut sin_definir: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+    for produccion in &gramatica.producciones {
+        for simbolo in &produccion.cuerpo {
+            if let Simbolo::NoTerminal(nt) = simbolo
+                && !definidos.contains(nt.as_str())
+            {
+                sin_definir.entry(nt).or_default().push(produccion.numero);
+            }
+        }
+    }
+    for (nt, producciones) in &sin_definir {
+        problemas.push(error(format!(
+            "{} se usa en la producción {} pero no tiene producciones{}",
+            nt, lista(producciones), sugerencia(nt)
+        )));
+    }
+
+    // No-terminales improductivos
+    let productivos = no_terminales_productivos(gramatica);
+    for nt in definidos.iter().filter(|nt| !productivos.contains(*nt)) {
+        problemas.push(error(format!("{} no deriva ninguna cadena de terminales (improductivo)", nt)));
+    }
+
+    // Producciones duplicadas
+    let mut vistas: BTreeMap<(&str, Vec<&str>), Vec<usize>> = BTreeMap::new();
+    for produccion in &gramatica.producciones {
+        let cuerpo = produccion.cuerpo.iter().map(nombre).collect();
+        vistas.entry((nombre(&produccion.cabeza), cuerpo)).or_default().push(produccion.numero);
+    }
+    for ((cabeza, cuerpo), numeros) in vistas.iter().filter(|(_, numeros)| numeros.len() > 1) {
+        let cuerpo = if cuerpo.is_empty() { "ε".to_string() } else { cuerpo.join(" ") };
+        problemas.push(error(format!("la producción {} → {} está duplicada (producciones {})", cabeza, cuerpo, lista(numeros))));
+    }
+
+    // No-terminales inalcanzables desde el símbolo inicial
+    let alcanzables = no_terminales_alcanzables(gramatica);
+    for nt in definidos.iter().filter(|nt| !alcanzables.contains(*nt)) {
+        problemas.push(advertencia(format!("{} no es alcanzable desde el símbolo inicial{}", nt, sugerencia(nt))));
+    }
+
+    // Nombres que solo difieren en acentos o mayúsculas
+    let mut grupos_reportados = HashSet::new();
+    for (nombre, otros) in &parecidos {
+        let mut grupo: Vec<&str> = otros.iter().map(|s| s.as_str()).chain([nombre.as_str()]).collect();
+        grupo.sort();
+        if grupos_reportados.insert(grupo.clone()) {
+            problemas.push(advertencia(format!(
+                "{} solo difieren en acentos o mayúsculas",
+                grupo.join(", ")
+            )));
+        }
+    }
+
+    problemas
+}
+
+/// No-terminales que derivan alguna cadena de terminales (punto fijo)
+fn no_terminales_productivos(gramatica: &Gramatica) -> HashSet<&str> {
+    let mut productivos = HashSet::new();
+    let mut cambio = true;
+
+    while cambio {
+        cambio = false;
+        for produccion in &gramatica.producciones {
+            let cabeza = nombre(&produccion.cabeza);
+            if productivos.contains(cabeza) {
+                continue;
+            }
+            let productiva = produccion.cuerpo.iter().all(|simbolo| match simbolo {
+                Simbolo::Terminal(_) => true,
+                Simbolo::NoTerminal(nt) => productivos.contains(nt.as_str()),
+            });
+            if productiva {
+                productivos.insert(cabeza);
+                cambio = true;
+            }
+        }
+    }
+
+    productivos
+}
+
+/// No-terminales alcanzables desde la regla aumentada
+fn no_terminales_alcanzables(gramatica: &Gramatica) -> HashSet<&str> {
+    let mut alcanzables = HashSet::from([gramatica.regla0.as_str()]);
+    let mut pendientes = vec![gramatica.regla0.as_str()];
+
+    while let Some(actual) = pendientes.pop() {
+        for produccion in gramatica.producciones.iter().filter(|p| nombre(&p.cabeza) == actual) {
+            for simbolo in &produccion.cuerpo {
+                if let Simbolo::NoTerminal(nt) = simbolo
+                    && alcanzables.insert(nt.as_str())
+                {
+                    pendientes.push(nt.as_str());
+                }
+            }
+        }
+    }
+
+    alcanzables
+}
+
+/// Para cada símbolo, los otros del mismo tipo con el mismo nombre normalizado
+fn nombres_parecidos(gramatica: &Gramatica) -> BTreeMap<String, Vec<String>> {
+    let mut grupos: BTreeMap<(bool, String), BTreeSet<&str>> = BTreeMap::new();
+    for simbolo in gramatica.simbolos_terminales.iter().chain(&gramatica.simbolos_no_terminales) {
+        let es_terminal = matches!(simbolo, Simbolo::Terminal(_));
+        grupos.entry((es_terminal, normalizar(nombre(simbolo)))).or_default().insert(nombre(simbolo));
+    }
+
+    let mut parecidos = BTreeMap::new();
+    for grupo in grupos.values().filter(|grupo| grupo.len() > 1) {
+        for nombre in grupo {
+            let otros = grupo.iter().filter(|otro| *otro != nombre).map(|s| s.to_string()).collect();
+            parecidos.insert(nombre.to_string(), otros);
+        }
+    }
+    parecidos
+}
+
+/// Minúsculas y sin acentos: `<EXPRESIÓN_OPT>` y `<Expresion_Opt>` se normalizan igual
+fn normalizar(nombre: &str) -> String {
+    nombre.to_lowercase().chars()
+        .map(|c| match c {
+            'á' | 'à' | 'ä' => 'a',
+            'é' | 'è' | 'ë' => 'e',
+            'í' | 'ì' | 'ï' => 'i',
+            'ó' | 'ò' | 'ö' => 'o',
+            'ú' | 'ù' | 'ü' => 'u',
+            'ñ' => 'n',
+            otro => otro,
+        })
+        .collect()
+}
+
+fn nombre(simbolo: &Simbolo) -> &str {
+    match simbolo {
+        Simbolo::Terminal(s) | Simbolo::NoTerminal(s) => s,
+    }
+}
+
+fn lista(numeros: &[usize]) -> String {
+    numeros.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gramatica::parser;
+
+    fn problemas_de(fuente: &str) -> Vec<String> {
+        validar_gramatica(&parser::parsear_gramatica(fuente).unwrap())
+            .iter()
+            .map(|p| p.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_gramatica_del_proyecto_es_valida() {
+        assert_eq!(problemas_de(include_str!("../../gramatica.txt")), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_no_terminal_sin_definir_y_acento() {
+        let problemas = problemas_de("<S> → ( <EXPRESIÓN_OPT> )\n<EXPRESION_OPT> → x\n");
+
+        assert_eq!(problemas, vec![
+            "error: <EXPRESIÓN_OPT> se usa en la producción 1 pero no tiene producciones (¿quisiste decir <EXPRESION_OPT>?)",
+            "error: <S> no deriva ninguna cadena de terminales (improductivo)",
+            "advertencia: <EXPRESION_OPT> no es alcanzable desde el símbolo inicial (¿quisiste decir <EXPRESIÓN_OPT>?)",
+            "advertencia: <EXPRESION_OPT>, <EXPRESIÓN_OPT> solo difieren en acentos o mayúsculas",
+        ]);
+    }
+
+    #[test]
+    fn test_improductivo_duplicado_e_inalcanzable() {
+        let problemas = problemas_de("\
+<S> → <A> x
+<S> → <B>
+<A> → a
+<A> → a
+<B> → b <B>
+<C> → c
+<S> → Y
+<S> → y
+");
+
+        assert!(problemas.contains(&"error: <B> no deriva ninguna cadena de terminales (improductivo)".to_string()), "{:?}", problemas);
+        assert!(problemas.contains(&"error: la producción <A> → a está duplicada (producciones 3, 4)".to_string()), "{:?}", problemas);
+        assert!(problemas.contains(&"advertencia: <C> no es alcanzable desde el símbolo inicial".to_string()), "{:?}", problemas);
+        assert!(problemas.contains(&"advertencia: Y, y solo difieren en acentos o mayúsculas".to_string()), "{:?}", problemas);
+        assert_eq!(problemas.len(), 4, "{:?}", problemas);
+    }
+}
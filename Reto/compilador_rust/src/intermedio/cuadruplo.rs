@@ -12,14 +12,23 @@ pub enum OperadorCuadruplo {
     Suma,           // +
     Resta,          // -
     Multiplicacion, // *
-    Division,       // /
+    Division,       // / (entera entre enteros)
+    Modulo,         // %
+    Negativo,       // neg (- unario)
 
     // Relacionales
     MayorQue,       // >
     MenorQue,       // <
+    MayorIgual,     // >=
+    MenorIgual,     // <=
     Igual,          // ==
     Diferente,      // !=
 
+    // Lógicos
+    Y,              // y (los estatutos usan cortocircuito con GOTOF)
+    O,              // o (los estatutos usan cortocircuito con GOTOV)
+    No,             // no (unario)
+
     // Asignación
     Asignacion,     // =
 
@@ -32,6 +41,12 @@ pub enum OperadorCuadruplo {
     GotoF,          // goto falso (condicional)
     GotoV,          // goto verdadero (condicional)
 
+    // Arreglos
+    Verifica,       // verifica índice dentro de rango
+
+    // Ciclo para
+    VerificaPaso,   // verifica que el paso no sea 0
+
     // Funciones
     Era,            // Activation Record
     Parametro,      // Paso de parámetro
@@ -42,22 +57,32 @@ pub enum OperadorCuadruplo {
 
 impl OperadorCuadruplo {
     /// Convierte una cadena a un OperadorCuadruplo
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "+" => Some(OperadorCuadruplo::Suma),
             "-" => Some(OperadorCuadruplo::Resta),
             "*" => Some(OperadorCuadruplo::Multiplicacion),
             "/" => Some(OperadorCuadruplo::Division),
+            "%" => Some(OperadorCuadruplo::Modulo),
+            "neg" => Some(OperadorCuadruplo::Negativo),
             ">" => Some(OperadorCuadruplo::MayorQue),
             "<" => Some(OperadorCuadruplo::MenorQue),
+            ">=" => Some(OperadorCuadruplo::MayorIgual),
+            "<=" => Some(OperadorCuadruplo::MenorIgual),
             "==" => Some(OperadorCuadruplo::Igual),
             "!=" => Some(OperadorCuadruplo::Diferente),
+            "y" => Some(OperadorCuadruplo::Y),
+            "o" => Some(OperadorCuadruplo::O),
+            "no" => Some(OperadorCuadruplo::No),
             "=" => Some(OperadorCuadruplo::Asignacion),
             "lee" => Some(OperadorCuadruplo::Lectura),
             "escribe" => Some(OperadorCuadruplo::Escritura),
             "goto" => Some(OperadorCuadruplo::Goto),
             "gotof" => Some(OperadorCuadruplo::GotoF),
             "gotov" => Some(OperadorCuadruplo::GotoV),
+            "ver" => Some(OperadorCuadruplo::Verifica),
+            "verpaso" => Some(OperadorCuadruplo::VerificaPaso),
             "era" => Some(OperadorCuadruplo::Era),
             "param" => Some(OperadorCuadruplo::Parametro),
             "gosub" => Some(OperadorCuadruplo::GoSub),
@@ -76,10 +101,16 @@ impl OperadorCuadruplo {
             Operador::Resta => OperadorCuadruplo::Resta,
             Operador::Multiplicacion => OperadorCuadruplo::Multiplicacion,
             Operador::Division => OperadorCuadruplo::Division,
+            Operador::Modulo => OperadorCuadruplo::Modulo,
             Operador::MayorQue => OperadorCuadruplo::MayorQue,
             Operador::MenorQue => OperadorCuadruplo::MenorQue,
+            Operador::MayorIgual => OperadorCuadruplo::MayorIgual,
+            Operador::MenorIgual => OperadorCuadruplo::MenorIgual,
             Operador::Igual => OperadorCuadruplo::Igual,
             Operador::Diferente => OperadorCuadruplo::Diferente,
+            Operador::Y => OperadorCuadruplo::Y,
+            Operador::O => OperadorCuadruplo::O,
+            Operador::No => OperadorCuadruplo::No,
             Operador::Asignacion => OperadorCuadruplo::Asignacion,
         }
     }
@@ -92,16 +123,25 @@ impl fmt::Display for OperadorCuadruplo {
             OperadorCuadruplo::Resta => "-",
             OperadorCuadruplo::Multiplicacion => "*",
             OperadorCuadruplo::Division => "/",
+            OperadorCuadruplo::Modulo => "%",
+            OperadorCuadruplo::Negativo => "neg",
             OperadorCuadruplo::MayorQue => ">",
             OperadorCuadruplo::MenorQue => "<",
+            OperadorCuadruplo::MayorIgual => ">=",
+            OperadorCuadruplo::MenorIgual => "<=",
             OperadorCuadruplo::Igual => "==",
             OperadorCuadruplo::Diferente => "!=",
+            OperadorCuadruplo::Y => "y",
+            OperadorCuadruplo::O => "o",
+            OperadorCuadruplo::No => "no",
             OperadorCuadruplo::Asignacion => "=",
             OperadorCuadruplo::Lectura => "lee",
             OperadorCuadruplo::Escritura => "escribe",
             OperadorCuadruplo::Goto => "goto",
             OperadorCuadruplo::GotoF => "gotof",
             OperadorCuadruplo::GotoV => "gotov",
+            OperadorCuadruplo::Verifica => "ver",
+            OperadorCuadruplo::VerificaPaso => "verpaso",
             OperadorCuadruplo::Era => "era",
             OperadorCuadruplo::Parametro => "param",
             OperadorCuadruplo::GoSub => "gosub",
@@ -133,6 +173,8 @@ pub enum Operando {
     Pendiente,
     /// Letrero (índice en tabla de strings, o el string directamente para compatibilidad)
     Letrero(usize),
+    /// Apuntador: dirección de un temporal que contiene la dirección real (acceso a arreglos)
+    Apuntador(usize),
 }
 
 impl fmt::Display for Operando {
@@ -147,6 +189,7 @@ impl fmt::Display for Operando {
             Operando::Etiqueta(dir) => write!(f, "L{}", dir),
             Operando::Pendiente => write!(f, "?"),
             Operando::Letrero(idx) => write!(f, "STR[{}]", idx),
+            Operando::Apuntador(dir) => write!(f, "(@{})", dir),
         }
     }
 }
@@ -214,8 +257,9 @@ mod tests {
     fn test_operando_display() {
         assert_eq!(Operando::Variable("x".to_string()).to_string(), "x");
         assert_eq!(Operando::ConstanteEntera(42).to_string(), "42");
-        assert_eq!(Operando::ConstanteFlotante(3.14).to_string(), "3.14");
+        assert_eq!(Operando::ConstanteFlotante(2.5).to_string(), "2.5");
         assert_eq!(Operando::Temporal(5).to_string(), "t5");
         assert_eq!(Operando::Vacio.to_string(), "-");
+        assert_eq!(Operando::Apuntador(21000).to_string(), "(@21000)");
     }
 }
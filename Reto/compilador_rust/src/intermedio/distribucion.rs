@@ -0,0 +1,259 @@
+//! # Distribución de Memoria
+//!
+//! Descriptor único del esquema de direcciones virtuales. Lo usan el generador
+//! (`MemoriaVirtual`, para asignar direcciones) y la máquina virtual (para
+//! traducir direcciones a segmento, offset y tipo), y viaja dentro del
+//! `ProgramaObjeto` para que la VM rechace programas compilados con otro esquema.
+//!
+//! Los segmentos se colocan uno tras otro a partir de `base`, y cada uno se
+//! divide en un rango por tipo (entero, flotante, char, booleano, letrero) con
+//! `casillas` espacios. Con la distribución por defecto (base 1000, 2000 casillas):
+//!
+//! ```text
+//! GLOBAL    (1000-10999):  Enteros 1000-2999,   Flotantes 3000-4999,   Chars 5000-6999,
+//!                          Booleanos 7000-8999,   Letreros 9000-10999
+//! LOCAL     (11000-20999): Enteros 11000-12999, Flotantes 13000-14999, Chars 15000-16999,
+//!                          Booleanos 17000-18999, Letreros 19000-20999
+//! TEMPORAL  (21000-30999): Enteros 21000-22999, Flotantes 23000-24999, Chars 25000-26999,
+//!                          Booleanos 27000-28999, Letreros 29000-30999
+//! CONSTANTE (31000-40999): Enteros 31000-32999, Flotantes 33000-34999, Chars 35000-36999,
+//!                          Booleanos 37000-38999, Letreros 39000-40999
+//! ```
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use crate::semantico::TipoDato;
+
+/// Primera dirección virtual de la distribución por defecto
+pub const BASE_POR_DEFECTO: usize = 1000;
+
+/// Casillas por tipo en cada segmento de la distribución por defecto
+pub const CASILLAS_POR_DEFECTO: usize = 2000;
+
+/// Tipos con rango propio dentro de cada segmento, en orden de dirección
+pub const TIPOS_EN_MEMORIA: [TipoDato; 5] = [
+    TipoDato::Entero,
+    TipoDato::Flotante,
+    TipoDato::Char,
+    TipoDato::Booleano,
+    TipoDato::Letrero,
+];
+
+/// Tipo de segmento de memoria
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TipoSegmento {
+    Global,
+    Local,
+    Temporal,
+    Constante,
+}
+
+impl TipoSegmento {
+    /// Todos los segmentos, en orden de dirección
+    pub const TODOS: [TipoSegmento; 4] = [
+        TipoSegmento::Global,
+        TipoSegmento::Local,
+        TipoSegmento::Temporal,
+        TipoSegmento::Constante,
+    ];
+
+    /// Posición del segmento en `TODOS`
+    pub(crate) fn indice(self) -> usize {
+        self as usize
+    }
+
+    /// Nombre en minúsculas (`global`, `local`, ...)
+    pub fn nombre(self) -> &'static str {
+        match self {
+            TipoSegmento::Global => "global",
+            TipoSegmento::Local => "local",
+            TipoSegmento::Temporal => "temporal",
+            TipoSegmento::Constante => "constante",
+        }
+    }
+
+    /// Inverso de `nombre`
+    pub fn desde_nombre(nombre: &str) -> Option<Self> {
+        Self::TODOS.into_iter().find(|s| s.nombre() == nombre)
+    }
+}
+
+/// Posición del rango de un tipo dentro de su segmento (índice en `TIPOS_EN_MEMORIA`)
+pub(crate) fn indice_tipo(tipo: TipoDato) -> usize {
+    match tipo {
+        TipoDato::Entero => 0,
+        TipoDato::Flotante => 1,
+        TipoDato::Char => 2,
+        TipoDato::Booleano => 3,
+        TipoDato::Letrero => 4,
+    }
+}
+
+/// Esquema de direcciones virtuales: dónde empieza cada segmento y cuántas
+/// casillas tiene cada tipo dentro de él
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DistribucionMemoria {
+    /// Primera dirección virtual (el primer entero global)
+    pub base: usize,
+
+    /// Casillas por tipo de cada segmento, en el orden de `TipoSegmento::TODOS`
+    pub casillas: [usize; 4],
+}
+
+impl Default for DistribucionMemoria {
+    fn default() -> Self {
+        DistribucionMemoria {
+            base: BASE_POR_DEFECTO,
+            casillas: [CASILLAS_POR_DEFECTO; 4],
+        }
+    }
+}
+
+impl DistribucionMemoria {
+    /// Cambia las casillas por tipo de un segmento (las direcciones de los
+    /// segmentos siguientes se recorren)
+    pub fn con_casillas(mut self, segmento: TipoSegmento, casillas: usize) -> Self {
+        self.casillas[segmento.indice()] = casillas;
+        self
+    }
+
+    /// Casillas por tipo de un segmento
+    pub fn casillas_de(&self, segmento: TipoSegmento) -> usize {
+        self.casillas[segmento.indice()]
+    }
+
+    /// Verifica que la distribución sea utilizable
+    ///
+    /// Las direcciones de todos los segmentos deben caber en `usize`; una vez
+    /// validada, `rango`, `inicio_segmento` y `fin_segmento` no desbordan.
+    pub fn validar(&self) -> Result<(), String> {
+        if let Some(segmento) = TipoSegmento::TODOS.into_iter().find(|s| self.casillas_de(*s) == 0) {
+            return Err(format!("Error: El segmento {} no tiene casillas", segmento.nombre()));
+        }
+        if self.base == 0 {
+            return Err("Error: La dirección base de la memoria debe ser mayor que 0".to_string());
+        }
+        if self.fin_verificado().is_none() {
+            return Err(format!(
+                "Error: La distribución de memoria [{}] excede el espacio de direcciones",
+                self
+            ));
+        }
+        Ok(())
+    }
+
+    /// Dirección siguiente a la última del último segmento, o `None` si no cabe en `usize`
+    fn fin_verificado(&self) -> Option<usize> {
+        TipoSegmento::TODOS.into_iter().try_fold(self.base, |inicio, segmento| {
+            self.casillas_de(segmento)
+                .checked_mul(TIPOS_EN_MEMORIA.len())?
+                .checked_add(inicio)
+        })
+    }
+
+    /// Tamaño total de un segmento (todas sus casillas de todos los tipos)
+    fn tamano_segmento(&self, segmento: TipoSegmento) -> usize {
+        self.casillas_de(segmento) * TIPOS_EN_MEMORIA.len()
+    }
+
+    /// Primera dirección de un segmento
+    pub fn inicio_segmento(&self, segmento: TipoSegmento) -> usize {
+        self.base + TipoSegmento::TODOS[..segmento.indice()]
+            .iter()
+            .map(|s| self.tamano_segmento(*s))
+            .sum::<usize>()
+    }
+
+    /// Última dirección de un segmento
+    pub fn fin_segmento(&self, segmento: TipoSegmento) -> usize {
+        self.inicio_segmento(segmento) + self.tamano_segmento(segmento) - 1
+    }
+
+    /// Rango de direcciones de un tipo dentro de un segmento
+    pub fn rango(&self, segmento: TipoSegmento, tipo: TipoDato) -> RangeInclusive<usize> {
+        let casillas = self.casillas_de(segmento);
+        let inicio = self.inicio_segmento(segmento) + indice_tipo(tipo) * casillas;
+        inicio..=inicio + casillas - 1
+    }
+
+    /// Traduce una dirección virtual a su segmento y su offset dentro de él
+    pub fn traducir(&self, dir: usize) -> Result<(TipoSegmento, usize), String> {
+        TipoSegmento::TODOS
+            .into_iter()
+            .find(|s| (self.inicio_segmento(*s)..=self.fin_segmento(*s)).contains(&dir))
+            .map(|s| (s, dir - self.inicio_segmento(s)))
+            .ok_or_else(|| format!("Error: Dirección virtual {} fuera de rango", dir))
+    }
+
+    /// Segmento al que pertenece una dirección virtual
+    pub fn segmento_de(&self, dir: usize) -> Result<TipoSegmento, String> {
+        self.traducir(dir).map(|(segmento, _)| segmento)
+    }
+
+    /// Tipo de dato de una dirección virtual (según su rango dentro del segmento)
+    pub fn tipo_de(&self, dir: usize) -> Result<TipoDato, String> {
+        let (segmento, offset) = self.traducir(dir)?;
+        Ok(TIPOS_EN_MEMORIA[offset / self.casillas_de(segmento)])
+    }
+}
+
+impl fmt::Display for DistribucionMemoria {
+    /// `base=1000 global=2000 local=2000 temporal=2000 constante=2000`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "base={}", self.base)?;
+        for segmento in TipoSegmento::TODOS {
+            write!(f, " {}={}", segmento.nombre(), self.casillas_de(segmento))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distribucion_por_defecto() {
+        let d = DistribucionMemoria::default();
+
+        assert_eq!(d.rango(TipoSegmento::Global, TipoDato::Entero), 1000..=2999);
+        assert_eq!(d.rango(TipoSegmento::Local, TipoDato::Flotante), 13000..=14999);
+        assert_eq!(d.rango(TipoSegmento::Temporal, TipoDato::Char), 25000..=26999);
+        assert_eq!(d.rango(TipoSegmento::Temporal, TipoDato::Booleano), 27000..=28999);
+        assert_eq!(d.rango(TipoSegmento::Constante, TipoDato::Entero), 31000..=32999);
+        assert_eq!(d.fin_segmento(TipoSegmento::Constante), 40999);
+
+        assert_eq!(d.traducir(11005).unwrap(), (TipoSegmento::Local, 5));
+        assert_eq!(d.tipo_de(33000).unwrap(), TipoDato::Flotante);
+        assert_eq!(d.tipo_de(7000).unwrap(), TipoDato::Booleano);
+        assert_eq!(d.tipo_de(40999).unwrap(), TipoDato::Letrero);
+        assert!(d.traducir(999).is_err());
+        assert!(d.traducir(41000).is_err());
+    }
+
+    #[test]
+    fn test_distribucion_configurable() {
+        let d = DistribucionMemoria::default().con_casillas(TipoSegmento::Global, 10000);
+
+        // El segmento global crece y los demás se recorren
+        assert_eq!(d.rango(TipoSegmento::Global, TipoDato::Flotante), 11000..=20999);
+        assert_eq!(d.inicio_segmento(TipoSegmento::Local), 51000);
+        assert_eq!(d.tipo_de(51000).unwrap(), TipoDato::Entero);
+        assert_eq!(d.segmento_de(50999).unwrap(), TipoSegmento::Global);
+        assert_ne!(d, DistribucionMemoria::default());
+
+        assert!(d.con_casillas(TipoSegmento::Local, 0).validar().is_err());
+        assert!(d.validar().is_ok());
+        assert_eq!(d.to_string(), "base=1000 global=10000 local=2000 temporal=2000 constante=2000");
+    }
+
+    #[test]
+    fn test_distribucion_desbordada() {
+        // El fin del segmento no cabe en usize: error en lugar de pánico al calcularlo
+        let d = DistribucionMemoria::default().con_casillas(TipoSegmento::Global, 4_000_000_000_000_000_000);
+        assert!(d.validar().unwrap_err().contains("excede el espacio de direcciones"));
+
+        let d = DistribucionMemoria::default().con_casillas(TipoSegmento::Constante, usize::MAX / 5);
+        assert!(d.validar().is_err());
+    }
+}
@@ -7,12 +7,16 @@
 //! - **POper**: Pila de operadores pendientes
 //! - **PilaO**: Pila de operandos pendientes
 //! - **PTypes**: Pila de tipos de operandos
+//! - **PSpans**: Pila de ubicaciones de los operandos en el código fuente
 //! - **Quad**: Cola de cuádruplos generados
 //! - **Pjumps**: Pila de saltos pendientes
 
 use crate::intermedio::{Cuadruplo, OperadorCuadruplo};
 use crate::intermedio::memoria_virtual::{MemoriaVirtual, TipoSegmento};
+use crate::intermedio::DistribucionMemoria;
 use crate::semantico::{CuboSemantico, TipoDato, ContextoSemantico};
+use crate::lexico;
+use crate::diagnostico::{codigos, Diagnostico, Span};
 use crate::intermedio::cuadruplo::Operando;
 use std::collections::{VecDeque, HashMap};
 
@@ -26,6 +30,34 @@ pub struct MetadatosFuncion {
     pub parametros: Vec<(String, TipoDato)>,
 }
 
+/// Operandos de un ciclo `para` abierto (variable de control, límite y paso)
+/// El límite y el paso se evalúan una sola vez, al entrar al ciclo
+#[derive(Debug, Clone)]
+struct ControlPara {
+    nombre: String,
+    variable: Operando,
+    limite: Operando,
+    paso: Operando,
+    signo: SignoPaso,
+    /// `i32::MAX - paso` (o `i32::MIN - paso` si baja): pasarlo desbordaría el incremento
+    margen: Operando,
+}
+
+/// Signo del paso de un ciclo `para`, que decide si se compara con `<=` o `>=`
+#[derive(Debug, Clone)]
+enum SignoPaso {
+    Positivo,
+    Negativo,
+    /// El paso no es constante: el booleano `paso > 0` se calcula al entrar al ciclo
+    AlEjecutar(Operando),
+}
+
+/// Saltos de `rompe` / `continua` pendientes de un ciclo abierto
+#[derive(Debug, Default)]
+struct SaltosCiclo {
+    rompe: Vec<usize>,
+    continua: Vec<usize>,
+}
 
 /// Generador de cuádruplos para código intermedio
 pub struct GeneradorCuadruplos {
@@ -42,14 +74,25 @@ pub struct GeneradorCuadruplos {
     /// Pila de tipos de datos (para verificación semántica)
     ptypes: Vec<TipoDato>,
 
-    /// Pila de saltos pendientes (para if/while)
+    /// Pila de spans de los operandos (paralela a PilaO), para señalar en los
+    /// errores de tipos el operando culpable (None si no tiene ubicación)
+    pspans: Vec<Option<Span>>,
+
+    /// Span del símbolo o producción que se está procesando; es la ubicación
+    /// de los operandos que se apilan
+    span_actual: Option<Span>,
+
+    /// Pila de saltos pendientes (para if/while y cortocircuito de y/o)
     pjumps: Vec<usize>,
 
-    /// Contador de expresiones dentro de condiciones (para saber cuándo generar GOTOF)
-    en_condicion: usize,
+    /// Pila de ciclos `para` abiertos (permite anidarlos)
+    ppara: Vec<ControlPara>,
+
+    /// Pila de ciclos abiertos con sus GOTO de `rompe` / `continua` por rellenar
+    pciclos: Vec<SaltosCiclo>,
 
     // Gestión de memoria
-    /// Sistema de memoria virtual (direcciones 1000-24999) - maneja variables, temporales y constantes
+    /// Sistema de memoria virtual (direcciones 1000-40999) - maneja variables, temporales y constantes
     memoria_virtual: MemoriaVirtual,
 
     // Validación semántica
@@ -70,31 +113,42 @@ pub struct GeneradorCuadruplos {
     /// Tabla de strings literales (para letreros)
     tabla_strings: Vec<String>,
 
-    /// Contador de parámetros durante llamada a función
-    contador_parametros: usize,
+    /// Pila de llamadas en curso: (función llamada, número del siguiente parámetro)
+    /// Es pila porque un argumento puede contener otra llamada
+    pllamadas: Vec<(String, usize)>,
 
-    /// Nombre de función siendo llamada (para tracking de params)
-    funcion_llamada_actual: Option<String>,
+    /// Literal `2147483648` que solo es válido negado (`-2147483648` es
+    /// `i32::MIN`); espera la reducción del `-` unario sin entrar a PilaO
+    literal_sin_signo: Option<String>,
 }
 
 impl GeneradorCuadruplos {
     /// Crea un nuevo generador de cuádruplos
     pub fn new() -> Self {
+        Self::con_distribucion(DistribucionMemoria::default())
+    }
+
+    /// Crea un generador que asigna temporales y constantes con la distribución dada
+    /// (debe ser la misma que la del `ContextoSemantico`)
+    pub fn con_distribucion(distribucion: DistribucionMemoria) -> Self {
         GeneradorCuadruplos {
             poper: Vec::new(),
             pilao: Vec::new(),
             ptypes: Vec::new(),
+            pspans: Vec::new(),
+            span_actual: None,
             quad: VecDeque::new(),
             pjumps: Vec::new(),
-            en_condicion: 0,
-            memoria_virtual: MemoriaVirtual::new(),
+            ppara: Vec::new(),
+            pciclos: Vec::new(),
+            memoria_virtual: MemoriaVirtual::con_distribucion(distribucion),
             cubo_semantico: CuboSemantico::new(),
             contexto: None,
             metadatos_funciones: HashMap::new(),
             funcion_actual: None,
             tabla_strings: Vec::new(),
-            contador_parametros: 0,
-            funcion_llamada_actual: None,
+            pllamadas: Vec::new(),
+            literal_sin_signo: None,
         }
     }
 
@@ -103,44 +157,91 @@ impl GeneradorCuadruplos {
         self.contexto = Some(contexto as *const ContextoSemantico);
     }
 
+    /// Establece la ubicación de la producción o token que se va a procesar
+    pub fn establecer_span(&mut self, span: Option<Span>) {
+        self.span_actual = span;
+    }
+
     /// Obtiene una referencia al contexto semántico
-    fn obtener_contexto(&self) -> Result<&ContextoSemantico, String> {
+    fn obtener_contexto(&self) -> Result<&ContextoSemantico, Diagnostico> {
         unsafe {
             self.contexto
                 .map(|ptr| &*ptr)
-                .ok_or_else(|| "Error: Contexto semántico no establecido".to_string())
+                .ok_or_else(|| Diagnostico::interno("Error: Contexto semántico no establecido"))
         }
     }
 
     // ==================== PUNTO NEURÁLGICO 1 ====================
     /// PN1: Procesar un identificador o constante
     /// PilaO.Push(id.name) y PTypes.Push(id.type)
-    pub fn procesar_operando(&mut self, nombre: &str) -> Result<(), String> {
+    pub fn procesar_operando(&mut self, nombre: &str) -> Result<(), Diagnostico> {
         // Si es un letrero (string literal entre comillas)
         if nombre.starts_with('"') && nombre.ends_with('"') {
-            // Agregar el string a la tabla y obtener su índice
-            let idx = self.tabla_strings.len();
-            self.tabla_strings.push(nombre.to_string());
-            
+            // Guardar el contenido (sin comillas, con escapes resueltos) en la
+            // tabla de strings, reutilizando el índice si ya existe
+            let contenido = lexico::valor_letrero(nombre)
+                .ok_or_else(|| Diagnostico::interno(format!("Error: Letrero inválido {}", nombre)))?;
+            let idx = match self.tabla_strings.iter().position(|s| *s == contenido) {
+                Some(idx) => idx,
+                None => {
+                    self.tabla_strings.push(contenido);
+                    self.tabla_strings.len() - 1
+                }
+            };
+
             self.pilao.push(Operando::Letrero(idx));
             self.ptypes.push(TipoDato::Letrero);
+            self.pspans.push(self.span_actual);
+            return Ok(());
+        }
+
+        // Si es una constante char ('a', '\n', ...)
+        if nombre.starts_with('\'') {
+            let valor = lexico::valor_char(nombre)
+                .ok_or_else(|| Diagnostico::interno(format!("Error: Constante char inválida {}", nombre)))?;
+            let direccion = self.memoria_virtual.asignar_constante_char(valor).map_err(Diagnostico::memoria_agotada)?;
+            self.pilao.push(Operando::Direccion(direccion));
+            self.ptypes.push(TipoDato::Char);
+            self.pspans.push(self.span_actual);
+            return Ok(());
+        }
+
+        // Si es una constante booleana (verdadero / falso)
+        if nombre == "verdadero" || nombre == "falso" {
+            let direccion = self.memoria_virtual.asignar_constante_booleana(nombre == "verdadero").map_err(Diagnostico::memoria_agotada)?;
+            self.pilao.push(Operando::Direccion(direccion));
+            self.ptypes.push(TipoDato::Booleano);
+            self.pspans.push(self.span_actual);
             return Ok(());
         }
 
         // Si es un número (constante)
         if let Ok(valor_entero) = nombre.parse::<i32>() {
             // Crear constante en memoria y obtener su dirección
-            let direccion = self.memoria_virtual.asignar_constante_entera(valor_entero)?;
+            let direccion = self.memoria_virtual.asignar_constante_entera(valor_entero).map_err(Diagnostico::memoria_agotada)?;
             self.pilao.push(Operando::Direccion(direccion));
             self.ptypes.push(TipoDato::Entero);
+            self.pspans.push(self.span_actual);
             return Ok(());
         }
 
+        // Un literal entero que no cabe en 32 bits no se convierte en flotante
+        if !nombre.is_empty() && nombre.bytes().all(|b| b.is_ascii_digit()) {
+            // La magnitud de i32::MIN se acepta solo si la siguiente reducción
+            // es el `-` unario (ver `verificar_literal_sin_signo`)
+            if nombre.parse::<i64>() == Ok(-(i32::MIN as i64)) && self.literal_sin_signo.is_none() {
+                self.literal_sin_signo = Some(nombre.to_string());
+                return Ok(());
+            }
+            return Err(Self::error_fuera_de_rango(nombre));
+        }
+
         if let Ok(valor_flotante) = nombre.parse::<f64>() {
             // Crear constante en memoria y obtener su dirección
-            let direccion = self.memoria_virtual.asignar_constante_flotante(valor_flotante)?;
+            let direccion = self.memoria_virtual.asignar_constante_flotante(valor_flotante).map_err(Diagnostico::memoria_agotada)?;
             self.pilao.push(Operando::Direccion(direccion));
             self.ptypes.push(TipoDato::Flotante);
+            self.pspans.push(self.span_actual);
             return Ok(());
         }
 
@@ -148,21 +249,113 @@ impl GeneradorCuadruplos {
         let contexto = self.obtener_contexto()?;
         let tipo = contexto.obtener_tipo_variable(nombre)?;
         let direccion = contexto.obtener_direccion_variable(nombre)?;
+        Self::validar_escalar(contexto, nombre)?;
 
         self.pilao.push(Operando::Direccion(direccion));
         self.ptypes.push(tipo);
+        self.pspans.push(self.span_actual);
 
         Ok(())
     }
 
+    /// Error de una constante entera que no cabe en 32 bits
+    fn error_fuera_de_rango(literal: &str) -> Diagnostico {
+        Diagnostico::semantico(format!(
+            "Error semántico: la constante entera {} no cabe en un entero de 32 bits (máximo {})",
+            literal, i32::MAX
+        ))
+    }
+
+    /// Rechaza un literal `2147483648` que no fue negado por la reducción
+    /// inmediata del `-` unario. Se llama antes de cualquier otra acción semántica
+    pub fn verificar_literal_sin_signo(&mut self) -> Result<(), Diagnostico> {
+        match self.literal_sin_signo.take() {
+            Some(literal) => Err(Self::error_fuera_de_rango(&literal)),
+            None => Ok(()),
+        }
+    }
+
+    /// Signo `-` antes de un factor (`<FACTOR> → - <FACTOR>`)
+    /// El operando ya está en PilaO: una constante numérica (`operando` es su
+    /// literal) se reemplaza por su valor negado y cualquier otro factor genera
+    /// (neg, factor, -, t)
+    pub fn generar_negativo(&mut self, operando: &str) -> Result<(), Diagnostico> {
+        // `-2147483648`: el literal quedó pendiente sin entrar a PilaO
+        if self.literal_sin_signo.take().is_some() {
+            let direccion = self.memoria_virtual.asignar_constante_entera(i32::MIN).map_err(Diagnostico::memoria_agotada)?;
+            self.pilao.push(Operando::Direccion(direccion));
+            self.ptypes.push(TipoDato::Entero);
+            self.pspans.push(self.span_actual);
+            return Ok(());
+        }
+
+        let valor = self.pilao.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay operando para negar"))?;
+        let tipo = self.ptypes.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay tipo para negar"))?;
+        let span = self.pspans.pop().flatten();
+
+        let tipo_resultado = self.validar_signo(crate::semantico::cubo_semantico::Operador::Resta, tipo, span)?;
+
+        if operando.parse::<f64>().is_ok() {
+            let negado = match operando.strip_prefix('-') {
+                Some(positivo) => positivo.to_string(),
+                None => format!("-{}", operando),
+            };
+            return self.procesar_operando(&negado);
+        }
+
+        self.liberar_si_temporal(&valor);
+        let dir_temporal = self.memoria_virtual.asignar_variable(tipo_resultado, TipoSegmento::Temporal).map_err(Diagnostico::memoria_agotada)?;
+        let resultado = Operando::Direccion(dir_temporal);
+
+        self.quad.push_back(Cuadruplo::new(
+            OperadorCuadruplo::Negativo,
+            valor,
+            Operando::Vacio,
+            resultado.clone(),
+        ));
+
+        self.pilao.push(resultado);
+        self.ptypes.push(tipo_resultado);
+        self.pspans.push(self.span_actual);
+
+        Ok(())
+    }
+
+    /// Signo `+` antes de un factor (`<FACTOR> → + <FACTOR>`)
+    /// No genera cuádruplos: solo valida que el factor sea numérico
+    pub fn generar_positivo(&mut self) -> Result<(), Diagnostico> {
+        let tipo = *self.ptypes.last()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay tipo para el signo +"))?;
+        let span = self.pspans.last().copied().flatten();
+        self.validar_signo(crate::semantico::cubo_semantico::Operador::Suma, tipo, span)?;
+        // El factor conserva su valor; su ubicación ahora incluye el signo
+        if let Some(ubicacion) = self.pspans.last_mut() {
+            *ubicacion = self.span_actual;
+        }
+        Ok(())
+    }
+
+    /// Valida un signo unario contra el cubo semántico (`span` es el del factor)
+    fn validar_signo(&self, signo: crate::semantico::cubo_semantico::Operador, tipo: TipoDato, span: Option<Span>) -> Result<TipoDato, Diagnostico> {
+        match self.cubo_semantico.validar_unario(signo, tipo) {
+            crate::semantico::cubo_semantico::ResultadoTipo::Ok(tipo) => Ok(tipo),
+            crate::semantico::cubo_semantico::ResultadoTipo::Error => Err(Diagnostico::tipos_incompatibles(format!(
+                "Error semántico: tipos incompatibles {:?} unario {}",
+                signo, tipo
+            )).con_span_por_omision(span)),
+        }
+    }
+
     // ==================== PUNTO NEURÁLGICO 2 ====================
     /// PN2: Procesar operador de suma o resta
     /// POper.Push(+ o -)
-    pub fn procesar_suma_resta(&mut self, operador: &str) -> Result<(), String> {
+    pub fn procesar_suma_resta(&mut self, operador: &str) -> Result<(), Diagnostico> {
         let op = match operador {
             "+" => OperadorCuadruplo::Suma,
             "-" => OperadorCuadruplo::Resta,
-            _ => return Err(format!("Operador inválido para suma/resta: {}", operador)),
+            _ => return Err(Diagnostico::interno(format!("Operador inválido para suma/resta: {}", operador))),
         };
 
         self.poper.push(op);
@@ -172,11 +365,12 @@ impl GeneradorCuadruplos {
     // ==================== PUNTO NEURÁLGICO 3 ====================
     /// PN3: Procesar operador de multiplicación o división
     /// POper.Push(* o /)
-    pub fn procesar_mult_div(&mut self, operador: &str) -> Result<(), String> {
+    pub fn procesar_mult_div(&mut self, operador: &str) -> Result<(), Diagnostico> {
         let op = match operador {
             "*" => OperadorCuadruplo::Multiplicacion,
             "/" => OperadorCuadruplo::Division,
-            _ => return Err(format!("Operador inválido para mult/div: {}", operador)),
+            "%" => OperadorCuadruplo::Modulo,
+            _ => return Err(Diagnostico::interno(format!("Operador inválido para mult/div: {}", operador))),
         };
 
         self.poper.push(op);
@@ -186,48 +380,56 @@ impl GeneradorCuadruplos {
     // ==================== PUNTO NEURÁLGICO 4 ====================
     /// PN4: Generar cuádruplo para suma o resta
     /// If POper.top() == '+' or '-' then ...
-    pub fn generar_suma_resta(&mut self) -> Result<(), String> {
-        if let Some(&op) = self.poper.last() {
-            if op == OperadorCuadruplo::Suma || op == OperadorCuadruplo::Resta {
-                self.generar_cuadruplo_aritmetico()?;
-            }
+    pub fn generar_suma_resta(&mut self) -> Result<(), Diagnostico> {
+        if let Some(&op) = self.poper.last()
+            && (op == OperadorCuadruplo::Suma || op == OperadorCuadruplo::Resta)
+        {
+            self.generar_cuadruplo_aritmetico()?;
         }
         Ok(())
     }
 
     // ==================== PUNTO NEURÁLGICO 5 ====================
-    /// PN5: Generar cuádruplo para multiplicación o división
-    /// If POper.top() == '*' or '/' then ...
-    pub fn generar_mult_div(&mut self) -> Result<(), String> {
-        if let Some(&op) = self.poper.last() {
-            if op == OperadorCuadruplo::Multiplicacion || op == OperadorCuadruplo::Division {
-                self.generar_cuadruplo_aritmetico()?;
-            }
+    /// PN5: Generar cuádruplo para multiplicación, división o residuo
+    /// If POper.top() == '*', '/' or '%' then ...
+    pub fn generar_mult_div(&mut self) -> Result<(), Diagnostico> {
+        if let Some(&op) = self.poper.last()
+            && matches!(op,
+                OperadorCuadruplo::Multiplicacion |
+                OperadorCuadruplo::Division |
+                OperadorCuadruplo::Modulo
+            )
+        {
+            self.generar_cuadruplo_aritmetico()?;
         }
         Ok(())
     }
 
     /// Algoritmo genérico para generar cuádruplo aritmético (usado por PN4 y PN5)
-    fn generar_cuadruplo_aritmetico(&mut self) -> Result<(), String> {
+    fn generar_cuadruplo_aritmetico(&mut self) -> Result<(), Diagnostico> {
         // right_operand = PilaO.Pop()
         let operando_der = self.pilao.pop()
-            .ok_or("Error: Pila de operandos vacía (derecho)")?;
+            .ok_or_else(|| Diagnostico::interno("Error: Pila de operandos vacía (derecho)"))?;
 
         // right_Type = PTypes.Pop()
         let tipo_der = self.ptypes.pop()
-            .ok_or("Error: Pila de tipos vacía (derecho)")?;
+            .ok_or_else(|| Diagnostico::interno("Error: Pila de tipos vacía (derecho)"))?;
 
         // left_operand = PilaO.Pop()
         let operando_izq = self.pilao.pop()
-            .ok_or("Error: Pila de operandos vacía (izquierdo)")?;
+            .ok_or_else(|| Diagnostico::interno("Error: Pila de operandos vacía (izquierdo)"))?;
 
         // left_Type = PTypes.Pop()
         let tipo_izq = self.ptypes.pop()
-            .ok_or("Error: Pila de tipos vacía (izquierdo)")?;
+            .ok_or_else(|| Diagnostico::interno("Error: Pila de tipos vacía (izquierdo)"))?;
+
+        let span_der = self.pspans.pop().flatten();
+        let span_izq = self.pspans.pop().flatten();
+        let span_resultado = Self::unir_spans(span_izq, span_der);
 
         // operator = POper.Pop()
         let operador = self.poper.pop()
-            .ok_or("Error: Pila de operadores vacía")?;
+            .ok_or_else(|| Diagnostico::interno("Error: Pila de operadores vacía"))?;
 
         // Convertir OperadorCuadruplo a Operador del cubo semántico
         let op_semantico = self.convertir_operador_semantico(operador)?;
@@ -236,13 +438,21 @@ impl GeneradorCuadruplos {
         let tipo_resultado = match self.cubo_semantico.validar(tipo_izq, op_semantico, tipo_der) {
             crate::semantico::cubo_semantico::ResultadoTipo::Ok(tipo) => tipo,
             crate::semantico::cubo_semantico::ResultadoTipo::Error => {
-                return Err(format!(
+                let mut diagnostico = Diagnostico::tipos_incompatibles(format!(
                     "Error semántico: tipos incompatibles {:?} {:?} {:?}",
                     tipo_izq, operador, tipo_der
-                ));
+                )).con_span_por_omision(span_resultado);
+                for (span, tipo) in [(span_izq, tipo_izq), (span_der, tipo_der)] {
+                    if let Some(span) = span {
+                        diagnostico = diagnostico.con_span_secundario(span, tipo.to_string());
+                    }
+                }
+                return Err(diagnostico);
             }
-        };        // result = AVAIL.next()
-        let dir_temporal = self.memoria_virtual.asignar_variable(tipo_resultado, TipoSegmento::Temporal)?;
+        };
+
+        // result = AVAIL.next()
+        let dir_temporal = self.memoria_virtual.asignar_variable(tipo_resultado, TipoSegmento::Temporal).map_err(Diagnostico::memoria_agotada)?;
         let resultado = Operando::Direccion(dir_temporal);
 
         // generate quad = (operator, left_operand, right_operand, result)
@@ -262,6 +472,9 @@ impl GeneradorCuadruplos {
         // PTypes.Push(result_Type)
         self.ptypes.push(tipo_resultado);
 
+        // El resultado abarca desde el operando izquierdo hasta el derecho
+        self.pspans.push(span_resultado);
+
         // Si los operandos son temporales, liberarlos
         self.liberar_si_temporal(&operando_izq);
         self.liberar_si_temporal(&operando_der);
@@ -281,56 +494,225 @@ impl GeneradorCuadruplos {
     // ==================== PUNTO NEURÁLGICO 7 ====================
     /// PN7: Quitar marca de fondo falso (cierra paréntesis)
     /// POper.Pop(False bottom mark)
-    pub fn cerrar_parentesis(&mut self) -> Result<(), String> {
-        if let Some(op) = self.poper.last() {
-            if *op == OperadorCuadruplo::Goto {
-                self.poper.pop();
-                return Ok(());
-            }
+    pub fn cerrar_parentesis(&mut self) -> Result<(), Diagnostico> {
+        if let Some(op) = self.poper.last()
+            && *op == OperadorCuadruplo::Goto
+        {
+            self.poper.pop();
+            return Ok(());
         }
-        Err("Error: No se encontró marca de fondo falso al cerrar paréntesis".to_string())
+        Err(Diagnostico::interno("Error: No se encontró marca de fondo falso al cerrar paréntesis"))
     }
 
     // ==================== PUNTO NEURÁLGICO 8 ====================
     /// PN8: Procesar operador relacional
     /// POper.Push(rel.op) (like >, <, ==, !=)
-    pub fn procesar_relacional(&mut self, operador: &str) -> Result<(), String> {
+    pub fn procesar_relacional(&mut self, operador: &str) -> Result<(), Diagnostico> {
         let op = match operador {
             ">" => OperadorCuadruplo::MayorQue,
             "<" => OperadorCuadruplo::MenorQue,
+            ">=" => OperadorCuadruplo::MayorIgual,
+            "<=" => OperadorCuadruplo::MenorIgual,
             "==" => OperadorCuadruplo::Igual,
             "!=" => OperadorCuadruplo::Diferente,
-            _ => return Err(format!("Operador relacional inválido: {}", operador)),
+            _ => return Err(Diagnostico::interno(format!("Operador relacional inválido: {}", operador))),
         };
 
+        // La gramática acepta `a < b < c` solo para dar un diagnóstico claro: si
+        // el tope de POper ya es relacional (sin fondo falso de por medio), la
+        // primera comparación sigue abierta. Se revisa aquí, antes de que la cola
+        // genere la comparación interna
+        if let Some(&anterior) = self.poper.last()
+            && Self::es_relacional(anterior)
+        {
+            return Err(Diagnostico::semantico(format!(
+                "Error semántico: las comparaciones no se encadenan ('{}' seguido de '{}'); \
+                 une dos comparaciones con `y`, p. ej. `a < b y b < c`",
+                anterior, op
+            )));
+        }
+
         self.poper.push(op);
         Ok(())
     }
 
+    fn es_relacional(op: OperadorCuadruplo) -> bool {
+        matches!(op,
+            OperadorCuadruplo::MayorQue |
+            OperadorCuadruplo::MenorQue |
+            OperadorCuadruplo::MayorIgual |
+            OperadorCuadruplo::MenorIgual |
+            OperadorCuadruplo::Igual |
+            OperadorCuadruplo::Diferente
+        )
+    }
+
     // ==================== PUNTO NEURÁLGICO 9 ====================
     /// PN9: Generar cuádruplo para operador relacional
     /// If POper.top() == rel.op then ...
-    pub fn generar_relacional(&mut self) -> Result<(), String> {
-        eprintln!("[GENERADOR] generar_relacional: poper.last() = {:?}, en_condicion = {}",
-                  self.poper.last(), self.en_condicion);
-
-        if let Some(&op) = self.poper.last() {
-            if matches!(op,
-                OperadorCuadruplo::MayorQue |
-                OperadorCuadruplo::MenorQue |
-                OperadorCuadruplo::Igual |
-                OperadorCuadruplo::Diferente
-            ) {
-                self.generar_cuadruplo_aritmetico()?;
-
-                // Si estamos en una condición, generar GOTOF automáticamente
-                if self.esta_en_condicion() {
-                    eprintln!("[GENERADOR] Generando GOTOF automático después de expresión relacional");
-                    self.generar_gotof()?;
-                    self.finalizar_condicion(); // Ya procesamos la expresión
-                }
+    pub fn generar_relacional(&mut self) -> Result<(), Diagnostico> {
+        if let Some(&op) = self.poper.last()
+            && Self::es_relacional(op)
+        {
+            self.generar_cuadruplo_aritmetico()?;
+        }
+        Ok(())
+    }
+
+    // ==================== OPERADORES LÓGICOS ====================
+    // `y` y `o` se evalúan en cortocircuito: al leer el operador, el operando
+    // izquierdo ya está en PilaO y se genera un salto pendiente (GOTOF para `y`,
+    // GOTOV para `o`) en PJumps. Al cerrar la cadena se rellenan todos los saltos
+    // hacia el código que asigna el resultado (verdadero o falso) a un temporal.
+
+    /// Cortocircuito de `y`: si el operando izquierdo es falso, la cadena es falsa
+    /// Genera (gotof, izq, -, pendiente)
+    pub fn procesar_y(&mut self) -> Result<(), Diagnostico> {
+        self.generar_salto_logico(OperadorCuadruplo::GotoF, OperadorCuadruplo::Y)
+    }
+
+    /// Cortocircuito de `o`: si el operando izquierdo es verdadero, la cadena es verdadera
+    /// Genera (gotov, izq, -, pendiente)
+    pub fn procesar_o(&mut self) -> Result<(), Diagnostico> {
+        self.generar_salto_logico(OperadorCuadruplo::GotoV, OperadorCuadruplo::O)
+    }
+
+    /// Cierra una cadena con `num_operadores` operadores `y`
+    /// ```text
+    ///     gotof ultimo, -, F
+    ///     =     verdadero, -, t
+    ///     goto  -, -, FIN
+    /// F:  =     falso, -, t
+    /// FIN:
+    /// ```
+    pub fn generar_fin_y(&mut self, num_operadores: usize) -> Result<(), Diagnostico> {
+        self.cerrar_cadena_logica(num_operadores, OperadorCuadruplo::GotoF, OperadorCuadruplo::Y, true, false)
+    }
+
+    /// Cierra una cadena con `num_operadores` operadores `o`
+    /// (igual que `generar_fin_y` con GOTOV y los valores invertidos)
+    pub fn generar_fin_o(&mut self, num_operadores: usize) -> Result<(), Diagnostico> {
+        self.cerrar_cadena_logica(num_operadores, OperadorCuadruplo::GotoV, OperadorCuadruplo::O, false, true)
+    }
+
+    /// Generar negación lógica: (no, operando, -, t)
+    pub fn generar_no(&mut self) -> Result<(), Diagnostico> {
+        let operando = self.pilao.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: Pila de operandos vacía (no)"))?;
+        let tipo = self.ptypes.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: Pila de tipos vacía (no)"))?;
+        let span = self.pspans.pop().flatten();
+
+        let tipo_resultado = match self.cubo_semantico.validar_unario(crate::semantico::cubo_semantico::Operador::No, tipo) {
+            crate::semantico::cubo_semantico::ResultadoTipo::Ok(tipo) => tipo,
+            crate::semantico::cubo_semantico::ResultadoTipo::Error => {
+                return Err(Diagnostico::tipos_incompatibles(format!("Error semántico: tipos incompatibles No {:?}", tipo))
+                    .con_span_por_omision(span));
             }
+        };
+
+        self.liberar_si_temporal(&operando);
+        let dir_temporal = self.memoria_virtual.asignar_variable(tipo_resultado, TipoSegmento::Temporal).map_err(Diagnostico::memoria_agotada)?;
+        let resultado = Operando::Direccion(dir_temporal);
+
+        self.quad.push_back(Cuadruplo::new(
+            OperadorCuadruplo::No,
+            operando,
+            Operando::Vacio,
+            resultado.clone(),
+        ));
+
+        self.pilao.push(resultado);
+        self.ptypes.push(tipo_resultado);
+        self.pspans.push(self.span_actual);
+
+        Ok(())
+    }
+
+    /// Saca el operando del tope, valida que sea booleano y genera un salto pendiente
+    fn generar_salto_logico(&mut self, salto: OperadorCuadruplo, operador: OperadorCuadruplo) -> Result<(), Diagnostico> {
+        let operando = self.pilao.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: Pila de operandos vacía (operador lógico)"))?;
+        let tipo = self.ptypes.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: Pila de tipos vacía (operador lógico)"))?;
+        let span = self.pspans.pop().flatten();
+
+        let op_semantico = self.convertir_operador_semantico(operador)?;
+        if let crate::semantico::cubo_semantico::ResultadoTipo::Error =
+            self.cubo_semantico.validar(tipo, op_semantico, TipoDato::Booleano)
+        {
+            return Err(Diagnostico::tipos_incompatibles(format!(
+                "Error semántico: tipos incompatibles {:?} {:?} (los operandos lógicos deben ser booleanos)",
+                tipo, operador
+            )).con_span_por_omision(span));
         }
+
+        self.quad.push_back(Cuadruplo::new(salto, operando.clone(), Operando::Vacio, Operando::Pendiente));
+        self.pjumps.push(self.quad.len() - 1);
+
+        self.liberar_si_temporal(&operando);
+
+        Ok(())
+    }
+
+    /// Genera el salto del último operando y materializa el resultado de la cadena
+    fn cerrar_cadena_logica(
+        &mut self,
+        num_operadores: usize,
+        salto: OperadorCuadruplo,
+        operador: OperadorCuadruplo,
+        valor_sin_salto: bool,
+        valor_con_salto: bool,
+    ) -> Result<(), Diagnostico> {
+        self.generar_salto_logico(salto, operador)?;
+
+        let dir_temporal = self.memoria_virtual.asignar_variable(TipoDato::Booleano, TipoSegmento::Temporal).map_err(Diagnostico::memoria_agotada)?;
+        let resultado = Operando::Direccion(dir_temporal);
+        let dir_sin_salto = self.memoria_virtual.asignar_constante_booleana(valor_sin_salto).map_err(Diagnostico::memoria_agotada)?;
+        let dir_con_salto = self.memoria_virtual.asignar_constante_booleana(valor_con_salto).map_err(Diagnostico::memoria_agotada)?;
+
+        // Ningún operando saltó
+        self.quad.push_back(Cuadruplo::new(
+            OperadorCuadruplo::Asignacion,
+            Operando::Direccion(dir_sin_salto),
+            Operando::Vacio,
+            resultado.clone(),
+        ));
+        self.quad.push_back(Cuadruplo::new(
+            OperadorCuadruplo::Goto,
+            Operando::Vacio,
+            Operando::Vacio,
+            Operando::Pendiente,
+        ));
+        let pos_goto_fin = self.quad.len() - 1;
+
+        // FILL de los saltos de la cadena (uno por operando)
+        let destino = self.quad.len();
+        for _ in 0..=num_operadores {
+            let pos_salto = self.pjumps.pop()
+                .ok_or_else(|| Diagnostico::interno("Error: No hay salto de cortocircuito pendiente"))?;
+            if let Some(cuadruplo) = self.quad.get_mut(pos_salto) {
+                cuadruplo.resultado = Operando::Etiqueta(destino);
+            }
+        }
+
+        // Algún operando saltó
+        self.quad.push_back(Cuadruplo::new(
+            OperadorCuadruplo::Asignacion,
+            Operando::Direccion(dir_con_salto),
+            Operando::Vacio,
+            resultado.clone(),
+        ));
+
+        let fin = self.quad.len();
+        if let Some(cuadruplo) = self.quad.get_mut(pos_goto_fin) {
+            cuadruplo.resultado = Operando::Etiqueta(fin);
+        }
+
+        self.pilao.push(resultado);
+        self.ptypes.push(TipoDato::Booleano);
+        self.pspans.push(self.span_actual);
+
         Ok(())
     }
 
@@ -338,27 +720,29 @@ impl GeneradorCuadruplos {
 
     /// Generar cuádruplo de asignación
     /// variable = expresión
-    pub fn generar_asignacion(&mut self, variable: &str) -> Result<(), String> {
+    pub fn generar_asignacion(&mut self, variable: &str) -> Result<(), Diagnostico> {
         // Obtener tipo y dirección de la variable
         let contexto = self.obtener_contexto()?;
         let tipo_var = contexto.obtener_tipo_variable(variable)?;
         let dir_var = contexto.obtener_direccion_variable(variable)?;
+        Self::validar_escalar(contexto, variable)?;
 
         // Obtener operando de la expresión
         let operando_expr = self.pilao.pop()
-            .ok_or("Error: No hay expresión para asignar")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay expresión para asignar"))?;
         let tipo_expr = self.ptypes.pop()
-            .ok_or("Error: No hay tipo de expresión para asignar")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay tipo de expresión para asignar"))?;
+        let span_expr = self.pspans.pop().flatten();
 
         // Validar compatibilidad de tipos con el cubo semántico
         let op_asig = crate::semantico::cubo_semantico::Operador::Asignacion;
         match self.cubo_semantico.validar(tipo_var, op_asig, tipo_expr) {
             crate::semantico::cubo_semantico::ResultadoTipo::Ok(_) => {},
             crate::semantico::cubo_semantico::ResultadoTipo::Error => {
-                return Err(format!(
+                return Err(Diagnostico::tipos_incompatibles(format!(
                     "Error en asignación: tipos incompatibles {:?} = {:?}",
                     tipo_var, tipo_expr
-                ));
+                )).con_span_por_omision(span_expr));
             }
         }
 
@@ -378,12 +762,76 @@ impl GeneradorCuadruplos {
         Ok(())
     }
 
+    /// Asignación compuesta (`x += e`, `v[i] -= e`, ...): se reduce a la forma
+    /// expandida `x = x OP e` con los mismos cuádruplos y validaciones del cubo,
+    /// así que `entero += flotante` se rechaza igual que `entero = entero + flotante`
+    ///
+    /// `destino` es el nombre de la variable o `nombre[]` si el destino es un
+    /// elemento de arreglo, cuyo apuntador ya está en PilaO debajo de la expresión.
+    pub fn generar_asignacion_compuesta(&mut self, destino: &str, operador: &str) -> Result<(), Diagnostico> {
+        let op = match operador {
+            "+=" => OperadorCuadruplo::Suma,
+            "-=" => OperadorCuadruplo::Resta,
+            "*=" => OperadorCuadruplo::Multiplicacion,
+            "/=" => OperadorCuadruplo::Division,
+            "%=" => OperadorCuadruplo::Modulo,
+            _ => return Err(Diagnostico::interno(format!("Operador de asignación compuesta inválido: {}", operador))),
+        };
+
+        let operando_expr = self.pilao.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay expresión para asignar"))?;
+        let tipo_expr = self.ptypes.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay tipo de expresión para asignar"))?;
+        let span_expr = self.pspans.pop().flatten();
+
+        // Operando izquierdo: el valor actual del destino
+        let es_arreglo = destino.ends_with("[]");
+        if es_arreglo {
+            let apuntador = self.pilao.last().cloned()
+                .ok_or_else(|| Diagnostico::interno("Error: No hay elemento de arreglo destino"))?;
+            let tipo = *self.ptypes.last()
+                .ok_or_else(|| Diagnostico::interno("Error: No hay tipo de elemento de arreglo destino"))?;
+            let span = self.pspans.last().copied().flatten();
+            self.pilao.push(apuntador);
+            self.ptypes.push(tipo);
+            self.pspans.push(span);
+        } else {
+            self.procesar_operando(destino)?;
+        }
+
+        self.pilao.push(operando_expr);
+        self.ptypes.push(tipo_expr);
+        self.pspans.push(span_expr);
+        self.poper.push(op);
+        self.generar_cuadruplo_aritmetico()?;
+
+        if es_arreglo {
+            // El apuntador ya se liberó como operando, pero ningún temporal se
+            // asigna entre ese cuádruplo y la asignación
+            self.generar_asignacion_arreglo()
+        } else {
+            self.generar_asignacion(destino)
+        }
+    }
+
+    /// Incremento o decremento (`x++;`, `v[i]--;`): equivale a `x += 1` / `x -= 1`
+    pub fn generar_incremento(&mut self, destino: &str, operador: &str) -> Result<(), Diagnostico> {
+        let compuesto = match operador {
+            "++" => "+=",
+            "--" => "-=",
+            _ => return Err(Diagnostico::interno(format!("Operador de incremento inválido: {}", operador))),
+        };
+        self.procesar_operando("1")?;
+        self.generar_asignacion_compuesta(destino, compuesto)
+    }
+
     /// Generar cuádruplo de escritura (escribe)
-    pub fn generar_escritura(&mut self) -> Result<(), String> {
+    pub fn generar_escritura(&mut self) -> Result<(), Diagnostico> {
         let operando = self.pilao.pop()
-            .ok_or("Error: No hay operando para escribir")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay operando para escribir"))?;
         let _tipo = self.ptypes.pop()
-            .ok_or("Error: No hay tipo para escribir")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay tipo para escribir"))?;
+        self.pspans.pop();
 
         // Generar cuádruplo: (escribe, -, -, operando)
         let cuadruplo = Cuadruplo::new(
@@ -402,11 +850,21 @@ impl GeneradorCuadruplos {
     }
 
     /// Generar cuádruplo de lectura (lee)
-    pub fn generar_lectura(&mut self, variable: &str) -> Result<(), String> {
+    pub fn generar_lectura(&mut self, variable: &str) -> Result<(), Diagnostico> {
         // Validar que la variable exista y obtener su dirección
         let contexto = self.obtener_contexto()?;
-        contexto.obtener_tipo_variable(variable)?;
+        let tipo = contexto.obtener_tipo_variable(variable)?;
         let direccion = contexto.obtener_direccion_variable(variable)?;
+        Self::validar_escalar(contexto, variable)?;
+
+        // La VM deduce el tipo a leer de la dirección y solo sabe leer enteros,
+        // flotantes y chars
+        if matches!(tipo, TipoDato::Letrero | TipoDato::Booleano) {
+            return Err(Diagnostico::semantico(format!(
+                "Error semántico: No se puede leer en '{}': lee no admite variables {}",
+                variable, tipo
+            )));
+        }
 
         // Generar cuádruplo: (lee, -, -, dirección_variable)
         let cuadruplo = Cuadruplo::new(
@@ -421,8 +879,188 @@ impl GeneradorCuadruplos {
         Ok(())
     }
 
+    // ==================== ARREGLOS ====================
+
+    /// Generar acceso a un elemento de arreglo: `id[e]` o `id[e1][e2]`
+    /// Los índices ya están en PilaO (el último en el tope). Genera un VER por índice,
+    /// calcula la dirección del elemento en un temporal y apila un `Apuntador` a él
+    pub fn generar_acceso_arreglo(&mut self, nombre: &str, num_indices: usize) -> Result<(), Diagnostico> {
+        let contexto = self.obtener_contexto()?;
+        let tipo = contexto.obtener_tipo_variable(nombre)?;
+        let base = contexto.obtener_direccion_variable(nombre)?;
+        let dimensiones = contexto.obtener_dimensiones_variable(nombre)?;
+
+        if dimensiones.is_empty() {
+            return Err(Diagnostico::arreglo_invalido(format!("Error semántico: La variable '{}' no es un arreglo", nombre)));
+        }
+        if dimensiones.len() != num_indices {
+            return Err(Diagnostico::arreglo_invalido(format!(
+                "Error semántico: El arreglo '{}' tiene {} dimensión(es) pero se usaron {} índice(s)",
+                nombre, dimensiones.len(), num_indices
+            )));
+        }
+
+        // Sacar los índices de la pila (quedan en orden de escritura)
+        let mut indices = Vec::with_capacity(num_indices);
+        for _ in 0..num_indices {
+            let indice = self.pilao.pop()
+                .ok_or_else(|| Diagnostico::interno("Error: Pila de operandos vacía (índice de arreglo)"))?;
+            let tipo_indice = self.ptypes.pop()
+                .ok_or_else(|| Diagnostico::interno("Error: Pila de tipos vacía (índice de arreglo)"))?;
+            let span_indice = self.pspans.pop().flatten();
+            if tipo_indice != TipoDato::Entero {
+                return Err(Diagnostico::arreglo_invalido(format!(
+                    "Error semántico: Los índices del arreglo '{}' deben ser enteros, se encontró {:?}",
+                    nombre, tipo_indice
+                )).con_span_por_omision(span_indice));
+            }
+            indices.push(indice);
+        }
+        indices.reverse();
+
+        // Verificar cada índice: (ver, índice, dimensión, arreglo) → 0 <= índice < dimensión
+        for (indice, dimension) in indices.iter().zip(&dimensiones) {
+            self.quad.push_back(Cuadruplo::new(
+                OperadorCuadruplo::Verifica,
+                indice.clone(),
+                Operando::ConstanteEntera(*dimension as i32),
+                Operando::Variable(nombre.to_string()),
+            ));
+        }
+
+        // Desplazamiento: i (1D) o i * d2 + j (2D)
+        let mut desplazamiento = indices[0].clone();
+        if num_indices == 2 {
+            let dir_d2 = self.memoria_virtual.asignar_constante_entera(dimensiones[1] as i32).map_err(Diagnostico::memoria_agotada)?;
+            let producto = self.generar_cuadruplo_entero(
+                OperadorCuadruplo::Multiplicacion,
+                desplazamiento,
+                Operando::Direccion(dir_d2),
+            )?;
+            desplazamiento = self.generar_cuadruplo_entero(
+                OperadorCuadruplo::Suma,
+                producto,
+                indices[1].clone(),
+            )?;
+        }
+
+        // Dirección del elemento = dirección base + desplazamiento
+        let dir_base = self.memoria_virtual.asignar_constante_entera(base as i32).map_err(Diagnostico::memoria_agotada)?;
+        let direccion_elemento = self.generar_cuadruplo_entero(
+            OperadorCuadruplo::Suma,
+            desplazamiento,
+            Operando::Direccion(dir_base),
+        )?;
+
+        if let Operando::Direccion(dir) = direccion_elemento {
+            self.pilao.push(Operando::Apuntador(dir));
+            self.ptypes.push(tipo);
+            self.pspans.push(self.span_actual);
+        }
+
+        Ok(())
+    }
+
+    /// Generar asignación a un elemento de arreglo: `id[...] = expresión`
+    /// El destino (`Apuntador`) se apiló al reducir el acceso, antes que la expresión
+    pub fn generar_asignacion_arreglo(&mut self) -> Result<(), Diagnostico> {
+        let operando_expr = self.pilao.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay expresión para asignar"))?;
+        let tipo_expr = self.ptypes.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay tipo de expresión para asignar"))?;
+        let destino = self.pilao.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay elemento de arreglo destino"))?;
+        let tipo_destino = self.ptypes.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay tipo de elemento de arreglo destino"))?;
+        let span_expr = self.pspans.pop().flatten();
+        let span_destino = self.pspans.pop().flatten();
+
+        if !matches!(destino, Operando::Apuntador(_)) {
+            return Err(Diagnostico::interno(format!("Error interno: Destino de asignación a arreglo inválido: {}", destino)));
+        }
+
+        let op_asig = crate::semantico::cubo_semantico::Operador::Asignacion;
+        if let crate::semantico::cubo_semantico::ResultadoTipo::Error =
+            self.cubo_semantico.validar(tipo_destino, op_asig, tipo_expr)
+        {
+            let mut diagnostico = Diagnostico::tipos_incompatibles(format!(
+                "Error en asignación: tipos incompatibles {:?} = {:?}",
+                tipo_destino, tipo_expr
+            )).con_span_por_omision(span_expr);
+            if let Some(span) = span_destino {
+                diagnostico = diagnostico.con_span_secundario(span, tipo_destino.to_string());
+            }
+            return Err(diagnostico);
+        }
+
+        // Generar cuádruplo: (=, expresión, -, (apuntador))
+        self.quad.push_back(Cuadruplo::new(
+            OperadorCuadruplo::Asignacion,
+            operando_expr.clone(),
+            Operando::Vacio,
+            destino.clone(),
+        ));
+
+        self.liberar_si_temporal(&operando_expr);
+        self.liberar_si_temporal(&destino);
+
+        Ok(())
+    }
+
+    /// Genera un cuádruplo aritmético entero con resultado en un temporal nuevo
+    /// (cálculo de direcciones de arreglos, sin pasar por PilaO)
+    fn generar_cuadruplo_entero(
+        &mut self,
+        operador: OperadorCuadruplo,
+        izq: Operando,
+        der: Operando,
+    ) -> Result<Operando, Diagnostico> {
+        let resultado = self.emitir_cuadruplo_entero(operador, izq.clone(), der.clone())?;
+
+        self.liberar_si_temporal(&izq);
+        self.liberar_si_temporal(&der);
+
+        Ok(resultado)
+    }
+
+    /// Igual que `generar_cuadruplo_entero` pero sin liberar los operandos
+    /// (para temporales que deben sobrevivir, como el límite y paso de `para`)
+    fn emitir_cuadruplo_entero(
+        &mut self,
+        operador: OperadorCuadruplo,
+        izq: Operando,
+        der: Operando,
+    ) -> Result<Operando, Diagnostico> {
+        self.emitir_cuadruplo(operador, izq, der, TipoDato::Entero)
+    }
+
+    /// Genera un cuádruplo con resultado en un temporal nuevo de `tipo`, sin liberar los operandos
+    fn emitir_cuadruplo(
+        &mut self,
+        operador: OperadorCuadruplo,
+        izq: Operando,
+        der: Operando,
+        tipo: TipoDato,
+    ) -> Result<Operando, Diagnostico> {
+        let dir_temporal = self.memoria_virtual.asignar_variable(tipo, TipoSegmento::Temporal).map_err(Diagnostico::memoria_agotada)?;
+        let resultado = Operando::Direccion(dir_temporal);
+
+        self.quad.push_back(Cuadruplo::new(operador, izq, der, resultado.clone()));
+
+        Ok(resultado)
+    }
+
+    /// Verifica que una variable no sea arreglo (los arreglos requieren índices)
+    fn validar_escalar(contexto: &ContextoSemantico, nombre: &str) -> Result<(), Diagnostico> {
+        if contexto.obtener_dimensiones_variable(nombre)?.is_empty() {
+            Ok(())
+        } else {
+            Err(Diagnostico::arreglo_invalido(format!("Error semántico: El arreglo '{}' debe usarse con índices", nombre)))
+        }
+    }
+
     /// Generar GOTO inicial al programa principal (saltar funciones)
-    pub fn generar_goto_inicio(&mut self) -> Result<(), String> {
+    pub fn generar_goto_inicio(&mut self) -> Result<(), Diagnostico> {
         // Generar cuádruplo GOTO con salto pendiente
         let cuadruplo = Cuadruplo::new(
             OperadorCuadruplo::Goto,
@@ -441,9 +1079,9 @@ impl GeneradorCuadruplos {
     }
 
     /// FILL del GOTO inicial (cuando se encuentra el 'inicio' del main)
-    pub fn fill_goto_inicio(&mut self) -> Result<(), String> {
+    pub fn fill_goto_inicio(&mut self) -> Result<(), Diagnostico> {
         let pos_salto = self.pjumps.pop()
-            .ok_or("Error: No hay GOTO inicial pendiente para rellenar")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay GOTO inicial pendiente para rellenar"))?;
 
         // La dirección de salto es la posición actual (siguiente cuádruplo)
         let direccion_salto = self.quad.len();
@@ -452,7 +1090,7 @@ impl GeneradorCuadruplos {
         if let Some(cuadruplo) = self.quad.get_mut(pos_salto) {
             cuadruplo.resultado = Operando::Etiqueta(direccion_salto);
         } else {
-            return Err(format!("Error: Posición de salto {} inválida", pos_salto));
+            return Err(Diagnostico::interno(format!("Error: Posición de salto {} inválida", pos_salto)));
         }
 
         Ok(())
@@ -460,43 +1098,26 @@ impl GeneradorCuadruplos {
 
     // ==================== ESTRUCTURAS DE CONTROL ======================================
 
-    /// Marcar que estamos entrando en una expresión condicional (si, mientras)
-    pub fn iniciar_condicion(&mut self) {
-        self.en_condicion += 1;
-        eprintln!("[GENERADOR] Iniciando condición (nivel {})", self.en_condicion);
-    }
-
-    /// Marcar que salimos de una expresión condicional
-    pub fn finalizar_condicion(&mut self) {
-        if self.en_condicion > 0 {
-            self.en_condicion -= 1;
-        }
-        eprintln!("[GENERADOR] Finalizando condición (nivel {})", self.en_condicion);
-    }
-
-    /// Verificar si estamos dentro de una expresión condicional
-    pub fn esta_en_condicion(&self) -> bool {
-        self.en_condicion > 0
-    }
-
     /// Paso 13-19: Generar GOTOF para condicionales y ciclos
     /// Verifica que el resultado de la expresión sea booleano y genera salto condicional
-    pub fn generar_gotof(&mut self) -> Result<(), String> {
+    /// Se invoca al leer `entonces` / `haz`, cuando la condición completa ya está en PilaO
+    pub fn generar_gotof(&mut self) -> Result<(), Diagnostico> {
         // Obtener el resultado de la expresión (debe estar en la pila)
         let tipo_expr = self.ptypes.last()
-            .ok_or("Error: No hay expresión para evaluar en condicional")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay expresión para evaluar en condicional"))?;
 
-        // Validar que sea tipo entero (se usa como booleano: 0=false, !=0=true)
-        if *tipo_expr != TipoDato::Entero {
-            return Err(format!(
-                "Error: La expresión condicional debe ser de tipo entero (booleano), se encontró {:?}",
+        // Validar que sea booleano (un entero ya no sirve como condición)
+        if *tipo_expr != TipoDato::Booleano {
+            return Err(Diagnostico::tipos_incompatibles(format!(
+                "Error semántico: tipos incompatibles en la condición: se esperaba booleano, se encontró {}",
                 tipo_expr
-            ));
+            )).con_span_por_omision(self.pspans.last().copied().flatten()));
         }
 
         let operando_cond = self.pilao.pop()
-            .ok_or("Error: No hay operando condicional")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay operando condicional"))?;
         self.ptypes.pop();
+        self.pspans.pop();
 
         // Generar cuádruplo GOTOF con salto pendiente
         let cuadruplo = Cuadruplo::new(
@@ -519,9 +1140,9 @@ impl GeneradorCuadruplos {
     }
 
     /// Paso 14: Rellenar salto de condicional (FILL)
-    pub fn fill_salto_condicional(&mut self) -> Result<(), String> {
+    pub fn fill_salto_condicional(&mut self) -> Result<(), Diagnostico> {
         let pos_salto = self.pjumps.pop()
-            .ok_or("Error: No hay salto pendiente para rellenar")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay salto pendiente para rellenar"))?;
 
         // La dirección de salto es la posición actual (siguiente cuádruplo)
         let direccion_salto = self.quad.len();
@@ -530,7 +1151,7 @@ impl GeneradorCuadruplos {
         if let Some(cuadruplo) = self.quad.get_mut(pos_salto) {
             cuadruplo.resultado = Operando::Etiqueta(direccion_salto);
         } else {
-            return Err(format!("Error: Posición de salto {} inválida", pos_salto));
+            return Err(Diagnostico::interno(format!("Error: Posición de salto {} inválida", pos_salto)));
         }
 
         Ok(())
@@ -538,7 +1159,7 @@ impl GeneradorCuadruplos {
 
     /// Paso 16: Generar else (FILL del GOTOF y generar GOTO)
     /// Iniciar else: genera GOTO al final del then (antes de procesar el cuerpo del else)
-    pub fn iniciar_else(&mut self) -> Result<(), String> {
+    pub fn iniciar_else(&mut self) -> Result<(), Diagnostico> {
         eprintln!("[INTERMEDIO] Iniciando else - generando GOTO");
 
         // Generar GOTO incondicional (saltará al final del else)
@@ -562,18 +1183,18 @@ impl GeneradorCuadruplos {
         Ok(())
     }
 
-    pub fn generar_else(&mut self) -> Result<(), String> {
+    pub fn generar_else(&mut self) -> Result<(), Diagnostico> {
         // En pjumps tenemos: [GOTOF, GOTO, INICIO_ELSE]
         // Necesitamos extraer estos tres valores
 
         let inicio_else = self.pjumps.pop()
-            .ok_or("Error: No hay inicio_else pendiente")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay inicio_else pendiente"))?;
 
         let pos_goto = self.pjumps.pop()
-            .ok_or("Error: No hay GOTO pendiente para else")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay GOTO pendiente para else"))?;
 
         let pos_gotof = self.pjumps.pop()
-            .ok_or("Error: No hay GOTOF pendiente para else")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay GOTOF pendiente para else"))?;
 
         // Rellenar el GOTOF para que apunte al inicio del else
         if let Some(cuadruplo) = self.quad.get_mut(pos_gotof) {
@@ -597,14 +1218,14 @@ impl GeneradorCuadruplos {
     }
 
     /// Paso 20: Generar fin de ciclo (GOTO inicio y FILL GOTOF)
-    pub fn generar_fin_ciclo(&mut self) -> Result<(), String> {
+    pub fn generar_fin_ciclo(&mut self) -> Result<(), Diagnostico> {
         // Obtener posición del GOTOF (salida del ciclo)
         let pos_gotof = self.pjumps.pop()
-            .ok_or("Error: No hay GOTOF de ciclo para rellenar")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay GOTOF de ciclo para rellenar"))?;
 
         // Obtener posición de inicio del ciclo
         let pos_inicio = self.pjumps.pop()
-            .ok_or("Error: No hay marca de inicio de ciclo")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay marca de inicio de ciclo"))?;
 
         // Generar GOTO para regresar al inicio
         let cuadruplo_goto = Cuadruplo::new(
@@ -622,24 +1243,345 @@ impl GeneradorCuadruplos {
             cuadruplo.resultado = Operando::Etiqueta(direccion_fin);
         }
 
+        // `continua` regresa a la condición y `rompe` sale del ciclo
+        let saltos = self.pciclos.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay ciclo abierto para rellenar rompe/continua"))?;
+        for pos in saltos.continua {
+            self.rellenar_salto(pos, pos_inicio)?;
+        }
+        for pos in saltos.rompe {
+            self.rellenar_salto(pos, direccion_fin)?;
+        }
+
         Ok(())
     }
 
+    // ==================== ROMPE / CONTINUA ====================
+
+    /// Abre el registro de `rompe` / `continua` de un ciclo (al leer `haz`)
+    pub fn abrir_ciclo(&mut self) {
+        self.pciclos.push(SaltosCiclo::default());
+    }
+
+    /// `rompe;`: GOTO pendiente al final del ciclo más interno
+    pub fn generar_rompe(&mut self) -> Result<(), Diagnostico> {
+        let pos = self.generar_goto_pendiente();
+        self.pciclos.last_mut()
+            .ok_or_else(|| Diagnostico::semantico("Error: 'rompe' fuera de un ciclo"))?
+            .rompe.push(pos);
+        Ok(())
+    }
+
+    /// `continua;`: GOTO pendiente a la siguiente iteración del ciclo más interno
+    pub fn generar_continua(&mut self) -> Result<(), Diagnostico> {
+        let pos = self.generar_goto_pendiente();
+        self.pciclos.last_mut()
+            .ok_or_else(|| Diagnostico::semantico("Error: 'continua' fuera de un ciclo"))?
+            .continua.push(pos);
+        Ok(())
+    }
+
+    /// Rellena los `continua` del ciclo más interno hacia `destino`
+    /// (en `para` apuntan al incremento, no a la condición)
+    fn rellenar_continua(&mut self, destino: usize) -> Result<(), Diagnostico> {
+        let pendientes = match self.pciclos.last_mut() {
+            Some(saltos) => std::mem::take(&mut saltos.continua),
+            None => return Err(Diagnostico::interno("Error: No hay ciclo abierto para rellenar continua")),
+        };
+        for pos in pendientes {
+            self.rellenar_salto(pos, destino)?;
+        }
+        Ok(())
+    }
+
+    /// Genera un GOTO con destino pendiente y regresa su posición
+    fn generar_goto_pendiente(&mut self) -> usize {
+        self.quad.push_back(Cuadruplo::new(
+            OperadorCuadruplo::Goto,
+            Operando::Vacio,
+            Operando::Vacio,
+            Operando::Pendiente,
+        ));
+        self.quad.len() - 1
+    }
+
+    /// FILL: hace que el salto en `pos` apunte a `destino`
+    fn rellenar_salto(&mut self, pos: usize, destino: usize) -> Result<(), Diagnostico> {
+        let cuadruplo = self.quad.get_mut(pos)
+            .ok_or_else(|| Diagnostico::interno(format!("Error: Posición de salto {} inválida", pos)))?;
+        cuadruplo.resultado = Operando::Etiqueta(destino);
+        Ok(())
+    }
+
+    // ==================== CICLO PARA ====================
+
+    /// Inicio del ciclo `para id = expresión`: valida que la variable de control
+    /// sea un entero escalar y genera su asignación inicial
+    pub fn iniciar_para(&mut self, variable: &str) -> Result<(), Diagnostico> {
+        let contexto = self.obtener_contexto()?;
+        let tipo_var = contexto.obtener_tipo_variable(variable)?;
+        let dir_var = contexto.obtener_direccion_variable(variable)?;
+        Self::validar_escalar(contexto, variable)?;
+
+        if tipo_var != TipoDato::Entero {
+            return Err(Diagnostico::error(codigos::TIPOS_INCOMPATIBLES, format!(
+                "Error semántico: La variable de control '{}' del ciclo para debe ser entero, se encontró {:?}",
+                variable, tipo_var
+            )).con_nota("la variable de control de un ciclo para debe declararse entero"));
+        }
+
+        self.generar_asignacion(variable)?;
+
+        self.ppara.push(ControlPara {
+            nombre: variable.to_string(),
+            variable: Operando::Direccion(dir_var),
+            limite: Operando::Vacio,
+            paso: Operando::Vacio,
+            signo: SignoPaso::Positivo,
+            margen: Operando::Vacio,
+        });
+
+        Ok(())
+    }
+
+    /// `hasta expresión`: fija el límite del ciclo para abierto
+    pub fn generar_limite_para(&mut self) -> Result<(), Diagnostico> {
+        let (limite, _) = self.fijar_operando_para("límite")?;
+        let control = self.ppara.last_mut()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay ciclo para abierto"))?;
+        control.limite = limite;
+        Ok(())
+    }
+
+    /// Cierra el encabezado del ciclo para (se invoca antes de leer `haz`)
+    ///
+    /// Fija el paso (1 si se omite), marca el inicio del ciclo y deja en PilaO la condición
+    /// `id <= límite` si el paso es positivo o `id >= límite` si es negativo. Con un paso
+    /// constante el signo se conoce al compilar (y `paso 0` es error); si no, al entrar al
+    /// ciclo se genera `verpaso` (error de ejecución si vale 0) y el booleano `paso > 0`
+    /// elige la comparación. El GOTOF de salida lo genera `generar_gotof()` al leer `haz`,
+    /// igual que en `mientras`.
+    pub fn generar_control_para(&mut self, con_paso: bool) -> Result<(), Diagnostico> {
+        let (paso, span_paso) = if con_paso {
+            self.fijar_operando_para("paso")?
+        } else {
+            (Operando::Direccion(self.memoria_virtual.asignar_constante_entera(1).map_err(Diagnostico::memoria_agotada)?), None)
+        };
+        let nombre = self.ppara.last()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay ciclo para abierto"))?
+            .nombre.clone();
+
+        let constante = match paso {
+            Operando::Direccion(dir) => self.memoria_virtual.valor_constante_entera(dir),
+            _ => None,
+        };
+        let (signo, margen) = match constante {
+            Some(0) => return Err(Diagnostico::semantico(format!(
+                "Error semántico: El paso del ciclo para de '{}' no puede ser 0", nombre
+            )).con_span_por_omision(span_paso)),
+            Some(valor) => {
+                // Ninguna de las dos restas desborda: el signo del paso es el del extremo
+                let (signo, margen) = if valor > 0 {
+                    (SignoPaso::Positivo, i32::MAX - valor)
+                } else {
+                    (SignoPaso::Negativo, i32::MIN - valor)
+                };
+                (signo, Operando::Direccion(self.memoria_virtual.asignar_constante_entera(margen).map_err(Diagnostico::memoria_agotada)?))
+            }
+            None => {
+                self.quad.push_back(Cuadruplo::new(
+                    OperadorCuadruplo::VerificaPaso,
+                    paso.clone(),
+                    Operando::Vacio,
+                    Operando::Variable(nombre),
+                ));
+                let cero = Operando::Direccion(self.memoria_virtual.asignar_constante_entera(0).map_err(Diagnostico::memoria_agotada)?);
+                let positivo = self.emitir_cuadruplo(OperadorCuadruplo::MayorQue, paso.clone(), cero, TipoDato::Booleano)?;
+                let maximo = Operando::Direccion(self.memoria_virtual.asignar_constante_entera(i32::MAX).map_err(Diagnostico::memoria_agotada)?);
+                let minimo = Operando::Direccion(self.memoria_virtual.asignar_constante_entera(i32::MIN).map_err(Diagnostico::memoria_agotada)?);
+                let signo = SignoPaso::AlEjecutar(positivo);
+                let margen = self.emitir_segun_signo(
+                    &signo,
+                    (OperadorCuadruplo::Resta, maximo, paso.clone()),
+                    (OperadorCuadruplo::Resta, minimo, paso.clone()),
+                    TipoDato::Entero,
+                )?;
+                (signo, margen)
+            }
+        };
+
+        let control = self.ppara.last_mut()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay ciclo para abierto"))?;
+        control.paso = paso;
+        control.signo = signo.clone();
+        control.margen = margen;
+        let (variable, limite) = (control.variable.clone(), control.limite.clone());
+
+        self.marcar_inicio_ciclo();
+
+        let condicion = self.emitir_segun_signo(
+            &signo,
+            (OperadorCuadruplo::MenorIgual, variable.clone(), limite.clone()),
+            (OperadorCuadruplo::MayorIgual, variable, limite),
+            TipoDato::Booleano,
+        )?;
+
+        self.pilao.push(condicion);
+        self.ptypes.push(TipoDato::Booleano);
+        self.pspans.push(self.span_actual);
+
+        Ok(())
+    }
+
+    /// Fin del ciclo para: incrementa la variable de control, regresa al inicio
+    /// y rellena el GOTOF de salida y los saltos de `rompe` / `continua`
+    ///
+    /// Antes de incrementar sale del ciclo si `id` ya pasó el margen: `id + paso`
+    /// desbordaría y de todos modos rebasaría el límite.
+    pub fn generar_fin_para(&mut self) -> Result<(), Diagnostico> {
+        let control = self.ppara.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay ciclo para abierto"))?;
+
+        // `continua` salta al incremento
+        self.rellenar_continua(self.quad.len())?;
+
+        // Si id > margen (o id < margen al bajar), salir como con `rompe`
+        let sin_margen = self.emitir_segun_signo(
+            &control.signo,
+            (OperadorCuadruplo::MayorQue, control.variable.clone(), control.margen.clone()),
+            (OperadorCuadruplo::MenorQue, control.variable.clone(), control.margen.clone()),
+            TipoDato::Booleano,
+        )?;
+        self.quad.push_back(Cuadruplo::new(
+            OperadorCuadruplo::GotoV,
+            sin_margen.clone(),
+            Operando::Vacio,
+            Operando::Pendiente,
+        ));
+        let pos_salida = self.quad.len() - 1;
+        self.pciclos.last_mut()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay ciclo abierto para la salida del para"))?
+            .rompe.push(pos_salida);
+        self.liberar_si_temporal(&sin_margen);
+
+        // id = id + paso
+        let siguiente = self.emitir_cuadruplo_entero(
+            OperadorCuadruplo::Suma,
+            control.variable.clone(),
+            control.paso.clone(),
+        )?;
+        self.quad.push_back(Cuadruplo::new(
+            OperadorCuadruplo::Asignacion,
+            siguiente.clone(),
+            Operando::Vacio,
+            control.variable,
+        ));
+        self.liberar_si_temporal(&siguiente);
+
+        self.generar_fin_ciclo()?;
+
+        // El límite, el paso y lo derivado de ellos ya no se usan después del ciclo
+        self.liberar_si_temporal(&control.limite);
+        self.liberar_si_temporal(&control.paso);
+        self.liberar_si_temporal(&control.margen);
+        if let SignoPaso::AlEjecutar(positivo) = &control.signo {
+            self.liberar_si_temporal(positivo);
+        }
+
+        Ok(())
+    }
+
+    /// Genera `positivo` o `negativo` según el signo del paso, con el resultado en un
+    /// temporal nuevo. Si el signo se conoce al ejecutar, genera ambos con saltos:
+    ///
+    /// ```text
+    /// (gotof, paso>0, -, L1)
+    /// positivo
+    /// (goto, -, -, L2)
+    /// L1: negativo
+    /// L2:
+    /// ```
+    fn emitir_segun_signo(
+        &mut self,
+        signo: &SignoPaso,
+        positivo: (OperadorCuadruplo, Operando, Operando),
+        negativo: (OperadorCuadruplo, Operando, Operando),
+        tipo: TipoDato,
+    ) -> Result<Operando, Diagnostico> {
+        let es_positivo = match signo {
+            SignoPaso::Positivo => return self.emitir_cuadruplo(positivo.0, positivo.1, positivo.2, tipo),
+            SignoPaso::Negativo => return self.emitir_cuadruplo(negativo.0, negativo.1, negativo.2, tipo),
+            SignoPaso::AlEjecutar(es_positivo) => es_positivo.clone(),
+        };
+
+        let resultado = Operando::Direccion(self.memoria_virtual.asignar_variable(tipo, TipoSegmento::Temporal).map_err(Diagnostico::memoria_agotada)?);
+        self.quad.push_back(Cuadruplo::new(OperadorCuadruplo::GotoF, es_positivo, Operando::Vacio, Operando::Pendiente));
+        let pos_gotof = self.quad.len() - 1;
+        self.quad.push_back(Cuadruplo::new(positivo.0, positivo.1, positivo.2, resultado.clone()));
+        let pos_goto = self.generar_goto_pendiente();
+        self.rellenar_salto(pos_gotof, self.quad.len())?;
+        self.quad.push_back(Cuadruplo::new(negativo.0, negativo.1, negativo.2, resultado.clone()));
+        self.rellenar_salto(pos_goto, self.quad.len())?;
+
+        Ok(resultado)
+    }
+
+    /// Saca de PilaO el límite o paso del ciclo para y valida que sea entero.
+    /// Las constantes y temporales se usan tal cual (el temporal queda reservado hasta el fin
+    /// del ciclo); una variable se copia a un temporal para que el cuerpo no pueda alterarlo.
+    /// Regresa también la ubicación de la expresión
+    fn fijar_operando_para(&mut self, que: &str) -> Result<(Operando, Option<Span>), Diagnostico> {
+        let operando = self.pilao.pop()
+            .ok_or_else(|| Diagnostico::interno(format!("Error: No hay expresión para el {} del ciclo para", que)))?;
+        let tipo = self.ptypes.pop()
+            .ok_or_else(|| Diagnostico::interno(format!("Error: No hay tipo para el {} del ciclo para", que)))?;
+        let span = self.pspans.pop().flatten();
+
+        if tipo != TipoDato::Entero {
+            return Err(Diagnostico::error(codigos::TIPOS_INCOMPATIBLES, format!(
+                "Error semántico: El {} del ciclo para debe ser entero, se encontró {:?}",
+                que, tipo
+            )).con_span_por_omision(span)
+                .con_nota(format!("el {} de un ciclo para debe ser una expresión entera", que)));
+        }
+
+        if let Operando::Direccion(dir) = operando
+            && matches!(
+                self.memoria_virtual.obtener_segmento_desde_direccion(dir).map_err(Diagnostico::interno)?,
+                TipoSegmento::Constante | TipoSegmento::Temporal
+            )
+        {
+            return Ok((operando, span));
+        }
+
+        let dir_temporal = self.memoria_virtual.asignar_variable(TipoDato::Entero, TipoSegmento::Temporal).map_err(Diagnostico::memoria_agotada)?;
+        let copia = Operando::Direccion(dir_temporal);
+        self.quad.push_back(Cuadruplo::new(
+            OperadorCuadruplo::Asignacion,
+            operando.clone(),
+            Operando::Vacio,
+            copia.clone(),
+        ));
+        self.liberar_si_temporal(&operando);
+
+        Ok((copia, span))
+    }
+
     // ==================== LLAMADAS A FUNCIONES ====================
 
     /// Marcar el inicio de una función durante la compilación
     /// Esto debe llamarse cuando se reduce la producción de declaración de función
-    pub fn iniciar_funcion(&mut self, nombre: &str) -> Result<(), String> {
+    pub fn iniciar_funcion(&mut self, nombre: &str) -> Result<(), Diagnostico> {
         let contexto = self.obtener_contexto()?;
 
         // Obtener información del contexto semántico
         let entrada_funcion = contexto.dir_funciones.buscar_funcion(nombre)
-            .ok_or_else(|| format!("Función '{}' no existe en el directorio", nombre))?;
+            .ok_or_else(|| Diagnostico::no_declarado(format!("Función '{}' no existe en el directorio", nombre)))?;
 
         // Extraer tipo de retorno
         let (tiene_retorno, tipo_retorno) = match &entrada_funcion.tipo_retorno {
             crate::semantico::tipos::TipoRetorno::Nula => (false, None),
-            crate::semantico::tipos::TipoRetorno::Tipo(t) => (true, Some(t.clone())),
+            crate::semantico::tipos::TipoRetorno::Tipo(t) => (true, Some(*t)),
         };
 
         // Obtener parámetros de la tabla de variables
@@ -663,7 +1605,7 @@ impl GeneradorCuadruplos {
 
     /// Marcar el fin de una función y generar ENDFUNC
     /// Se llama cuando se reduce la producción completa de función
-    pub fn finalizar_funcion(&mut self) -> Result<(), String> {
+    pub fn finalizar_funcion(&mut self) -> Result<(), Diagnostico> {
         // Generar cuádruplo ENDFUNC
         self.generar_endfunc()?;
 
@@ -674,20 +1616,19 @@ impl GeneradorCuadruplos {
     }
 
     /// Paso 1: Verificar que una función existe y guardar su nombre
-    pub fn iniciar_llamada(&mut self, nombre_func: &str) -> Result<(), String> {
+    pub fn iniciar_llamada(&mut self, nombre_func: &str) -> Result<(), Diagnostico> {
         let contexto = self.obtener_contexto()?;
         contexto.verificar_funcion_existe(nombre_func)?;
 
         // Inicializar tracking de parámetros
-        self.contador_parametros = 0;
-        self.funcion_llamada_actual = Some(nombre_func.to_string());
+        self.pllamadas.push((nombre_func.to_string(), 0));
 
         Ok(())
     }
 
     /// Paso 2: Generar ERA (Expand Activation Record)
     /// Se invoca cuando se reduce <LLAMADA_HEADER> (después de procesar id)
-    pub fn generar_era(&mut self, nombre_func: &str) -> Result<(), String> {
+    pub fn generar_era(&mut self, nombre_func: &str) -> Result<(), Diagnostico> {
         // Verificar que la función existe
         let contexto = self.obtener_contexto()?;
         contexto.verificar_funcion_existe(nombre_func)?;
@@ -706,21 +1647,42 @@ impl GeneradorCuadruplos {
 
     /// Paso 3-4: Generar PARAM
     /// Se invoca cuando se procesa cada expresión en la lista de argumentos
-    pub fn generar_param(&mut self) -> Result<(), String> {
+    pub fn generar_param(&mut self) -> Result<(), Diagnostico> {
         let operando = self.pilao.pop()
-            .ok_or("Error: No hay parámetro para pasar")?;
-        self.ptypes.pop();
+            .ok_or_else(|| Diagnostico::interno("Error: No hay parámetro para pasar"))?;
+        let tipo = self.ptypes.pop()
+            .ok_or_else(|| Diagnostico::interno("Error: No hay tipo para el parámetro"))?;
+        let span = self.pspans.pop().flatten();
 
         // Usar contador actual y luego incrementar
-        let num_param = self.contador_parametros;
-        self.contador_parametros += 1;
+        let (nombre_func, num_param) = self.pllamadas.last_mut()
+            .map(|(nombre, contador)| {
+                *contador += 1;
+                (nombre.clone(), *contador - 1)
+            })
+            .ok_or_else(|| Diagnostico::interno("Error: Parámetro fuera de una llamada a función"))?;
+
+        // El argumento se pasa como una asignación al parámetro formal
+        let contexto = self.obtener_contexto()?;
+        let tipo_param = contexto.obtener_tipo_parametro(&nombre_func, num_param)?;
+        let op_asig = crate::semantico::cubo_semantico::Operador::Asignacion;
+        if let crate::semantico::cubo_semantico::ResultadoTipo::Error = self.cubo_semantico.validar(tipo_param, op_asig, tipo) {
+            return Err(Diagnostico::tipos_incompatibles(format!(
+                "Error semántico: tipos incompatibles en el argumento {} de '{}': se esperaba {}, se recibió {}",
+                num_param + 1, nombre_func, tipo_param, tipo
+            )).con_span_por_omision(span));
+        }
+
+        // El destino es la dirección local del parámetro formal, para que la VM
+        // lo guarde en la celda de su tipo
+        let dir_param = contexto.obtener_direccion_parametro(&nombre_func, num_param)?;
 
-        // Generar cuádruplo: (param, argumento, -, num_param)
+        // Generar cuádruplo: (param, argumento, -, dirección_parámetro)
         let cuadruplo = Cuadruplo::new(
             OperadorCuadruplo::Parametro,
             operando.clone(),
             Operando::Vacio,
-            Operando::ConstanteEntera(num_param as i32),
+            Operando::Direccion(dir_param),
         );
 
         self.quad.push_back(cuadruplo);
@@ -731,7 +1693,17 @@ impl GeneradorCuadruplos {
 
     /// Paso 6: Generar GOSUB
     /// Se invoca cuando se reduce <LLAMADA> completa
-    pub fn generar_gosub(&mut self, nombre_func: &str) -> Result<(), String> {
+    pub fn generar_gosub(&mut self, nombre_func: &str) -> Result<(), Diagnostico> {
+        // Los argumentos de más ya fallan en generar_param; aquí se detectan los que faltan
+        let recibidos = self.pllamadas.last().map(|(_, contador)| *contador).unwrap_or(0);
+        let esperados = self.obtener_contexto()?.num_parametros(nombre_func);
+        if recibidos < esperados {
+            return Err(Diagnostico::semantico(format!(
+                "Error semántico: La función '{}' recibe {} parámetro(s), se pasaron {}",
+                nombre_func, esperados, recibidos
+            )));
+        }
+
         // Primero verificamos si la función tiene retorno y obtenemos el tipo
         let tipo_retorno_opt = {
             let contexto = self.obtener_contexto()?;
@@ -740,7 +1712,7 @@ impl GeneradorCuadruplos {
 
         // Si la función tiene retorno, crear temporal ANTES del GOSUB
         let resultado_opt = if let Some(tipo_retorno) = tipo_retorno_opt {
-            let dir_temporal = self.memoria_virtual.asignar_variable(tipo_retorno, TipoSegmento::Temporal)?;
+            let dir_temporal = self.memoria_virtual.asignar_variable(tipo_retorno, TipoSegmento::Temporal).map_err(Diagnostico::memoria_agotada)?;
             Some((Operando::Direccion(dir_temporal), tipo_retorno))
         } else {
             None
@@ -769,18 +1741,18 @@ impl GeneradorCuadruplos {
         if let Some((resultado, tipo_retorno)) = resultado_opt {
             self.pilao.push(resultado);
             self.ptypes.push(tipo_retorno);
+            self.pspans.push(self.span_actual);
         }
 
-        // Resetear tracking de parámetros
-        self.contador_parametros = 0;
-        self.funcion_llamada_actual = None;
+        // Terminar tracking de parámetros
+        self.pllamadas.pop();
 
         Ok(())
     }
 
     /// Generar ENDFUNC (fin de función)
     /// Se invoca cuando se reduce <FUNCS> completa
-    pub fn generar_endfunc(&mut self) -> Result<(), String> {
+    pub fn generar_endfunc(&mut self) -> Result<(), Diagnostico> {
         let cuadruplo = Cuadruplo::new(
             OperadorCuadruplo::EndFunc,
             Operando::Vacio,
@@ -794,14 +1766,26 @@ impl GeneradorCuadruplos {
 
     /// Generar RETURN (retorno de función con valor)
     /// Se invoca cuando se procesa un estatuto return
-    pub fn generar_return(&mut self) -> Result<(), String> {
+    pub fn generar_return(&mut self) -> Result<(), Diagnostico> {
         let operando = self.pilao.pop()
-            .ok_or("Error: No hay valor de retorno")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay valor de retorno"))?;
         let tipo = self.ptypes.pop()
-            .ok_or("Error: No hay tipo para valor de retorno")?;
+            .ok_or_else(|| Diagnostico::interno("Error: No hay tipo para valor de retorno"))?;
+        let span = self.pspans.pop().flatten();
+
+        // El valor se convierte al tipo de retorno como en una asignación
+        let funcion = self.obtener_contexto()?.alcance_actual().to_string();
+        let tipo_retorno = self.obtener_contexto()?.obtener_tipo_retorno_funcion(&funcion)?;
+        let op_asig = crate::semantico::cubo_semantico::Operador::Asignacion;
+        if let crate::semantico::cubo_semantico::ResultadoTipo::Error = self.cubo_semantico.validar(tipo_retorno, op_asig, tipo) {
+            return Err(Diagnostico::tipos_incompatibles(format!(
+                "Error semántico: tipos incompatibles en regresa de '{}': se esperaba {}, se recibió {}",
+                funcion, tipo_retorno, tipo
+            )).con_span_por_omision(span));
+        }
 
         // Crear un temporal para el valor de retorno
-        let dir_temporal = self.memoria_virtual.asignar_variable(tipo, TipoSegmento::Temporal)?;
+        let dir_temporal = self.memoria_virtual.asignar_variable(tipo_retorno, TipoSegmento::Temporal).map_err(Diagnostico::memoria_agotada)?;
         let resultado = Operando::Direccion(dir_temporal);
 
         // Generar cuádruplo: (=, expresión, -, temp_retorno)
@@ -830,16 +1814,24 @@ impl GeneradorCuadruplos {
 
     // ==================== UTILIDADES ====================
 
+    /// Span que cubre ambos operandos (o el que tenga ubicación)
+    fn unir_spans(izq: Option<Span>, der: Option<Span>) -> Option<Span> {
+        match (izq, der) {
+            (Some(izq), Some(der)) => Some(izq.unir(&der)),
+            (izq, der) => izq.or(der),
+        }
+    }
+
     /// Libera un temporal si el operando es temporal
     fn liberar_si_temporal(&mut self, operando: &Operando) {
-        if let Operando::Direccion(dir) = operando {
+        if let Operando::Direccion(dir) | Operando::Apuntador(dir) = operando {
             self.memoria_virtual.liberar_temporal(*dir);
         }
     }
 
     /// Convierte OperadorCuadruplo a Operador del cubo semántico
     fn convertir_operador_semantico(&self, op: OperadorCuadruplo)
-        -> Result<crate::semantico::cubo_semantico::Operador, String> {
+        -> Result<crate::semantico::cubo_semantico::Operador, Diagnostico> {
         use crate::semantico::cubo_semantico::Operador;
 
         match op {
@@ -847,12 +1839,18 @@ impl GeneradorCuadruplos {
             OperadorCuadruplo::Resta => Ok(Operador::Resta),
             OperadorCuadruplo::Multiplicacion => Ok(Operador::Multiplicacion),
             OperadorCuadruplo::Division => Ok(Operador::Division),
+            OperadorCuadruplo::Modulo => Ok(Operador::Modulo),
             OperadorCuadruplo::MayorQue => Ok(Operador::MayorQue),
             OperadorCuadruplo::MenorQue => Ok(Operador::MenorQue),
+            OperadorCuadruplo::MayorIgual => Ok(Operador::MayorIgual),
+            OperadorCuadruplo::MenorIgual => Ok(Operador::MenorIgual),
             OperadorCuadruplo::Igual => Ok(Operador::Igual),
             OperadorCuadruplo::Diferente => Ok(Operador::Diferente),
+            OperadorCuadruplo::Y => Ok(Operador::Y),
+            OperadorCuadruplo::O => Ok(Operador::O),
+            OperadorCuadruplo::No => Ok(Operador::No),
             OperadorCuadruplo::Asignacion => Ok(Operador::Asignacion),
-            _ => Err(format!("Operador no soportado para conversión semántica: {:?}", op)),
+            _ => Err(Diagnostico::interno(format!("Operador no soportado para conversión semántica: {:?}", op))),
         }
     }
 
@@ -877,7 +1875,7 @@ impl GeneradorCuadruplos {
             return;
         }
 
-        println!("  {:>4}  {}", "Num", "Cuádruplo");
+        println!("  {:>4}  Cuádruplo", "Num");
         println!("  {}", "─".repeat(60));
 
         for (i, cuadruplo) in self.quad.iter().enumerate() {
@@ -893,8 +1891,11 @@ impl GeneradorCuadruplos {
         self.poper.clear();
         self.pilao.clear();
         self.ptypes.clear();
+        self.pspans.clear();
         self.quad.clear();
         self.pjumps.clear();
+        self.ppara.clear();
+        self.pciclos.clear();
         self.memoria_virtual.reiniciar();
     }
 
@@ -908,7 +1909,7 @@ impl GeneradorCuadruplos {
 
     /// Exporta el programa compilado como ProgramaObjeto
     /// Este método crea el "binario" listo para la VM
-    pub fn exportar_programa(&self, nombre_programa: String) -> Result<crate::intermedio::programa::ProgramaObjeto, String> {
+    pub fn exportar_programa(&self, nombre_programa: String) -> Result<crate::intermedio::programa::ProgramaObjeto, Diagnostico> {
         use crate::intermedio::programa::{ProgramaObjeto, InfoFuncionPrograma};
         use std::collections::HashMap;
         use crate::vm::memoria::Valor;
@@ -969,9 +1970,14 @@ impl GeneradorCuadruplos {
             }
         }
 
-        // Agregar constantes char (almacenadas como entero)
+        // Agregar constantes char
         for (valor, direccion) in tabla_chars {
-            mapa_constantes.insert(direccion, Valor::Entero(valor as i32));
+            mapa_constantes.insert(direccion, Valor::Char(valor));
+        }
+
+        // Agregar constantes booleanas (verdadero / falso)
+        for (valor, direccion) in self.memoria_virtual.obtener_constantes_booleanas() {
+            mapa_constantes.insert(direccion, Valor::Booleano(valor));
         }
 
         // Crear programa objeto
@@ -981,7 +1987,7 @@ impl GeneradorCuadruplos {
             mapa_funciones,
             mapa_constantes,
             self.tabla_strings.clone(),
-        );
+        ).con_distribucion(self.memoria_virtual.distribucion());
 
         Ok(programa)
     }
@@ -1010,6 +2016,21 @@ mod tests {
         assert!(generador.procesar_operando("3.14").is_ok());
         assert_eq!(generador.pilao.len(), 2);
         assert_eq!(generador.ptypes.len(), 2);
+
+        // Un entero fuera de 32 bits es error, no un flotante
+        assert!(generador.procesar_operando("2147483647").is_ok());
+        let error = generador.procesar_operando("2147483649").unwrap_err();
+        assert!(error.mensaje.contains("no cabe en un entero de 32 bits"));
+        assert_eq!(generador.pilao.len(), 3);
+
+        // 2147483648 solo es válido negado: -2147483648 es i32::MIN
+        assert!(generador.procesar_operando("2147483648").is_ok());
+        assert!(generador.generar_negativo("2147483648").is_ok());
+        assert_eq!(generador.pilao.len(), 4);
+        assert!(generador.procesar_operando("2147483648").is_ok());
+        let error = generador.verificar_literal_sin_signo().unwrap_err();
+        assert!(error.mensaje.contains("2147483648 no cabe en un entero de 32 bits"));
+        assert_eq!(generador.pilao.len(), 4);
     }
 
     #[test]
@@ -4,259 +4,163 @@
 //! Organiza la memoria en segmentos (global, local, temporal, constante)
 //! y asigna direcciones únicas a cada variable según su tipo y alcance.
 //!
-//! ## Esquema de Direcciones Virtuales
+//! Los rangos salen de una `DistribucionMemoria` (ver `distribucion.rs`), la
+//! misma que usa la VM; se puede ampliar con `MemoriaVirtual::con_distribucion`.
+//!
+//! ## Esquema de Direcciones Virtuales (distribución por defecto)
 //!
 //! ```text
-//! SEGMENTO GLOBAL (1000-6999)
+//! SEGMENTO GLOBAL (1000-10999)
 //!   ├── Enteros:   1000-2999 (2000 espacios)
 //!   ├── Flotantes: 3000-4999 (2000 espacios)
-//!   └── Chars:     5000-6999 (2000 espacios)
+//!   ├── Chars:     5000-6999 (2000 espacios)
+//!   ├── Booleanos: 7000-8999 (2000 espacios)
+//!   └── Letreros:  9000-10999 (2000 espacios)
 //!
-//! SEGMENTO LOCAL (7000-12999)
-//!   ├── Enteros:   7000-8999 (2000 espacios)
-//!   ├── Flotantes: 9000-10999 (2000 espacios)
-//!   └── Chars:     11000-12999 (2000 espacios)
+//! SEGMENTO LOCAL (11000-20999)
+//!   ├── Enteros:   11000-12999 (2000 espacios)
+//!   ├── Flotantes: 13000-14999 (2000 espacios)
+//!   ├── Chars:     15000-16999 (2000 espacios)
+//!   ├── Booleanos: 17000-18999 (2000 espacios)
+//!   └── Letreros:  19000-20999 (2000 espacios)
 //!
-//! SEGMENTO TEMPORAL (13000-18999)
-//!   ├── Enteros:   13000-14999 (2000 espacios)
-//!   ├── Flotantes: 15000-16999 (2000 espacios)
-//!   └── Chars:     17000-18999 (2000 espacios)
+//! SEGMENTO TEMPORAL (21000-30999)
+//!   ├── Enteros:   21000-22999 (2000 espacios)
+//!   ├── Flotantes: 23000-24999 (2000 espacios)
+//!   ├── Chars:     25000-26999 (2000 espacios)
+//!   ├── Booleanos: 27000-28999 (2000 espacios)
+//!   └── Letreros:  29000-30999 (2000 espacios)
 //!
-//! SEGMENTO CONSTANTE (19000-24999)
-//!   ├── Enteros:   19000-20999 (2000 espacios)
-//!   ├── Flotantes: 21000-22999 (2000 espacios)
-//!   └── Chars:     23000-24999 (2000 espacios)
+//! SEGMENTO CONSTANTE (31000-40999)
+//!   ├── Enteros:   31000-32999 (2000 espacios)
+//!   ├── Flotantes: 33000-34999 (2000 espacios)
+//!   ├── Chars:     35000-36999 (2000 espacios)
+//!   ├── Booleanos: 37000-38999 (2000 espacios)
+//!   └── Letreros:  39000-40999 (2000 espacios)
 //! ```
 
 use crate::semantico::TipoDato;
+use super::distribucion::{indice_tipo, DistribucionMemoria, TIPOS_EN_MEMORIA};
 use std::collections::{HashMap, HashSet};
 
-// Límites de direcciones para cada segmento y tipo
-const GLOBAL_ENTERO_INICIO: usize = 1000;
-const GLOBAL_ENTERO_FIN: usize = 2999;
-const GLOBAL_FLOTANTE_INICIO: usize = 3000;
-const GLOBAL_FLOTANTE_FIN: usize = 4999;
-const GLOBAL_CHAR_INICIO: usize = 5000;
-const GLOBAL_CHAR_FIN: usize = 6999;
-
-const LOCAL_ENTERO_INICIO: usize = 7000;
-const LOCAL_ENTERO_FIN: usize = 8999;
-const LOCAL_FLOTANTE_INICIO: usize = 9000;
-const LOCAL_FLOTANTE_FIN: usize = 10999;
-const LOCAL_CHAR_INICIO: usize = 11000;
-const LOCAL_CHAR_FIN: usize = 12999;
-
-const TEMPORAL_ENTERO_INICIO: usize = 13000;
-const TEMPORAL_ENTERO_FIN: usize = 14999;
-const TEMPORAL_FLOTANTE_INICIO: usize = 15000;
-const TEMPORAL_FLOTANTE_FIN: usize = 16999;
-const TEMPORAL_CHAR_INICIO: usize = 17000;
-const TEMPORAL_CHAR_FIN: usize = 18999;
-
-const CONSTANTE_ENTERO_INICIO: usize = 19000;
-const CONSTANTE_ENTERO_FIN: usize = 20999;
-const CONSTANTE_FLOTANTE_INICIO: usize = 21000;
-const CONSTANTE_FLOTANTE_FIN: usize = 22999;
-const CONSTANTE_CHAR_INICIO: usize = 23000;
-const CONSTANTE_CHAR_FIN: usize = 24999;
-
-/// Tipo de segmento de memoria
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum TipoSegmento {
-    Global,
-    Local,
-    Temporal,
-    Constante,
+pub use super::distribucion::TipoSegmento;
+
+/// Nombre en plural de un tipo para los mensajes de desbordamiento
+fn plural(tipo: TipoDato) -> &'static str {
+    match tipo {
+        TipoDato::Entero => "enteros",
+        TipoDato::Flotante => "flotantes",
+        TipoDato::Char => "chars",
+        TipoDato::Letrero => "letreros",
+        TipoDato::Booleano => "booleanos",
+    }
 }
 
 /// Gestor de memoria virtual
 pub struct MemoriaVirtual {
-    // Contadores de direcciones por segmento y tipo
-    global_entero: usize,
-    global_flotante: usize,
-    global_char: usize,
+    /// Esquema de direcciones (rangos por segmento y tipo)
+    distribucion: DistribucionMemoria,
 
-    local_entero: usize,
-    local_flotante: usize,
-    local_char: usize,
-
-    temporal_entero: usize,
-    temporal_flotante: usize,
-    temporal_char: usize,
-
-    constante_entero: usize,
-    constante_flotante: usize,
-    constante_char: usize,
+    /// Siguiente dirección libre por segmento y tipo
+    /// (índices de `TipoSegmento::TODOS` y `TIPOS_EN_MEMORIA`)
+    contadores: [[usize; TIPOS_EN_MEMORIA.len()]; 4],
 
     // Tablas de constantes (valor -> dirección)
     tabla_constantes_entero: HashMap<i32, usize>,
     tabla_constantes_flotante: HashMap<String, usize>, // String para evitar problemas de precisión
     tabla_constantes_char: HashMap<char, usize>,
+    tabla_constantes_booleano: HashMap<bool, usize>,
 
-    // Pools de temporales disponibles para reutilización
-    temporales_disponibles_entero: HashSet<usize>,
-    temporales_disponibles_flotante: HashSet<usize>,
-    temporales_disponibles_char: HashSet<usize>,
+    /// Pools de temporales disponibles para reutilización, por tipo
+    temporales_disponibles: [HashSet<usize>; TIPOS_EN_MEMORIA.len()],
 }
 
 impl MemoriaVirtual {
-    /// Crea un nuevo gestor de memoria virtual
+    /// Crea un nuevo gestor de memoria virtual con la distribución por defecto
     pub fn new() -> Self {
-        MemoriaVirtual {
-            global_entero: GLOBAL_ENTERO_INICIO,
-            global_flotante: GLOBAL_FLOTANTE_INICIO,
-            global_char: GLOBAL_CHAR_INICIO,
-
-            local_entero: LOCAL_ENTERO_INICIO,
-            local_flotante: LOCAL_FLOTANTE_INICIO,
-            local_char: LOCAL_CHAR_INICIO,
-
-            temporal_entero: TEMPORAL_ENTERO_INICIO,
-            temporal_flotante: TEMPORAL_FLOTANTE_INICIO,
-            temporal_char: TEMPORAL_CHAR_INICIO,
-
-            constante_entero: CONSTANTE_ENTERO_INICIO,
-            constante_flotante: CONSTANTE_FLOTANTE_INICIO,
-            constante_char: CONSTANTE_CHAR_INICIO,
+        Self::con_distribucion(DistribucionMemoria::default())
+    }
 
+    /// Crea un gestor de memoria virtual con una distribución propia
+    pub fn con_distribucion(distribucion: DistribucionMemoria) -> Self {
+        let mut memoria = MemoriaVirtual {
+            distribucion,
+            contadores: [[0; TIPOS_EN_MEMORIA.len()]; 4],
             tabla_constantes_entero: HashMap::new(),
             tabla_constantes_flotante: HashMap::new(),
             tabla_constantes_char: HashMap::new(),
+            tabla_constantes_booleano: HashMap::new(),
+            temporales_disponibles: Default::default(),
+        };
+        memoria.reiniciar();
+        memoria
+    }
+
+    /// Distribución de memoria usada para asignar direcciones
+    pub fn distribucion(&self) -> DistribucionMemoria {
+        self.distribucion
+    }
 
-            temporales_disponibles_entero: HashSet::new(),
-            temporales_disponibles_flotante: HashSet::new(),
-            temporales_disponibles_char: HashSet::new(),
+    /// Reinicia los contadores de un segmento al inicio de cada rango
+    fn reiniciar_segmento(&mut self, segmento: TipoSegmento) {
+        for tipo in TIPOS_EN_MEMORIA {
+            self.contadores[segmento.indice()][indice_tipo(tipo)] =
+                *self.distribucion.rango(segmento, tipo).start();
         }
     }
 
-    /// Asigna una dirección virtual para una variable
-    pub fn asignar_variable(&mut self, tipo: TipoDato, segmento: TipoSegmento) -> Result<usize, String> {
-        match (segmento, tipo) {
-            // Segmento Global
-            (TipoSegmento::Global, TipoDato::Entero) => {
-                if self.global_entero > GLOBAL_ENTERO_FIN {
-                    return Err("Error: Desbordamiento de memoria global para enteros".to_string());
-                }
-                let dir = self.global_entero;
-                self.global_entero += 1;
-                Ok(dir)
-            }
-            (TipoSegmento::Global, TipoDato::Flotante) => {
-                if self.global_flotante > GLOBAL_FLOTANTE_FIN {
-                    return Err("Error: Desbordamiento de memoria global para flotantes".to_string());
-                }
-                let dir = self.global_flotante;
-                self.global_flotante += 1;
-                Ok(dir)
-            }
-            (TipoSegmento::Global, TipoDato::Char) => {
-                if self.global_char > GLOBAL_CHAR_FIN {
-                    return Err("Error: Desbordamiento de memoria global para chars".to_string());
-                }
-                let dir = self.global_char;
-                self.global_char += 1;
-                Ok(dir)
-            }
-            (TipoSegmento::Global, TipoDato::Letrero) => {
-                if self.global_char > GLOBAL_CHAR_FIN {
-                    return Err("Error: Desbordamiento de memoria global para letreros".to_string());
-                }
-                let dir = self.global_char;
-                self.global_char += 1;
-                Ok(dir)
-            }
+    /// Reserva `tamano` direcciones contiguas del rango (segmento, tipo)
+    fn reservar(&mut self, segmento: TipoSegmento, tipo: TipoDato, tamano: usize) -> Result<usize, String> {
+        let fin = *self.distribucion.rango(segmento, tipo).end();
+        let contador = &mut self.contadores[segmento.indice()][indice_tipo(tipo)];
+
+        // Sin `contador + tamano`: un bloque enorme desbordaría la suma
+        if tamano == 0 || tamano > (fin + 1).saturating_sub(*contador) {
+            return Err(if tamano == 1 {
+                format!("Error: Desbordamiento de memoria {} para {}", segmento.nombre(), plural(tipo))
+            } else {
+                format!(
+                    "Error: Desbordamiento de memoria {} al reservar {} casillas de {}",
+                    segmento.nombre(), tamano, plural(tipo)
+                )
+            });
+        }
 
-            // Segmento Local
-            (TipoSegmento::Local, TipoDato::Entero) => {
-                if self.local_entero > LOCAL_ENTERO_FIN {
-                    return Err("Error: Desbordamiento de memoria local para enteros".to_string());
-                }
-                let dir = self.local_entero;
-                self.local_entero += 1;
-                Ok(dir)
-            }
-            (TipoSegmento::Local, TipoDato::Flotante) => {
-                if self.local_flotante > LOCAL_FLOTANTE_FIN {
-                    return Err("Error: Desbordamiento de memoria local para flotantes".to_string());
-                }
-                let dir = self.local_flotante;
-                self.local_flotante += 1;
-                Ok(dir)
-            }
-            (TipoSegmento::Local, TipoDato::Char) => {
-                if self.local_char > LOCAL_CHAR_FIN {
-                    return Err("Error: Desbordamiento de memoria local para chars".to_string());
-                }
-                let dir = self.local_char;
-                self.local_char += 1;
-                Ok(dir)
-            }
-            (TipoSegmento::Local, TipoDato::Letrero) => {
-                if self.local_char > LOCAL_CHAR_FIN {
-                    return Err("Error: Desbordamiento de memoria local para letreros".to_string());
-                }
-                let dir = self.local_char;
-                self.local_char += 1;
-                Ok(dir)
-            }
+        let base = *contador;
+        *contador += tamano;
+        Ok(base)
+    }
 
-            // Segmento Temporal (con pool de reutilización)
-            (TipoSegmento::Temporal, TipoDato::Entero) => {
-                // Intentar reutilizar una dirección del pool
-                if let Some(&dir) = self.temporales_disponibles_entero.iter().next() {
-                    self.temporales_disponibles_entero.remove(&dir);
-                    return Ok(dir);
-                }
-                // Si no hay disponibles, asignar nueva
-                if self.temporal_entero > TEMPORAL_ENTERO_FIN {
-                    return Err("Error: Desbordamiento de memoria temporal para enteros".to_string());
-                }
-                let dir = self.temporal_entero;
-                self.temporal_entero += 1;
-                Ok(dir)
+    /// Asigna una dirección virtual para una variable
+    ///
+    /// Los temporales reutilizan primero las direcciones liberadas (pool AVAIL).
+    pub fn asignar_variable(&mut self, tipo: TipoDato, segmento: TipoSegmento) -> Result<usize, String> {
+        match segmento {
+            // Constantes - Estas no se asignan aquí, ver asignar_constante_*
+            TipoSegmento::Constante => {
+                Err("Error: Use asignar_constante_* para constantes".to_string())
             }
-            (TipoSegmento::Temporal, TipoDato::Flotante) => {
+            TipoSegmento::Temporal => {
                 // Intentar reutilizar una dirección del pool
-                if let Some(&dir) = self.temporales_disponibles_flotante.iter().next() {
-                    self.temporales_disponibles_flotante.remove(&dir);
+                let pool = &mut self.temporales_disponibles[indice_tipo(tipo)];
+                if let Some(&dir) = pool.iter().next() {
+                    pool.remove(&dir);
                     return Ok(dir);
                 }
                 // Si no hay disponibles, asignar nueva
-                if self.temporal_flotante > TEMPORAL_FLOTANTE_FIN {
-                    return Err("Error: Desbordamiento de memoria temporal para flotantes".to_string());
-                }
-                let dir = self.temporal_flotante;
-                self.temporal_flotante += 1;
-                Ok(dir)
-            }
-            (TipoSegmento::Temporal, TipoDato::Char) => {
-                // Intentar reutilizar una dirección del pool
-                if let Some(&dir) = self.temporales_disponibles_char.iter().next() {
-                    self.temporales_disponibles_char.remove(&dir);
-                    return Ok(dir);
-                }
-                // Si no hay disponibles, asignar nueva
-                if self.temporal_char > TEMPORAL_CHAR_FIN {
-                    return Err("Error: Desbordamiento de memoria temporal para chars".to_string());
-                }
-                let dir = self.temporal_char;
-                self.temporal_char += 1;
-                Ok(dir)
-            }
-            (TipoSegmento::Temporal, TipoDato::Letrero) => {
-                if self.temporal_char > TEMPORAL_CHAR_FIN {
-                    return Err("Error: Desbordamiento de memoria temporal para letreros".to_string());
-                }
-                let dir = self.temporal_char;
-                self.temporal_char += 1;
-                Ok(dir)
+                self.reservar(segmento, tipo, 1)
             }
+            _ => self.reservar(segmento, tipo, 1),
+        }
+    }
 
-            // Constantes - Estas no se asignan aquí, ver asignar_constante_*
-            (TipoSegmento::Constante, _) => {
-                Err("Error: Use asignar_constante_* para constantes".to_string())
-            }
+    /// Asigna un bloque contiguo de `tamano` direcciones (para arreglos)
+    /// Retorna la dirección base; solo válido en los segmentos global y local
+    pub fn asignar_bloque(&mut self, tipo: TipoDato, segmento: TipoSegmento, tamano: usize) -> Result<usize, String> {
+        if !matches!(segmento, TipoSegmento::Global | TipoSegmento::Local) {
+            return Err(format!("Error: No se pueden reservar bloques en el segmento {:?}", segmento));
         }
+        self.reservar(segmento, tipo, tamano)
     }
 
     /// Asigna una dirección para una constante entera (reutiliza si ya existe)
@@ -267,16 +171,18 @@ impl MemoriaVirtual {
         }
 
         // Si no existe, asignar nueva dirección
-        if self.constante_entero > CONSTANTE_ENTERO_FIN {
-            return Err("Error: Desbordamiento de memoria para constantes enteras".to_string());
-        }
-
-        let dir = self.constante_entero;
-        self.constante_entero += 1;
+        let dir = self.reservar(TipoSegmento::Constante, TipoDato::Entero, 1)?;
         self.tabla_constantes_entero.insert(valor, dir);
         Ok(dir)
     }
 
+    /// Valor de la constante entera guardada en `direccion`, si lo es
+    pub fn valor_constante_entera(&self, direccion: usize) -> Option<i32> {
+        self.tabla_constantes_entero.iter()
+            .find(|(_, dir)| **dir == direccion)
+            .map(|(valor, _)| *valor)
+    }
+
     /// Asigna una dirección para una constante flotante (reutiliza si ya existe)
     pub fn asignar_constante_flotante(&mut self, valor: f64) -> Result<usize, String> {
         let key = valor.to_string(); // Usar string como clave para evitar problemas de precisión
@@ -285,12 +191,7 @@ impl MemoriaVirtual {
             return Ok(dir);
         }
 
-        if self.constante_flotante > CONSTANTE_FLOTANTE_FIN {
-            return Err("Error: Desbordamiento de memoria para constantes flotantes".to_string());
-        }
-
-        let dir = self.constante_flotante;
-        self.constante_flotante += 1;
+        let dir = self.reservar(TipoSegmento::Constante, TipoDato::Flotante, 1)?;
         self.tabla_constantes_flotante.insert(key, dir);
         Ok(dir)
     }
@@ -301,28 +202,30 @@ impl MemoriaVirtual {
             return Ok(dir);
         }
 
-        if self.constante_char > CONSTANTE_CHAR_FIN {
-            return Err("Error: Desbordamiento de memoria para constantes char".to_string());
+        let dir = self.reservar(TipoSegmento::Constante, TipoDato::Char, 1)?;
+        self.tabla_constantes_char.insert(valor, dir);
+        Ok(dir)
+    }
+
+    /// Asigna una dirección para una constante booleana (reutiliza si ya existe)
+    pub fn asignar_constante_booleana(&mut self, valor: bool) -> Result<usize, String> {
+        if let Some(&dir) = self.tabla_constantes_booleano.get(&valor) {
+            return Ok(dir);
         }
 
-        let dir = self.constante_char;
-        self.constante_char += 1;
-        self.tabla_constantes_char.insert(valor, dir);
+        let dir = self.reservar(TipoSegmento::Constante, TipoDato::Booleano, 1)?;
+        self.tabla_constantes_booleano.insert(valor, dir);
         Ok(dir)
     }
 
     /// Reinicia los contadores del segmento local (al cambiar de función)
     pub fn reiniciar_local(&mut self) {
-        self.local_entero = LOCAL_ENTERO_INICIO;
-        self.local_flotante = LOCAL_FLOTANTE_INICIO;
-        self.local_char = LOCAL_CHAR_INICIO;
+        self.reiniciar_segmento(TipoSegmento::Local);
     }
 
     /// Reinicia los contadores del segmento temporal
     pub fn reiniciar_temporal(&mut self) {
-        self.temporal_entero = TEMPORAL_ENTERO_INICIO;
-        self.temporal_flotante = TEMPORAL_FLOTANTE_INICIO;
-        self.temporal_char = TEMPORAL_CHAR_INICIO;
+        self.reiniciar_segmento(TipoSegmento::Temporal);
     }
 
     /// Obtiene las tablas de constantes (para serializar al archivo objeto)
@@ -334,104 +237,75 @@ impl MemoriaVirtual {
         )
     }
 
+    /// Obtiene la tabla de constantes booleanas (para serializar al archivo objeto)
+    pub fn obtener_constantes_booleanas(&self) -> HashMap<bool, usize> {
+        self.tabla_constantes_booleano.clone()
+    }
+
     /// Libera una dirección temporal para que pueda ser reutilizada (pool AVAIL)
     pub fn liberar_temporal(&mut self, direccion: usize) {
         // Determinar el tipo basándose en el rango de direcciones
-        if direccion >= TEMPORAL_ENTERO_INICIO && direccion <= TEMPORAL_ENTERO_FIN {
-            self.temporales_disponibles_entero.insert(direccion);
-        } else if direccion >= TEMPORAL_FLOTANTE_INICIO && direccion <= TEMPORAL_FLOTANTE_FIN {
-            self.temporales_disponibles_flotante.insert(direccion);
-        } else if direccion >= TEMPORAL_CHAR_INICIO && direccion <= TEMPORAL_CHAR_FIN {
-            self.temporales_disponibles_char.insert(direccion);
-        }
         // Si no es temporal, no hacer nada (silencioso para simplificar uso)
+        if let Ok((TipoSegmento::Temporal, _)) = self.distribucion.traducir(direccion)
+            && let Ok(tipo) = self.distribucion.tipo_de(direccion)
+        {
+            self.temporales_disponibles[indice_tipo(tipo)].insert(direccion);
+        }
     }
 
     /// Reinicia completamente el gestor de memoria (limpia contadores, pools y tablas)
     pub fn reiniciar(&mut self) {
-        // Reiniciar contadores globales
-        self.global_entero = GLOBAL_ENTERO_INICIO;
-        self.global_flotante = GLOBAL_FLOTANTE_INICIO;
-        self.global_char = GLOBAL_CHAR_INICIO;
-
-        // Reiniciar contadores locales
-        self.local_entero = LOCAL_ENTERO_INICIO;
-        self.local_flotante = LOCAL_FLOTANTE_INICIO;
-        self.local_char = LOCAL_CHAR_INICIO;
-
-        // Reiniciar contadores temporales
-        self.temporal_entero = TEMPORAL_ENTERO_INICIO;
-        self.temporal_flotante = TEMPORAL_FLOTANTE_INICIO;
-        self.temporal_char = TEMPORAL_CHAR_INICIO;
+        // Reiniciar contadores de todos los segmentos
+        for segmento in TipoSegmento::TODOS {
+            self.reiniciar_segmento(segmento);
+        }
 
         // Limpiar pools de temporales disponibles
-        self.temporales_disponibles_entero.clear();
-        self.temporales_disponibles_flotante.clear();
-        self.temporales_disponibles_char.clear();
-
-        // Reiniciar contadores de constantes
-        self.constante_entero = CONSTANTE_ENTERO_INICIO;
-        self.constante_flotante = CONSTANTE_FLOTANTE_INICIO;
-        self.constante_char = CONSTANTE_CHAR_INICIO;
+        for pool in &mut self.temporales_disponibles {
+            pool.clear();
+        }
 
         // Limpiar tablas de constantes
         self.tabla_constantes_entero.clear();
         self.tabla_constantes_flotante.clear();
         self.tabla_constantes_char.clear();
+        self.tabla_constantes_booleano.clear();
     }
 
     /// Retorna estadísticas de uso de memoria
     pub fn obtener_estadisticas(&self) -> String {
-        let temporales_usados_e = self.temporal_entero - TEMPORAL_ENTERO_INICIO;
-        let temporales_usados_f = self.temporal_flotante - TEMPORAL_FLOTANTE_INICIO;
-        let temporales_usados_c = self.temporal_char - TEMPORAL_CHAR_INICIO;
+        let usados = |tipo: TipoDato| {
+            self.contadores[TipoSegmento::Temporal.indice()][indice_tipo(tipo)]
+                - *self.distribucion.rango(TipoSegmento::Temporal, tipo).start()
+        };
 
         format!(
-            "Memoria Virtual - Temporales: E={} F={} C={} | Pool Disponible: E={} F={} C={} | Constantes: E={} F={} C={}",
-            temporales_usados_e,
-            temporales_usados_f,
-            temporales_usados_c,
-            self.temporales_disponibles_entero.len(),
-            self.temporales_disponibles_flotante.len(),
-            self.temporales_disponibles_char.len(),
+            "Memoria Virtual - Temporales: E={} F={} C={} B={} L={} | Pool Disponible: E={} F={} C={} B={} L={} | Constantes: E={} F={} C={} B={}",
+            usados(TipoDato::Entero),
+            usados(TipoDato::Flotante),
+            usados(TipoDato::Char),
+            usados(TipoDato::Booleano),
+            usados(TipoDato::Letrero),
+            self.temporales_disponibles[0].len(),
+            self.temporales_disponibles[1].len(),
+            self.temporales_disponibles[2].len(),
+            self.temporales_disponibles[3].len(),
+            self.temporales_disponibles[4].len(),
             self.tabla_constantes_entero.len(),
             self.tabla_constantes_flotante.len(),
             self.tabla_constantes_char.len(),
+            self.tabla_constantes_booleano.len(),
         )
     }
 
     /// Determina el tipo de dato a partir de una dirección virtual
-    pub fn obtener_tipo_desde_direccion(dir: usize) -> Result<TipoDato, String> {
-        match dir {
-            GLOBAL_ENTERO_INICIO..=GLOBAL_ENTERO_FIN => Ok(TipoDato::Entero),
-            GLOBAL_FLOTANTE_INICIO..=GLOBAL_FLOTANTE_FIN => Ok(TipoDato::Flotante),
-            GLOBAL_CHAR_INICIO..=GLOBAL_CHAR_FIN => Ok(TipoDato::Char),
-
-            LOCAL_ENTERO_INICIO..=LOCAL_ENTERO_FIN => Ok(TipoDato::Entero),
-            LOCAL_FLOTANTE_INICIO..=LOCAL_FLOTANTE_FIN => Ok(TipoDato::Flotante),
-            LOCAL_CHAR_INICIO..=LOCAL_CHAR_FIN => Ok(TipoDato::Char),
-
-            TEMPORAL_ENTERO_INICIO..=TEMPORAL_ENTERO_FIN => Ok(TipoDato::Entero),
-            TEMPORAL_FLOTANTE_INICIO..=TEMPORAL_FLOTANTE_FIN => Ok(TipoDato::Flotante),
-            TEMPORAL_CHAR_INICIO..=TEMPORAL_CHAR_FIN => Ok(TipoDato::Char),
-
-            CONSTANTE_ENTERO_INICIO..=CONSTANTE_ENTERO_FIN => Ok(TipoDato::Entero),
-            CONSTANTE_FLOTANTE_INICIO..=CONSTANTE_FLOTANTE_FIN => Ok(TipoDato::Flotante),
-            CONSTANTE_CHAR_INICIO..=CONSTANTE_CHAR_FIN => Ok(TipoDato::Char),
-
-            _ => Err(format!("Error: Dirección virtual {} fuera de rango", dir)),
-        }
+    pub fn obtener_tipo_desde_direccion(&self, dir: usize) -> Result<TipoDato, String> {
+        self.distribucion.tipo_de(dir)
     }
 
     /// Determina el segmento a partir de una dirección virtual
-    pub fn obtener_segmento_desde_direccion(dir: usize) -> Result<TipoSegmento, String> {
-        match dir {
-            GLOBAL_ENTERO_INICIO..=GLOBAL_CHAR_FIN => Ok(TipoSegmento::Global),
-            LOCAL_ENTERO_INICIO..=LOCAL_CHAR_FIN => Ok(TipoSegmento::Local),
-            TEMPORAL_ENTERO_INICIO..=TEMPORAL_CHAR_FIN => Ok(TipoSegmento::Temporal),
-            CONSTANTE_ENTERO_INICIO..=CONSTANTE_CHAR_FIN => Ok(TipoSegmento::Constante),
-            _ => Err(format!("Error: Dirección virtual {} fuera de rango", dir)),
-        }
+    pub fn obtener_segmento_desde_direccion(&self, dir: usize) -> Result<TipoSegmento, String> {
+        self.distribucion.segmento_de(dir)
     }
 }
 
@@ -457,6 +331,10 @@ mod tests {
 
         let dir3 = mem.asignar_variable(TipoDato::Flotante, TipoSegmento::Global).unwrap();
         assert_eq!(dir3, 3000);
+
+        // Booleanos y letreros tienen su propio rango
+        assert_eq!(mem.asignar_variable(TipoDato::Booleano, TipoSegmento::Global).unwrap(), 7000);
+        assert_eq!(mem.asignar_variable(TipoDato::Letrero, TipoSegmento::Global).unwrap(), 9000);
     }
 
     #[test]
@@ -469,6 +347,22 @@ mod tests {
         assert_eq!(dir1, dir2); // Debe reutilizar la misma dirección
     }
 
+    #[test]
+    fn test_asignar_bloque_contiguo() {
+        let mut mem = MemoriaVirtual::new();
+
+        let base = mem.asignar_bloque(TipoDato::Flotante, TipoSegmento::Global, 12).unwrap();
+        assert_eq!(base, 3000);
+
+        // La siguiente variable queda después del bloque
+        let dir = mem.asignar_variable(TipoDato::Flotante, TipoSegmento::Global).unwrap();
+        assert_eq!(dir, 3012);
+
+        // Un bloque que no cabe en el rango debe fallar
+        assert!(mem.asignar_bloque(TipoDato::Entero, TipoSegmento::Global, 5000).is_err());
+        assert!(mem.asignar_bloque(TipoDato::Entero, TipoSegmento::Temporal, 2).is_err());
+    }
+
     #[test]
     fn test_reiniciar_local() {
         let mut mem = MemoriaVirtual::new();
@@ -477,21 +371,40 @@ mod tests {
         mem.reiniciar_local();
 
         let dir = mem.asignar_variable(TipoDato::Entero, TipoSegmento::Local).unwrap();
-        assert_eq!(dir, LOCAL_ENTERO_INICIO);
+        assert_eq!(dir, 11000);
     }
 
     #[test]
     fn test_obtener_tipo_desde_direccion() {
-        assert_eq!(MemoriaVirtual::obtener_tipo_desde_direccion(1000).unwrap(), TipoDato::Entero);
-        assert_eq!(MemoriaVirtual::obtener_tipo_desde_direccion(3000).unwrap(), TipoDato::Flotante);
-        assert_eq!(MemoriaVirtual::obtener_tipo_desde_direccion(13500).unwrap(), TipoDato::Entero);
+        let mem = MemoriaVirtual::new();
+        assert_eq!(mem.obtener_tipo_desde_direccion(1000).unwrap(), TipoDato::Entero);
+        assert_eq!(mem.obtener_tipo_desde_direccion(3000).unwrap(), TipoDato::Flotante);
+        assert_eq!(mem.obtener_tipo_desde_direccion(21500).unwrap(), TipoDato::Entero);
+        assert_eq!(mem.obtener_tipo_desde_direccion(27000).unwrap(), TipoDato::Booleano);
+        assert_eq!(mem.obtener_tipo_desde_direccion(9000).unwrap(), TipoDato::Letrero);
     }
 
     #[test]
     fn test_obtener_segmento_desde_direccion() {
-        assert_eq!(MemoriaVirtual::obtener_segmento_desde_direccion(1500).unwrap(), TipoSegmento::Global);
-        assert_eq!(MemoriaVirtual::obtener_segmento_desde_direccion(7500).unwrap(), TipoSegmento::Local);
-        assert_eq!(MemoriaVirtual::obtener_segmento_desde_direccion(13500).unwrap(), TipoSegmento::Temporal);
-        assert_eq!(MemoriaVirtual::obtener_segmento_desde_direccion(19500).unwrap(), TipoSegmento::Constante);
+        let mem = MemoriaVirtual::new();
+        assert_eq!(mem.obtener_segmento_desde_direccion(1500).unwrap(), TipoSegmento::Global);
+        assert_eq!(mem.obtener_segmento_desde_direccion(11500).unwrap(), TipoSegmento::Local);
+        assert_eq!(mem.obtener_segmento_desde_direccion(21500).unwrap(), TipoSegmento::Temporal);
+        assert_eq!(mem.obtener_segmento_desde_direccion(31500).unwrap(), TipoSegmento::Constante);
+    }
+
+    #[test]
+    fn test_distribucion_configurable_amplia_globales() {
+        let distribucion = DistribucionMemoria::default().con_casillas(TipoSegmento::Global, 5000);
+        let mut mem = MemoriaVirtual::con_distribucion(distribucion);
+
+        // 3000 enteros globales no caben en la distribución por defecto
+        let base = mem.asignar_bloque(TipoDato::Entero, TipoSegmento::Global, 3000).unwrap();
+        assert_eq!(base, 1000);
+        assert_eq!(mem.asignar_variable(TipoDato::Flotante, TipoSegmento::Global).unwrap(), 6000);
+        assert_eq!(mem.asignar_variable(TipoDato::Entero, TipoSegmento::Local).unwrap(), 26000);
+        assert_eq!(mem.asignar_constante_entera(7).unwrap(), 46000);
+
+        assert!(MemoriaVirtual::new().asignar_bloque(TipoDato::Entero, TipoSegmento::Global, 3000).is_err());
     }
 }
@@ -3,11 +3,14 @@
 //! Este módulo contiene las estructuras y algoritmos para generar código intermedio (cuádruplos).
 
 pub mod cuadruplo;
+pub mod distribucion;
 pub mod generador;
 pub mod memoria_virtual;
+pub mod objeto;
 pub mod programa;
 
 pub use cuadruplo::{Cuadruplo, OperadorCuadruplo, Operando};
+pub use distribucion::DistribucionMemoria;
 pub use generador::GeneradorCuadruplos;
 pub use memoria_virtual::{MemoriaVirtual, TipoSegmento};
 pub use programa::{ProgramaObjeto, InfoFuncionPrograma};
@@ -0,0 +1,827 @@
+//! # Archivo Objeto
+//!
+//! Serializa un `ProgramaObjeto` a disco para poder ejecutarlo sin el código fuente.
+//!
+//! Hay dos formatos con el mismo contenido (cuádruplos, funciones, constantes y strings):
+//! - **Texto** (`.obj`): legible, una sección por tabla
+//! - **Binario** (`.objb`): compacto, enteros little-endian y strings con longitud
+//!
+//! Ambos inician con una cabecera con la versión del formato y terminan con un
+//! checksum CRC-32 de todo lo anterior, de modo que un archivo truncado, editado
+//! o de otra versión se rechaza al cargarlo.
+//!
+//! ## Formato de texto
+//! ```text
+//! OBJ compilador_rust v11
+//! programa fibonacci
+//! memoria 1000 2000 2000 2000 2000
+//! cuadruplos 2
+//! 0 goto - - L1
+//! 1 escribe - - @31000
+//! funciones 1
+//! fib 2 1 Entero 1
+//! constantes 1
+//! 31000 entero 5
+//! strings 1
+//! "hola\n"
+//! checksum 8a820b68
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+use crate::intermedio::cuadruplo::{Cuadruplo, OperadorCuadruplo, Operando};
+use crate::intermedio::distribucion::DistribucionMemoria;
+use crate::intermedio::programa::{InfoFuncionPrograma, ProgramaObjeto};
+use crate::vm::memoria::Valor;
+
+/// Versión actual del formato de archivo objeto (texto y binario)
+///
+/// - v1: formato inicial
+/// - v2: `param` guarda la dirección del parámetro formal en lugar de su número
+/// - v3: distribución de memoria después del nombre del programa
+/// - v4: constantes `char` (texto: código del carácter; binario: etiqueta 3)
+/// - v5: la tabla de strings guarda el contenido de los letreros sin comillas
+///   y con los escapes resueltos (el texto lo vuelve a poner entre comillas)
+/// - v6: constantes `booleano` (texto: `verdadero` / `falso`; binario: etiqueta 4 y un byte)
+/// - v7: operadores `%` y `neg` (signo negativo de cualquier factor)
+/// - v8: operadores relacionales `>=` y `<=`
+/// - v9: operador `verpaso` (paso 0 en un ciclo `para`)
+/// - v10: booleanos y letreros con rango propio en cada segmento (cinco rangos
+///   por segmento en lugar de tres)
+/// - v11: las constantes `letrero` guardan su contenido en lugar de un índice
+///   (texto: entre comillas con escapes; binario: string con longitud)
+pub const VERSION_FORMATO: u16 = 11;
+
+/// Cabecera del formato de texto (seguida de ` v<versión>`)
+const CABECERA_TEXTO: &str = "OBJ compilador_rust";
+
+/// Número mágico del formato binario
+const MAGIA_BINARIO: &[u8; 4] = b"COBJ";
+
+/// Extensión por defecto del formato binario (el de texto usa `.obj`)
+pub const EXTENSION_BINARIO: &str = "objb";
+
+impl ProgramaObjeto {
+    // ==================== FORMATO DE TEXTO ====================
+
+    /// Serializa el programa al formato de texto `.obj`
+    pub fn a_texto(&self) -> String {
+        let mut texto = format!("{} v{}\n", CABECERA_TEXTO, VERSION_FORMATO);
+        texto.push_str(&format!("programa {}\n", self.nombre_programa));
+        texto.push_str(&format!("memoria {}", self.distribucion.base));
+        for casillas in self.distribucion.casillas {
+            texto.push_str(&format!(" {}", casillas));
+        }
+        texto.push('\n');
+
+        texto.push_str(&format!("cuadruplos {}\n", self.cuadruplos.len()));
+        for (i, cuadruplo) in self.cuadruplos.iter().enumerate() {
+            texto.push_str(&format!(
+                "{} {} {} {} {}\n",
+                i,
+                cuadruplo.operador,
+                operando_a_texto(&cuadruplo.operando_izq),
+                operando_a_texto(&cuadruplo.operando_der),
+                operando_a_texto(&cuadruplo.resultado),
+            ));
+        }
+
+        // Los HashMap se escriben ordenados para que el archivo sea determinista
+        let mut funciones: Vec<_> = self.mapa_funciones.values().collect();
+        funciones.sort_by(|a, b| a.nombre.cmp(&b.nombre));
+        texto.push_str(&format!("funciones {}\n", funciones.len()));
+        for info in funciones {
+            texto.push_str(&format!(
+                "{} {} {} {} {}\n",
+                info.nombre,
+                info.direccion_inicio,
+                info.tiene_retorno as u8,
+                info.tipo_retorno.as_deref().unwrap_or("-"),
+                info.num_parametros,
+            ));
+        }
+
+        let mut constantes: Vec<_> = self.mapa_constantes.iter().collect();
+        constantes.sort_by_key(|(direccion, _)| **direccion);
+        texto.push_str(&format!("constantes {}\n", constantes.len()));
+        for (direccion, valor) in constantes {
+            let (tipo, valor) = match valor {
+                Valor::Entero(v) => ("entero", v.to_string()),
+                Valor::Flotante(v) => ("flotante", format!("{:?}", v)),
+                Valor::Char(c) => ("char", (*c as u32).to_string()),
+                Valor::Letrero(s) => ("letrero", format!("\"{}\"", escapar(s))),
+                Valor::Booleano(b) => ("booleano", if *b { "verdadero" } else { "falso" }.to_string()),
+            };
+            texto.push_str(&format!("{} {} {}\n", direccion, tipo, valor));
+        }
+
+        texto.push_str(&format!("strings {}\n", self.tabla_strings.len()));
+        for s in &self.tabla_strings {
+            texto.push_str(&format!("\"{}\"", escapar(s)));
+            texto.push('\n');
+        }
+
+        let checksum = crc32(texto.as_bytes());
+        texto.push_str(&format!("checksum {:08x}\n", checksum));
+        texto
+    }
+
+    /// Reconstruye un programa desde el formato de texto `.obj`
+    pub fn desde_texto(texto: &str) -> Result<Self, String> {
+        // Verificar el checksum antes de interpretar cualquier sección
+        let inicio_checksum = texto.rfind("checksum ")
+            .ok_or("Error: Archivo objeto sin checksum (¿truncado?)")?;
+        let (contenido, linea_checksum) = texto.split_at(inicio_checksum);
+        let esperado = u32::from_str_radix(linea_checksum["checksum ".len()..].trim(), 16)
+            .map_err(|_| "Error: Checksum inválido en el archivo objeto".to_string())?;
+        let calculado = crc32(contenido.as_bytes());
+        if esperado != calculado {
+            return Err(format!(
+                "Error: Checksum incorrecto en el archivo objeto (esperado {:08x}, calculado {:08x})",
+                esperado, calculado
+            ));
+        }
+
+        let mut lineas = LectorLineas::new(contenido);
+
+        let cabecera = lineas.siguiente()?;
+        let version = cabecera.strip_prefix(CABECERA_TEXTO)
+            .and_then(|resto| resto.trim().strip_prefix('v'))
+            .ok_or_else(|| format!("Error: Cabecera de archivo objeto inválida: '{}'", cabecera))?;
+        validar_version(version.parse().map_err(|_| format!("Error: Versión inválida '{}'", version))?)?;
+
+        let nombre_programa = lineas.siguiente()?
+            .strip_prefix("programa ")
+            .ok_or("Error: Falta la línea 'programa' en el archivo objeto")?
+            .to_string();
+
+        let linea = lineas.siguiente()?;
+        let campos: Vec<&str> = linea.strip_prefix("memoria ")
+            .ok_or("Error: Falta la línea 'memoria' en el archivo objeto")?
+            .split_whitespace()
+            .collect();
+        if campos.len() != 5 {
+            return Err(format!("Error: Distribución de memoria mal formada: '{}'", linea));
+        }
+        let mut distribucion = DistribucionMemoria { base: parsear(campos[0], linea)?, casillas: [0; 4] };
+        for (casillas, campo) in distribucion.casillas.iter_mut().zip(&campos[1..]) {
+            *casillas = parsear(campo, linea)?;
+        }
+        distribucion.validar()?;
+
+        // Las cantidades vienen del archivo: la capacidad no pasa de las líneas que quedan
+        let num_cuadruplos = lineas.seccion("cuadruplos")?;
+        let mut cuadruplos = Vec::with_capacity(num_cuadruplos.min(lineas.restantes()));
+        for i in 0..num_cuadruplos {
+            let linea = lineas.siguiente()?;
+            let campos: Vec<&str> = linea.split_whitespace().collect();
+            if campos.len() != 5 || campos[0] != i.to_string() {
+                return Err(format!("Error: Cuádruplo {} mal formado: '{}'", i, linea));
+            }
+            let operador = OperadorCuadruplo::from_str(campos[1])
+                .ok_or_else(|| format!("Error: Operador desconocido '{}' en el cuádruplo {}", campos[1], i))?;
+            cuadruplos.push(Cuadruplo::new(
+                operador,
+                operando_desde_texto(campos[2])?,
+                operando_desde_texto(campos[3])?,
+                operando_desde_texto(campos[4])?,
+            ));
+        }
+
+        let num_funciones = lineas.seccion("funciones")?;
+        let mut mapa_funciones = HashMap::new();
+        for _ in 0..num_funciones {
+            let linea = lineas.siguiente()?;
+            let campos: Vec<&str> = linea.split_whitespace().collect();
+            if campos.len() != 5 {
+                return Err(format!("Error: Función mal formada: '{}'", linea));
+            }
+            let info = InfoFuncionPrograma {
+                nombre: campos[0].to_string(),
+                direccion_inicio: parsear(campos[1], linea)?,
+                tiene_retorno: campos[2] == "1",
+                tipo_retorno: (campos[3] != "-").then(|| campos[3].to_string()),
+                num_parametros: parsear(campos[4], linea)?,
+            };
+            mapa_funciones.insert(info.nombre.clone(), info);
+        }
+
+        let num_constantes = lineas.seccion("constantes")?;
+        let mut mapa_constantes = HashMap::new();
+        for _ in 0..num_constantes {
+            let linea = lineas.siguiente()?;
+            // El valor de un letrero puede contener espacios: va al final de la línea
+            let campos: Vec<&str> = linea.splitn(3, ' ').collect();
+            if campos.len() != 3 {
+                return Err(format!("Error: Constante mal formada: '{}'", linea));
+            }
+            let valor = match campos[1] {
+                "entero" => Valor::Entero(parsear(campos[2], linea)?),
+                "flotante" => Valor::Flotante(parsear(campos[2], linea)?),
+                "char" => Valor::Char(char::from_u32(parsear(campos[2], linea)?)
+                    .ok_or_else(|| format!("Error: Código de char inválido en '{}'", linea))?),
+                "letrero" => {
+                    let contenido = campos[2].strip_prefix('"').and_then(|l| l.strip_suffix('"'))
+                        .ok_or_else(|| format!("Error: Letrero sin comillas en la línea '{}'", linea))?;
+                    Valor::Letrero(desescapar(contenido)?.into())
+                }
+                "booleano" => match campos[2] {
+                    "verdadero" => Valor::Booleano(true),
+                    "falso" => Valor::Booleano(false),
+                    _ => return Err(format!("Error: Valor booleano inválido en '{}'", linea)),
+                },
+                otro => return Err(format!("Error: Tipo de constante desconocido '{}'", otro)),
+            };
+            mapa_constantes.insert(parsear(campos[0], linea)?, valor);
+        }
+
+        let num_strings = lineas.seccion("strings")?;
+        let mut tabla_strings = Vec::with_capacity(num_strings.min(lineas.restantes()));
+        for _ in 0..num_strings {
+            let linea = lineas.siguiente()?;
+            let contenido = linea.strip_prefix('"').and_then(|l| l.strip_suffix('"'))
+                .ok_or_else(|| format!("Error: String sin comillas en la línea '{}'", linea))?;
+            tabla_strings.push(desescapar(contenido)?);
+        }
+
+        Ok(ProgramaObjeto::crear(
+            nombre_programa,
+            cuadruplos,
+            mapa_funciones,
+            mapa_constantes,
+            tabla_strings,
+        ).con_distribucion(distribucion))
+    }
+
+    // ==================== FORMATO BINARIO ====================
+
+    /// Serializa el programa al formato binario compacto
+    ///
+    /// Falla si una dirección, cantidad o longitud no cabe en los 32 bits del formato
+    pub fn a_binario(&self) -> Result<Vec<u8>, String> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIA_BINARIO);
+        bytes.extend_from_slice(&VERSION_FORMATO.to_le_bytes());
+        escribir_string(&mut bytes, &self.nombre_programa)?;
+        escribir_u32(&mut bytes, self.distribucion.base)?;
+        for casillas in self.distribucion.casillas {
+            escribir_u32(&mut bytes, casillas)?;
+        }
+
+        escribir_u32(&mut bytes, self.cuadruplos.len())?;
+        for cuadruplo in &self.cuadruplos {
+            escribir_string(&mut bytes, &cuadruplo.operador.to_string())?;
+            escribir_operando(&mut bytes, &cuadruplo.operando_izq)?;
+            escribir_operando(&mut bytes, &cuadruplo.operando_der)?;
+            escribir_operando(&mut bytes, &cuadruplo.resultado)?;
+        }
+
+        let mut funciones: Vec<_> = self.mapa_funciones.values().collect();
+        funciones.sort_by(|a, b| a.nombre.cmp(&b.nombre));
+        escribir_u32(&mut bytes, funciones.len())?;
+        for info in funciones {
+            escribir_string(&mut bytes, &info.nombre)?;
+            escribir_u32(&mut bytes, info.direccion_inicio)?;
+            bytes.push(info.tiene_retorno as u8);
+            escribir_string(&mut bytes, info.tipo_retorno.as_deref().unwrap_or(""))?;
+            escribir_u32(&mut bytes, info.num_parametros)?;
+        }
+
+        let mut constantes: Vec<_> = self.mapa_constantes.iter().collect();
+        constantes.sort_by_key(|(direccion, _)| **direccion);
+        escribir_u32(&mut bytes, constantes.len())?;
+        for (direccion, valor) in constantes {
+            escribir_u32(&mut bytes, *direccion)?;
+            match valor {
+                Valor::Entero(v) => {
+                    bytes.push(0);
+                    bytes.extend_from_slice(&v.to_le_bytes());
+                }
+                Valor::Flotante(v) => {
+                    bytes.push(1);
+                    bytes.extend_from_slice(&v.to_le_bytes());
+                }
+                Valor::Letrero(s) => {
+                    bytes.push(2);
+                    escribir_string(&mut bytes, s)?;
+                }
+                Valor::Char(c) => {
+                    bytes.push(3);
+                    escribir_u32(&mut bytes, *c as usize)?;
+                }
+                Valor::Booleano(b) => {
+                    bytes.push(4);
+                    bytes.push(*b as u8);
+                }
+            }
+        }
+
+        escribir_u32(&mut bytes, self.tabla_strings.len())?;
+        for s in &self.tabla_strings {
+            escribir_string(&mut bytes, s)?;
+        }
+
+        let checksum = crc32(&bytes);
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+        Ok(bytes)
+    }
+
+    /// Reconstruye un programa desde el formato binario
+    pub fn desde_binario(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < MAGIA_BINARIO.len() + 2 + 4 || !bytes.starts_with(MAGIA_BINARIO) {
+            return Err("Error: El archivo no es un objeto binario válido".to_string());
+        }
+
+        let (contenido, checksum) = bytes.split_at(bytes.len() - 4);
+        let esperado = u32::from_le_bytes(checksum.try_into().unwrap());
+        let calculado = crc32(contenido);
+        if esperado != calculado {
+            return Err(format!(
+                "Error: Checksum incorrecto en el archivo objeto (esperado {:08x}, calculado {:08x})",
+                esperado, calculado
+            ));
+        }
+
+        let mut lector = LectorBytes { bytes: contenido, pos: MAGIA_BINARIO.len() };
+        validar_version(u16::from_le_bytes(lector.tomar::<2>()?))?;
+        let nombre_programa = lector.string()?;
+        let mut distribucion = DistribucionMemoria { base: lector.u32()?, casillas: [0; 4] };
+        for casillas in &mut distribucion.casillas {
+            *casillas = lector.u32()?;
+        }
+        distribucion.validar()?;
+
+        // Las cantidades vienen del archivo: la capacidad no pasa de lo que cabe en
+        // los bytes que quedan con el tamaño mínimo de cada elemento
+        let num_cuadruplos = lector.u32()?;
+        let mut cuadruplos = Vec::with_capacity(num_cuadruplos.min(lector.restantes() / MIN_BYTES_CUADRUPLO));
+        for i in 0..num_cuadruplos {
+            let simbolo = lector.string()?;
+            let operador = OperadorCuadruplo::from_str(&simbolo)
+                .ok_or_else(|| format!("Error: Operador desconocido '{}' en el cuádruplo {}", simbolo, i))?;
+            let izq = lector.operando()?;
+            let der = lector.operando()?;
+            let resultado = lector.operando()?;
+            cuadruplos.push(Cuadruplo::new(operador, izq, der, resultado));
+        }
+
+        let num_funciones = lector.u32()?;
+        let mut mapa_funciones = HashMap::new();
+        for _ in 0..num_funciones {
+            let nombre = lector.string()?;
+            let direccion_inicio = lector.u32()?;
+            let tiene_retorno = lector.tomar::<1>()?[0] != 0;
+            let tipo_retorno = Some(lector.string()?).filter(|t| !t.is_empty());
+            let num_parametros = lector.u32()?;
+            mapa_funciones.insert(nombre.clone(), InfoFuncionPrograma {
+                nombre,
+                direccion_inicio,
+                tiene_retorno,
+                tipo_retorno,
+                num_parametros,
+            });
+        }
+
+        let num_constantes = lector.u32()?;
+        let mut mapa_constantes = HashMap::new();
+        for _ in 0..num_constantes {
+            let direccion = lector.u32()?;
+            let valor = match lector.tomar::<1>()?[0] {
+                0 => Valor::Entero(i32::from_le_bytes(lector.tomar::<4>()?)),
+                1 => Valor::Flotante(f64::from_le_bytes(lector.tomar::<8>()?)),
+                2 => Valor::Letrero(lector.string()?.into()),
+                3 => {
+                    let codigo = lector.u32()?;
+                    Valor::Char(char::from_u32(codigo as u32)
+                        .ok_or_else(|| format!("Error: Código de char inválido ({})", codigo))?)
+                }
+                4 => match lector.tomar::<1>()?[0] {
+                    0 => Valor::Booleano(false),
+                    1 => Valor::Booleano(true),
+                    otro => return Err(format!("Error: Valor booleano inválido ({})", otro)),
+                },
+                otro => return Err(format!("Error: Tipo de constante desconocido ({})", otro)),
+            };
+            mapa_constantes.insert(direccion, valor);
+        }
+
+        let num_strings = lector.u32()?;
+        let mut tabla_strings = Vec::with_capacity(num_strings.min(lector.restantes() / MIN_BYTES_STRING));
+        for _ in 0..num_strings {
+            tabla_strings.push(lector.string()?);
+        }
+
+        if lector.pos != contenido.len() {
+            return Err("Error: Datos sobrantes al final del archivo objeto".to_string());
+        }
+
+        Ok(ProgramaObjeto::crear(
+            nombre_programa,
+            cuadruplos,
+            mapa_funciones,
+            mapa_constantes,
+            tabla_strings,
+        ).con_distribucion(distribucion))
+    }
+
+    // ==================== ARCHIVOS ====================
+
+    /// Guarda el programa en disco: binario si la extensión es `.objb`, texto en otro caso
+    pub fn guardar(&self, ruta: &Path) -> Result<(), String> {
+        let resultado = if ruta.extension().is_some_and(|ext| ext == EXTENSION_BINARIO) {
+            std::fs::write(ruta, self.a_binario()?)
+        } else {
+            std::fs::write(ruta, self.a_texto())
+        };
+        resultado.map_err(|e| format!("Error al escribir '{}': {}", ruta.display(), e))
+    }
+
+    /// Carga un programa desde disco, detectando el formato por su cabecera
+    pub fn cargar(ruta: &Path) -> Result<Self, String> {
+        let bytes = std::fs::read(ruta)
+            .map_err(|e| format!("Error al leer '{}': {}", ruta.display(), e))?;
+        Self::desde_bytes(&bytes)
+    }
+
+    /// Reconstruye un programa desde el contenido de un archivo objeto (texto o binario)
+    pub fn desde_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.starts_with(MAGIA_BINARIO) {
+            return Self::desde_binario(bytes);
+        }
+
+        let texto = std::str::from_utf8(bytes)
+            .map_err(|_| "Error: El contenido no es un archivo objeto válido".to_string())?;
+        Self::desde_texto(texto)
+    }
+}
+
+/// Indica si un contenido es un archivo objeto (por su cabecera) y no código fuente
+pub fn es_archivo_objeto(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIA_BINARIO) || bytes.starts_with(CABECERA_TEXTO.as_bytes())
+}
+
+// ==================== UTILIDADES ====================
+
+/// Rechaza archivos generados con otra versión del formato
+fn validar_version(version: u16) -> Result<(), String> {
+    if version != VERSION_FORMATO {
+        return Err(format!(
+            "Error: Versión de archivo objeto no soportada: v{} (se esperaba v{})",
+            version, VERSION_FORMATO
+        ));
+    }
+    Ok(())
+}
+
+/// Representación textual de un operando; a diferencia de `Display` distingue
+/// flotantes enteros (`2.0`) de constantes enteras y marca las variables con `$`
+fn operando_a_texto(operando: &Operando) -> String {
+    match operando {
+        Operando::ConstanteFlotante(valor) => format!("{:?}", valor),
+        Operando::Variable(nombre) => format!("${}", nombre),
+        otro => otro.to_string(),
+    }
+}
+
+/// Inverso de `operando_a_texto`
+fn operando_desde_texto(texto: &str) -> Result<Operando, String> {
+    let numero = |s: &str| s.parse::<usize>()
+        .map_err(|_| format!("Error: Operando inválido '{}'", texto));
+
+    if texto == "-" {
+        Ok(Operando::Vacio)
+    } else if texto == "?" {
+        Ok(Operando::Pendiente)
+    } else if let Some(dir) = texto.strip_prefix("(@").and_then(|s| s.strip_suffix(')')) {
+        Ok(Operando::Apuntador(numero(dir)?))
+    } else if let Some(dir) = texto.strip_prefix('@') {
+        Ok(Operando::Direccion(numero(dir)?))
+    } else if let Some(idx) = texto.strip_prefix("STR[").and_then(|s| s.strip_suffix(']')) {
+        Ok(Operando::Letrero(numero(idx)?))
+    } else if let Some(dir) = texto.strip_prefix('L') {
+        Ok(Operando::Etiqueta(numero(dir)?))
+    } else if let Some(num) = texto.strip_prefix('t') {
+        Ok(Operando::Temporal(numero(num)?))
+    } else if let Some(nombre) = texto.strip_prefix('$') {
+        Ok(Operando::Variable(nombre.to_string()))
+    } else if let Ok(valor) = texto.parse::<i32>() {
+        Ok(Operando::ConstanteEntera(valor))
+    } else if let Ok(valor) = texto.parse::<f64>() {
+        Ok(Operando::ConstanteFlotante(valor))
+    } else {
+        Err(format!("Error: Operando inválido '{}'", texto))
+    }
+}
+
+/// Parsea un campo numérico de una línea del archivo objeto
+fn parsear<T: std::str::FromStr>(campo: &str, linea: &str) -> Result<T, String> {
+    campo.parse().map_err(|_| format!("Error: Valor inválido '{}' en la línea '{}'", campo, linea))
+}
+
+/// Escapa saltos de línea y diagonales para guardar un string en una sola línea
+fn escapar(s: &str) -> String {
+    let mut resultado = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => resultado.push_str("\\\\"),
+            '\n' => resultado.push_str("\\n"),
+            '\r' => resultado.push_str("\\r"),
+            '\t' => resultado.push_str("\\t"),
+            '\0' => resultado.push_str("\\0"),
+            otro => resultado.push(otro),
+        }
+    }
+    resultado
+}
+
+/// Inverso de `escapar`
+fn desescapar(s: &str) -> Result<String, String> {
+    let mut resultado = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            resultado.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => resultado.push('\\'),
+            Some('n') => resultado.push('\n'),
+            Some('r') => resultado.push('\r'),
+            Some('t') => resultado.push('\t'),
+            Some('0') => resultado.push('\0'),
+            otro => return Err(format!("Error: Escape inválido '\\{}' en la tabla de strings", otro.unwrap_or(' '))),
+        }
+    }
+    Ok(resultado)
+}
+
+/// CRC-32 (IEEE 802.3, polinomio reflejado 0xEDB88320)
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mascara = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mascara);
+        }
+    }
+    !crc
+}
+
+/// Recorre las líneas del formato de texto
+struct LectorLineas<'a> {
+    lineas: std::str::Lines<'a>,
+}
+
+impl<'a> LectorLineas<'a> {
+    fn new(texto: &'a str) -> Self {
+        LectorLineas { lineas: texto.lines() }
+    }
+
+    fn siguiente(&mut self) -> Result<&'a str, String> {
+        self.lineas.next().ok_or_else(|| "Error: Archivo objeto incompleto".to_string())
+    }
+
+    /// Número de líneas que faltan por leer
+    fn restantes(&self) -> usize {
+        self.lineas.clone().count()
+    }
+
+    /// Lee el encabezado `<nombre> <cantidad>` de una sección
+    fn seccion(&mut self, nombre: &str) -> Result<usize, String> {
+        let linea = self.siguiente()?;
+        linea.strip_prefix(nombre)
+            .and_then(|resto| resto.trim().parse().ok())
+            .ok_or_else(|| format!("Error: Se esperaba la sección '{}', se encontró '{}'", nombre, linea))
+    }
+}
+
+fn escribir_u32(bytes: &mut Vec<u8>, valor: usize) -> Result<(), String> {
+    let valor = u32::try_from(valor)
+        .map_err(|_| format!("Error: El valor {} no cabe en los 32 bits del formato binario", valor))?;
+    bytes.extend_from_slice(&valor.to_le_bytes());
+    Ok(())
+}
+
+fn escribir_string(bytes: &mut Vec<u8>, s: &str) -> Result<(), String> {
+    escribir_u32(bytes, s.len())?;
+    bytes.extend_from_slice(s.as_bytes());
+    Ok(())
+}
+
+/// Un byte de etiqueta seguido del contenido del operando
+fn escribir_operando(bytes: &mut Vec<u8>, operando: &Operando) -> Result<(), String> {
+    match operando {
+        Operando::Vacio => bytes.push(0),
+        Operando::Direccion(dir) => {
+            bytes.push(1);
+            escribir_u32(bytes, *dir)?;
+        }
+        Operando::Etiqueta(dir) => {
+            bytes.push(2);
+            escribir_u32(bytes, *dir)?;
+        }
+        Operando::Letrero(idx) => {
+            bytes.push(3);
+            escribir_u32(bytes, *idx)?;
+        }
+        Operando::Apuntador(dir) => {
+            bytes.push(4);
+            escribir_u32(bytes, *dir)?;
+        }
+        Operando::ConstanteEntera(valor) => {
+            bytes.push(5);
+            bytes.extend_from_slice(&valor.to_le_bytes());
+        }
+        Operando::ConstanteFlotante(valor) => {
+            bytes.push(6);
+            bytes.extend_from_slice(&valor.to_le_bytes());
+        }
+        Operando::Temporal(num) => {
+            bytes.push(7);
+            escribir_u32(bytes, *num)?;
+        }
+        Operando::Variable(nombre) => {
+            bytes.push(8);
+            escribir_string(bytes, nombre)?;
+        }
+        Operando::Pendiente => bytes.push(9),
+    }
+    Ok(())
+}
+
+/// Cursor sobre el contenido del formato binario
+struct LectorBytes<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+/// Bytes que ocupa como mínimo un string (su longitud) y un cuádruplo (el
+/// símbolo del operador vacío y tres operandos de un byte) en el formato binario
+const MIN_BYTES_STRING: usize = 4;
+const MIN_BYTES_CUADRUPLO: usize = MIN_BYTES_STRING + 3;
+
+impl LectorBytes<'_> {
+    /// Bytes que faltan por leer
+    fn restantes(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    fn tomar<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let fin = self.pos + N;
+        let trozo = self.bytes.get(self.pos..fin)
+            .ok_or("Error: Archivo objeto incompleto")?;
+        self.pos = fin;
+        Ok(trozo.try_into().unwrap())
+    }
+
+    fn u32(&mut self) -> Result<usize, String> {
+        Ok(u32::from_le_bytes(self.tomar::<4>()?) as usize)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let longitud = self.u32()?;
+        let fin = self.pos + longitud;
+        let trozo = self.bytes.get(self.pos..fin)
+            .ok_or("Error: Archivo objeto incompleto")?;
+        self.pos = fin;
+        String::from_utf8(trozo.to_vec()).map_err(|_| "Error: String inválido en el archivo objeto".to_string())
+    }
+
+    fn operando(&mut self) -> Result<Operando, String> {
+        Ok(match self.tomar::<1>()?[0] {
+            0 => Operando::Vacio,
+            1 => Operando::Direccion(self.u32()?),
+            2 => Operando::Etiqueta(self.u32()?),
+            3 => Operando::Letrero(self.u32()?),
+            4 => Operando::Apuntador(self.u32()?),
+            5 => Operando::ConstanteEntera(i32::from_le_bytes(self.tomar::<4>()?)),
+            6 => Operando::ConstanteFlotante(f64::from_le_bytes(self.tomar::<8>()?)),
+            7 => Operando::Temporal(self.u32()?),
+            8 => Operando::Variable(self.string()?),
+            9 => Operando::Pendiente,
+            otro => return Err(format!("Error: Tipo de operando desconocido ({})", otro)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexico, sintactico};
+
+    fn compilar(fuente: &str) -> ProgramaObjeto {
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+        let generador = sintactico::analyze(&tokens, 0).ok().unwrap();
+        generador.exportar_programa("prueba".to_string()).unwrap()
+    }
+
+    const FUENTE: &str = "programa p; vars x : entero; vars f : flotante; vars c : char;\n\
+                          entero doble(n : entero) { regresa n * 2; };\n\
+                          inicio { x = doble(21); f = 2.0; c = '\\n'; escribe(\"x =\\tvale\"); escribe(x); } fin";
+
+    fn mismo_programa(a: &ProgramaObjeto, b: &ProgramaObjeto) {
+        assert_eq!(a.nombre_programa, b.nombre_programa);
+        assert_eq!(a.cuadruplos.len(), b.cuadruplos.len());
+        for (ca, cb) in a.cuadruplos.iter().zip(&b.cuadruplos) {
+            assert_eq!(ca.to_string(), cb.to_string());
+        }
+        assert_eq!(a.mapa_constantes, b.mapa_constantes);
+        assert_eq!(a.tabla_strings, b.tabla_strings);
+        assert_eq!(a.mapa_funciones.len(), b.mapa_funciones.len());
+        let info = &b.mapa_funciones["doble"];
+        assert_eq!(info.direccion_inicio, a.mapa_funciones["doble"].direccion_inicio);
+        assert_eq!(info.tipo_retorno.as_deref(), Some("Entero"));
+        assert_eq!(info.num_parametros, 1);
+    }
+
+    #[test]
+    fn test_ida_y_vuelta_texto() {
+        let programa = compilar(FUENTE);
+        let texto = programa.a_texto();
+
+        assert!(texto.starts_with("OBJ compilador_rust v11\n"));
+        mismo_programa(&programa, &ProgramaObjeto::desde_texto(&texto).unwrap());
+    }
+
+    #[test]
+    fn test_ida_y_vuelta_constante_letrero() {
+        let mut programa = compilar(FUENTE);
+        programa.mapa_constantes.insert(39000, Valor::Letrero("dos palabras \"y\"\n".into()));
+
+        mismo_programa(&programa, &ProgramaObjeto::desde_texto(&programa.a_texto()).unwrap());
+        mismo_programa(&programa, &ProgramaObjeto::desde_binario(&programa.a_binario().unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_ida_y_vuelta_binario() {
+        let programa = compilar(FUENTE);
+        let bytes = programa.a_binario().unwrap();
+
+        assert!(bytes.starts_with(b"COBJ"));
+        assert!(bytes.len() < programa.a_texto().len());
+        mismo_programa(&programa, &ProgramaObjeto::desde_binario(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_binario_rechaza_valores_de_mas_de_32_bits() {
+        use crate::intermedio::distribucion::TipoSegmento;
+
+        let distribucion = DistribucionMemoria::default().con_casillas(TipoSegmento::Global, 5_000_000_000);
+        let programa = compilar(FUENTE).con_distribucion(distribucion);
+
+        // Sin truncar a 705032704: el formato de texto sí guarda la distribución completa
+        assert!(programa.a_binario().unwrap_err().contains("5000000000 no cabe en los 32 bits"));
+        let cargado = ProgramaObjeto::desde_texto(&programa.a_texto()).unwrap();
+        assert_eq!(cargado.distribucion, distribucion);
+    }
+
+    #[test]
+    fn test_cantidades_enormes_no_reservan_memoria() {
+        let programa = compilar(FUENTE);
+
+        // Texto: la sección declara más cuádruplos de los que hay
+        let texto = programa.a_texto();
+        let contenido = &texto[..texto.rfind("checksum ").unwrap()];
+        let declarada = format!("cuadruplos {}\n", programa.cuadruplos.len());
+        let contenido = contenido.replacen(&declarada, "cuadruplos 4000000000000000000\n", 1);
+        let alterado = format!("{}checksum {:08x}\n", contenido, crc32(contenido.as_bytes()));
+        assert!(ProgramaObjeto::desde_texto(&alterado).is_err());
+
+        // Binario: el conteo de cuádruplos va después de la versión, el nombre y la memoria
+        let bytes = programa.a_binario().unwrap();
+        let pos = MAGIA_BINARIO.len() + 2 + 4 + programa.nombre_programa.len() + 5 * 4;
+        let mut contenido = bytes[..bytes.len() - 4].to_vec();
+        contenido[pos..pos + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        let checksum = crc32(&contenido);
+        contenido.extend_from_slice(&checksum.to_le_bytes());
+        assert!(ProgramaObjeto::desde_binario(&contenido).is_err());
+    }
+
+    #[test]
+    fn test_rechaza_archivo_alterado() {
+        let programa = compilar(FUENTE);
+
+        let texto = programa.a_texto().replacen("doble", "triple", 1);
+        assert!(ProgramaObjeto::desde_texto(&texto).unwrap_err().contains("Checksum"));
+
+        let mut bytes = programa.a_binario().unwrap();
+        bytes[10] ^= 0xFF;
+        assert!(ProgramaObjeto::desde_binario(&bytes).unwrap_err().contains("Checksum"));
+        assert!(ProgramaObjeto::desde_binario(&bytes[..8]).is_err());
+    }
+
+    #[test]
+    fn test_rechaza_otra_version() {
+        let texto = "OBJ compilador_rust v99\nprograma p\n";
+        let texto = format!("{}checksum {:08x}\n", texto, crc32(texto.as_bytes()));
+
+        let error = ProgramaObjeto::desde_texto(&texto).unwrap_err();
+        assert!(error.contains("v99"));
+    }
+
+    #[test]
+    fn test_crc32_conocido() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+}
@@ -5,6 +5,7 @@
 
 use std::collections::HashMap;
 use crate::intermedio::cuadruplo::Cuadruplo;
+use crate::intermedio::distribucion::DistribucionMemoria;
 use crate::vm::memoria::Valor;
 
 /// Información de una función en el programa compilado
@@ -43,6 +44,9 @@ pub struct ProgramaObjeto {
 
     /// Tabla de strings literales
     pub tabla_strings: Vec<String>,
+
+    /// Distribución de memoria con la que se asignaron las direcciones
+    pub distribucion: DistribucionMemoria,
 }
 
 impl ProgramaObjeto {
@@ -54,6 +58,7 @@ impl ProgramaObjeto {
             mapa_constantes: HashMap::new(),
             nombre_programa,
             tabla_strings: Vec::new(),
+            distribucion: DistribucionMemoria::default(),
         }
     }
 
@@ -71,9 +76,16 @@ impl ProgramaObjeto {
             mapa_constantes,
             nombre_programa,
             tabla_strings,
+            distribucion: DistribucionMemoria::default(),
         }
     }
 
+    /// Indica la distribución de memoria con la que se compiló el programa
+    pub fn con_distribucion(mut self, distribucion: DistribucionMemoria) -> Self {
+        self.distribucion = distribucion;
+        self
+    }
+
     /// Obtiene el número total de cuádruplos
     pub fn num_cuadruplos(&self) -> usize {
         self.cuadruplos.len()
@@ -1,6 +1,7 @@
 pub mod token;
 
 use crate::lexico::token::{Token, TipoToken};
+use crate::diagnostico::{codigos, Diagnostico, Span};
 use regex::Regex;
 use lazy_static::lazy_static;
 
@@ -29,19 +30,35 @@ lazy_static! {
         ReglaToken { tipo: TipoToken::Entero, patron: Regex::new(r"^\bentero\b").unwrap() },
         ReglaToken { tipo: TipoToken::Flotante, patron: Regex::new(r"^\bflotante\b").unwrap() },
         ReglaToken { tipo: TipoToken::LetreroTipo, patron: Regex::new(r"^\bletrero\b").unwrap() },
+        ReglaToken { tipo: TipoToken::Char, patron: Regex::new(r"^\bchar\b").unwrap() },
+        ReglaToken { tipo: TipoToken::Booleano, patron: Regex::new(r"^\bbooleano\b").unwrap() },
+        ReglaToken { tipo: TipoToken::Verdadero, patron: Regex::new(r"^\bverdadero\b").unwrap() },
+        ReglaToken { tipo: TipoToken::Falso, patron: Regex::new(r"^\bfalso\b").unwrap() },
         ReglaToken { tipo: TipoToken::Escribe, patron: Regex::new(r"^\bescribe\b").unwrap() },
+        ReglaToken { tipo: TipoToken::Lee, patron: Regex::new(r"^\blee\b").unwrap() },
         ReglaToken { tipo: TipoToken::Mientras, patron: Regex::new(r"^\bmientras\b").unwrap() },
         ReglaToken { tipo: TipoToken::Haz, patron: Regex::new(r"^\bhaz\b").unwrap() },
+        ReglaToken { tipo: TipoToken::Para, patron: Regex::new(r"^\bpara\b").unwrap() },
+        ReglaToken { tipo: TipoToken::Hasta, patron: Regex::new(r"^\bhasta\b").unwrap() },
+        ReglaToken { tipo: TipoToken::Paso, patron: Regex::new(r"^\bpaso\b").unwrap() },
+        ReglaToken { tipo: TipoToken::Rompe, patron: Regex::new(r"^\brompe\b").unwrap() },
+        ReglaToken { tipo: TipoToken::Continua, patron: Regex::new(r"^\bcontinua\b").unwrap() },
         ReglaToken { tipo: TipoToken::Si, patron: Regex::new(r"^si\b").unwrap() },
         ReglaToken { tipo: TipoToken::Entonces, patron: Regex::new(r"^entonces\b").unwrap() },
         ReglaToken { tipo: TipoToken::Sino, patron: Regex::new(r"^sino\b").unwrap() },
         ReglaToken { tipo: TipoToken::Nula, patron: Regex::new(r"^\bnula\b").unwrap() },
         ReglaToken { tipo: TipoToken::Regresa, patron: Regex::new(r"^\bregresa\b").unwrap() },
 
+        // Operadores lógicos (palabras reservadas)
+        ReglaToken { tipo: TipoToken::Y, patron: Regex::new(r"^\by\b").unwrap() },
+        ReglaToken { tipo: TipoToken::O, patron: Regex::new(r"^\bo\b").unwrap() },
+        ReglaToken { tipo: TipoToken::No, patron: Regex::new(r"^\bno\b").unwrap() },
+
         // Constantes (deben ir antes de identificadores)
         ReglaToken { tipo: TipoToken::CteFlot, patron: Regex::new(r"^[0-9]+\.[0-9]+([eE][+-]?[0-9]+)?").unwrap() },
         ReglaToken { tipo: TipoToken::CteEnt, patron: Regex::new(r"^[0-9]+").unwrap() },
-        ReglaToken { tipo: TipoToken::Letrero, patron: Regex::new(r#"^"[^"]*""#).unwrap() },
+        ReglaToken { tipo: TipoToken::Letrero, patron: Regex::new(r#"^"([^"\\]|\\.)*""#).unwrap() },
+        ReglaToken { tipo: TipoToken::CteChar, patron: Regex::new(r"^'([^'\\\n]|\\.)'").unwrap() },
 
         // Identificadores
         ReglaToken { tipo: TipoToken::Id, patron: Regex::new(r"^[a-zA-Z_][a-zA-Z0-9_]*").unwrap() },
@@ -49,10 +66,20 @@ lazy_static! {
         // Operadores (los compuestos primero)
         ReglaToken { tipo: TipoToken::Diferente, patron: Regex::new(r"^!=").unwrap() },
         ReglaToken { tipo: TipoToken::Igual, patron: Regex::new(r"^==").unwrap() },
+        ReglaToken { tipo: TipoToken::MayorIgual, patron: Regex::new(r"^>=").unwrap() },
+        ReglaToken { tipo: TipoToken::MenorIgual, patron: Regex::new(r"^<=").unwrap() },
+        ReglaToken { tipo: TipoToken::MasIgual, patron: Regex::new(r"^\+=").unwrap() },
+        ReglaToken { tipo: TipoToken::MenosIgual, patron: Regex::new(r"^-=").unwrap() },
+        ReglaToken { tipo: TipoToken::MultiIgual, patron: Regex::new(r"^\*=").unwrap() },
+        ReglaToken { tipo: TipoToken::DivIgual, patron: Regex::new(r"^/=").unwrap() },
+        ReglaToken { tipo: TipoToken::ModIgual, patron: Regex::new(r"^%=").unwrap() },
+        ReglaToken { tipo: TipoToken::Incremento, patron: Regex::new(r"^\+\+").unwrap() },
+        ReglaToken { tipo: TipoToken::Decremento, patron: Regex::new(r"^--").unwrap() },
         ReglaToken { tipo: TipoToken::Mas, patron: Regex::new(r"^\+").unwrap() },
         ReglaToken { tipo: TipoToken::Menos, patron: Regex::new(r"^-").unwrap() },
         ReglaToken { tipo: TipoToken::Multi, patron: Regex::new(r"^\*").unwrap() },
         ReglaToken { tipo: TipoToken::Div, patron: Regex::new(r"^/").unwrap() },
+        ReglaToken { tipo: TipoToken::Mod, patron: Regex::new(r"^%").unwrap() },
         ReglaToken { tipo: TipoToken::MayorQue, patron: Regex::new(r"^>").unwrap() },
         ReglaToken { tipo: TipoToken::MenorQue, patron: Regex::new(r"^<").unwrap() },
         ReglaToken { tipo: TipoToken::Asignacion, patron: Regex::new(r"^=").unwrap() },
@@ -64,25 +91,158 @@ lazy_static! {
         ReglaToken { tipo: TipoToken::LlaveCierra, patron: Regex::new(r"^\}").unwrap() },
         ReglaToken { tipo: TipoToken::ParenAbre, patron: Regex::new(r"^\(").unwrap() },
         ReglaToken { tipo: TipoToken::ParenCierra, patron: Regex::new(r"^\)").unwrap() },
+        ReglaToken { tipo: TipoToken::CorcheteAbre, patron: Regex::new(r"^\[").unwrap() },
+        ReglaToken { tipo: TipoToken::CorcheteCierra, patron: Regex::new(r"^\]").unwrap() },
         ReglaToken { tipo: TipoToken::Coma, patron: Regex::new(r"^,").unwrap() },
     ];
 
     // Regex para espacios en blanco y saltos de linea
     static ref REGLAS_ESPACIOS: Regex = Regex::new(r"^[ \t\r\n]+").unwrap();
+
+    // Regex para comentarios de línea (hasta el salto de línea, sin incluirlo)
+    static ref REGLA_COMENTARIO_LINEA: Regex = Regex::new(r"^//[^\n]*").unwrap();
+}
+
+/// Carácter que representa la secuencia de escape `\c` (`None` si no existe)
+fn caracter_escapado(c: char) -> Option<char> {
+    match c {
+        'n' => Some('\n'),
+        't' => Some('\t'),
+        'r' => Some('\r'),
+        '0' => Some('\0'),
+        '\\' => Some('\\'),
+        '\'' => Some('\''),
+        '"' => Some('"'),
+        _ => None,
+    }
+}
+
+/// Valor de una constante char a partir de su lexema (`'a'`, `'\n'`, ...)
+///
+/// Retorna `None` si no es un solo carácter o una secuencia de escape conocida.
+pub fn valor_char(lexema: &str) -> Option<char> {
+    let contenido = lexema.strip_prefix('\'')?.strip_suffix('\'')?;
+    let mut chars = contenido.chars();
+    let valor = match chars.next()? {
+        '\\' => caracter_escapado(chars.next()?)?,
+        c => c,
+    };
+    chars.next().is_none().then_some(valor)
+}
+
+/// Contenido de un letrero a partir de su lexema, sin comillas y con los
+/// escapes resueltos (`"a\tb"` → `a<tab>b`)
+///
+/// Retorna `None` si contiene una secuencia de escape desconocida.
+pub fn valor_letrero(lexema: &str) -> Option<String> {
+    let contenido = lexema.strip_prefix('"')?.strip_suffix('"')?;
+    let mut resultado = String::with_capacity(contenido.len());
+    let mut chars = contenido.chars();
+    while let Some(c) = chars.next() {
+        resultado.push(match c {
+            '\\' => caracter_escapado(chars.next()?)?,
+            c => c,
+        });
+    }
+    Some(resultado)
+}
+
+// Error léxico para una constante char mal formada que inicia en `offset`
+fn error_char_invalido(resto: &str, offset: usize, linea: usize, columna: usize) -> Diagnostico {
+    // Se subraya hasta la comilla de cierre si la hay en la misma línea
+    let largo = resto[1..]
+        .find(['\'', '\n'])
+        .map(|pos| pos + 1 + usize::from(resto[1 + pos..].starts_with('\'')))
+        .unwrap_or(1);
+    Diagnostico::error(
+        codigos::CHAR_INVALIDO,
+        format!("Constante char inválida {}", &resto[..largo]),
+    )
+    .con_span(Span::new(offset, offset + largo, linea, columna))
+    .con_nota("una constante char es un solo carácter entre comillas simples; escapes válidos: \\n \\t \\r \\0 \\\\ \\' \\\"")
+}
+
+// Error léxico para un letrero sin cerrar o con un escape desconocido que inicia en `offset`
+fn error_letrero_invalido(valor: Option<&str>, offset: usize, linea: usize, columna: usize) -> Diagnostico {
+    let (mensaje, largo) = match valor {
+        Some(valor) => ("Letrero con una secuencia de escape desconocida", valor.len()),
+        None => ("Letrero sin cerrar", 1),
+    };
+    Diagnostico::error(codigos::LETRERO_INVALIDO, mensaje)
+        .con_span(Span::new(offset, offset + largo, linea, columna))
+        .con_nota("escapes válidos en letreros: \\n \\t \\r \\0 \\\\ \\' \\\"")
+}
+
+// Longitud en bytes de un comentario de bloque anidable al inicio de `texto`
+// Retorna None si el comentario no se cierra
+fn longitud_comentario_bloque(texto: &str) -> Option<usize> {
+    let bytes = texto.as_bytes();
+    let mut profundidad = 0;
+    let mut i = 0;
+
+    while i + 1 < bytes.len() {
+        match (bytes[i], bytes[i + 1]) {
+            (b'/', b'*') => {
+                profundidad += 1;
+                i += 2;
+            }
+            (b'*', b'/') => {
+                profundidad -= 1;
+                i += 2;
+                if profundidad == 0 {
+                    return Some(i);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    None
 }
 
 // Funcion de analisis lexico
-pub fn analyze(input: &str, nivel_verbose: usize) -> Result<Vec<Token>, String> {
+// Cada token guarda su línea, columna y offset en bytes para los diagnósticos
+// Los comentarios `//` y `/* */` (anidables) se descartan igual que los espacios
+pub fn analyze(input: &str, nivel_verbose: usize) -> Result<Vec<Token>, Diagnostico> {
     let mut tokens: Vec<Token> = Vec::new();
     let mut linea = 1;
+    let mut inicio_linea = 0; // Offset en bytes donde inicia la línea actual
     let mut resto = input;
 
     while !resto.is_empty() {
-        // Ignorar espacios en blanco y saltos de linea
-        if let Some(mat) = REGLAS_ESPACIOS.find(resto) {
-            let espacio = mat.as_str();
-            linea += espacio.matches('\n').count();
-            resto = &resto[mat.end()..];
+        let offset = input.len() - resto.len();
+        let columna = input[inicio_linea..offset].chars().count() + 1;
+
+        // Ignorar espacios en blanco, saltos de linea y comentarios
+        let ignorado = if let Some(mat) = REGLAS_ESPACIOS.find(resto) {
+            Some(mat.end())
+        } else if let Some(mat) = REGLA_COMENTARIO_LINEA.find(resto) {
+            Some(mat.end())
+        } else if resto.starts_with("/*") {
+            match longitud_comentario_bloque(resto) {
+                Some(longitud) => Some(longitud),
+                None => {
+                    // Se reporta donde abrió el comentario
+                    let span = Span::new(offset, offset + 2, linea, columna);
+                    return Err(Diagnostico::error(
+                        codigos::COMENTARIO_SIN_CERRAR,
+                        "Comentario de bloque sin cerrar",
+                    )
+                    .con_span(span)
+                    .con_nota("los comentarios de bloque se anidan: cada '/*' necesita su propio '*/'"));
+                }
+            }
+        } else {
+            None
+        };
+
+        if let Some(longitud) = ignorado {
+            let texto = &resto[..longitud];
+            linea += texto.matches('\n').count();
+            if let Some(pos) = texto.rfind('\n') {
+                inicio_linea = offset + pos + 1;
+            }
+            resto = &resto[longitud..];
             continue;
         }
 
@@ -90,14 +250,21 @@ pub fn analyze(input: &str, nivel_verbose: usize) -> Result<Vec<Token>, String>
         let mut matched = false;
         for ReglaToken { tipo, patron } in REGLAS_TOKENS.iter() {
             if let Some(mat) = patron.find(resto) {
-                let valor = mat.as_str().to_string();
-                tokens.push(Token {
-                    tipo: tipo.clone(),
-                    valor: valor.clone(),
-                    linea,
-                });
+                let valor = mat.as_str();
+                if *tipo == TipoToken::CteChar && valor_char(valor).is_none() {
+                    return Err(error_char_invalido(resto, offset, linea, columna));
+                }
+                if *tipo == TipoToken::Letrero && valor_letrero(valor).is_none() {
+                    return Err(error_letrero_invalido(Some(valor), offset, linea, columna));
+                }
+                tokens.push(Token::new(tipo.clone(), valor, linea, columna, offset));
                 // Nivel 3: mostrar cada token encontrado
-                verbose_log(&format!("Token encontrado: {:?} ('{}') en linea {}", tipo, valor, linea), 3, nivel_verbose);
+                verbose_log(&format!("Token encontrado: {:?} ('{}') en linea {}, columna {}", tipo, valor, linea, columna), 3, nivel_verbose);
+                // Un letrero puede abarcar varias líneas
+                if let Some(pos) = valor.rfind('\n') {
+                    linea += valor.matches('\n').count();
+                    inicio_linea = offset + pos + 1;
+                }
                 resto = &resto[mat.end()..];
                 matched = true;
                 break;
@@ -106,18 +273,150 @@ pub fn analyze(input: &str, nivel_verbose: usize) -> Result<Vec<Token>, String>
 
         // Si no se encontro ningun token valido, es un error lexico
         if !matched {
+            if resto.starts_with('\'') {
+                return Err(error_char_invalido(resto, offset, linea, columna));
+            }
+            if resto.starts_with('"') {
+                return Err(error_letrero_invalido(None, offset, linea, columna));
+            }
             let caracter = resto.chars().next().unwrap_or('?');
-            return Err(format!("Error lexico en linea {}: simbolo no reconocido '{}'", linea, caracter));
+            let span = Span::new(offset, offset + caracter.len_utf8(), linea, columna);
+            return Err(Diagnostico::error(
+                codigos::SIMBOLO_NO_RECONOCIDO,
+                format!("Símbolo no reconocido '{}'", caracter),
+            ).con_span(span));
         }
     }
 
     // Agregar token EOF al final
-    tokens.push(Token {
-        tipo: TipoToken::EOF,
-        valor: "$".to_string(),
-        linea,
-    });
+    let offset = input.len();
+    let columna = input[inicio_linea..].chars().count() + 1;
+    tokens.push(Token::new(TipoToken::EOF, "$", linea, columna, offset));
 
     Ok(tokens)
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tipos(tokens: &[Token]) -> Vec<TipoToken> {
+        tokens.iter().map(|t| t.tipo.clone()).collect()
+    }
+
+    #[test]
+    fn test_comentario_de_linea() {
+        let tokens = analyze("x = 1; // comentario con ; y /* \nz = 2;", 0).unwrap();
+
+        assert_eq!(tokens.len(), 9);
+        assert_eq!(tokens[4].valor, "z");
+        assert_eq!((tokens[4].linea, tokens[4].columna), (2, 1));
+    }
+
+    #[test]
+    fn test_comentario_de_bloque_anidado() {
+        let fuente = "a /* uno\n /* dos\n */ sigue\n */ b / c";
+        let tokens = analyze(fuente, 0).unwrap();
+
+        assert_eq!(
+            tipos(&tokens),
+            vec![TipoToken::Id, TipoToken::Id, TipoToken::Div, TipoToken::Id, TipoToken::EOF]
+        );
+        // El conteo de líneas sigue siendo correcto después del comentario
+        assert_eq!((tokens[1].linea, tokens[1].columna), (4, 5));
+    }
+
+    #[test]
+    fn test_constantes_char() {
+        let tokens = analyze(r"c = 'a'; d = '\n'; e = '\''; f = ' ';", 0).unwrap();
+
+        assert_eq!(tokens[2].tipo, TipoToken::CteChar);
+        assert_eq!(valor_char(&tokens[2].valor), Some('a'));
+        assert_eq!(valor_char(&tokens[6].valor), Some('\n'));
+        assert_eq!(valor_char(&tokens[10].valor), Some('\''));
+        assert_eq!(valor_char(&tokens[14].valor), Some(' '));
+        assert_eq!(analyze("char", 0).unwrap()[0].tipo, TipoToken::Char);
+    }
+
+    #[test]
+    fn test_booleanos() {
+        let tokens = analyze("vars b : booleano; b = verdadero; b = falso; b = falsos;", 0).unwrap();
+
+        assert_eq!(tokens[3].tipo, TipoToken::Booleano);
+        assert_eq!(tokens[7].tipo, TipoToken::Verdadero);
+        assert_eq!(tokens[11].tipo, TipoToken::Falso);
+        assert_eq!(tokens[15].tipo, TipoToken::Id);
+    }
+
+    #[test]
+    fn test_relacionales_compuestos() {
+        let tokens = analyze("a<=b>=c< =d", 0).unwrap();
+
+        assert_eq!(
+            tipos(&tokens),
+            vec![
+                TipoToken::Id, TipoToken::MenorIgual, TipoToken::Id, TipoToken::MayorIgual, TipoToken::Id,
+                TipoToken::MenorQue, TipoToken::Asignacion, TipoToken::Id, TipoToken::EOF,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_asignacion_compuesta_e_incremento() {
+        let tokens = analyze("a+=1; b-=c; d*=e/=f%=g; i++; j--; k = - -1;", 0).unwrap();
+
+        assert_eq!(tokens[1].tipo, TipoToken::MasIgual);
+        assert_eq!(tokens[5].tipo, TipoToken::MenosIgual);
+        assert_eq!(tokens[9].tipo, TipoToken::MultiIgual);
+        assert_eq!(tokens[11].tipo, TipoToken::DivIgual);
+        assert_eq!(tokens[13].tipo, TipoToken::ModIgual);
+        assert_eq!(tokens[17].tipo, TipoToken::Incremento);
+        assert_eq!(tokens[20].tipo, TipoToken::Decremento);
+        // Con espacio, dos signos siguen siendo dos tokens
+        assert_eq!(tokens[24].tipo, TipoToken::Menos);
+        assert_eq!(tokens[25].tipo, TipoToken::Menos);
+    }
+
+    #[test]
+    fn test_residuo() {
+        let tokens = analyze("a % -b", 0).unwrap();
+
+        assert_eq!(
+            tipos(&tokens),
+            vec![TipoToken::Id, TipoToken::Mod, TipoToken::Menos, TipoToken::Id, TipoToken::EOF]
+        );
+    }
+
+    #[test]
+    fn test_constante_char_invalida() {
+        for fuente in ["c = 'ab';", r"c = '\q';", "c = '';", "c = 'a"] {
+            let diag = analyze(fuente, 0).unwrap_err();
+            assert_eq!(diag.codigo, codigos::CHAR_INVALIDO, "{}", fuente);
+            assert_eq!(diag.span.unwrap().columna, 5);
+        }
+    }
+
+    #[test]
+    fn test_letreros_con_escapes() {
+        let tokens = analyze(r#"escribe("a\tb \"c\"\\", "");"#, 0).unwrap();
+
+        assert_eq!(tokens[2].tipo, TipoToken::Letrero);
+        assert_eq!(valor_letrero(&tokens[2].valor).unwrap(), "a\tb \"c\"\\");
+        assert_eq!(valor_letrero(&tokens[4].valor).unwrap(), "");
+
+        assert_eq!(analyze(r#"x = "mal \q";"#, 0).unwrap_err().codigo, codigos::LETRERO_INVALIDO);
+        let diag = analyze("x = \"sin cerrar;", 0).unwrap_err();
+        assert_eq!(diag.codigo, codigos::LETRERO_INVALIDO);
+        assert_eq!(diag.mensaje, "Letrero sin cerrar");
+    }
+
+    #[test]
+    fn test_comentario_sin_cerrar() {
+        let fuente = "x = 1;\n  /* abre /* anidado */\nz = 2;";
+        let diag = analyze(fuente, 0).unwrap_err();
+
+        assert_eq!(diag.codigo, codigos::COMENTARIO_SIN_CERRAR);
+        assert_eq!(diag.span, Some(Span::new(9, 11, 2, 3)));
+    }
+}
@@ -5,9 +5,11 @@
 //!
 //! ## Estructura
 //! - `TipoToken`: Enum con todos los tipos de tokens del lenguaje
-//! - `Token`: Estructura que representa un token con su tipo, valor y línea
+//! - `Token`: Estructura que representa un token con su tipo, valor y ubicación
 //!
 
+use crate::diagnostico::Span;
+
 
 /// Tipos de tokens reconocidos por el lenguaje.
 ///
@@ -41,12 +43,28 @@ pub enum TipoToken {
     Flotante,
     /// Palabra reservada: `letrero` - Tipo de dato string
     LetreroTipo,
+    /// Palabra reservada: `char` - Tipo de dato carácter
+    Char,
+    /// Palabra reservada: `booleano` - Tipo de dato booleano
+    Booleano,
     /// Palabra reservada: `escribe` - Función de salida (print)
     Escribe,
+    /// Palabra reservada: `lee` - Función de entrada (read)
+    Lee,
     /// Palabra reservada: `mientras` - Inicio de ciclo while
     Mientras,
-    /// Palabra reservada: `haz` - Cuerpo del ciclo mientras
+    /// Palabra reservada: `haz` - Cuerpo del ciclo mientras / para
     Haz,
+    /// Palabra reservada: `para` - Inicio de ciclo contado (for)
+    Para,
+    /// Palabra reservada: `hasta` - Límite del ciclo para
+    Hasta,
+    /// Palabra reservada: `paso` - Incremento opcional del ciclo para
+    Paso,
+    /// Palabra reservada: `rompe` - Sale del ciclo más interno (break)
+    Rompe,
+    /// Palabra reservada: `continua` - Salta a la siguiente iteración (continue)
+    Continua,
     /// Palabra reservada: `si` - Condicional if
     Si,
     /// Palabra reservada: `entonces` - Parte del condicional if
@@ -76,9 +94,20 @@ pub enum TipoToken {
     /// Ejemplos: `3.14`, `1.5e10`, `2.0e-5`
     CteFlot,
 
-    /// Letrero: cadena de texto entre comillas dobles
-    /// Formato: "[^"]*"
-    /// Ejemplos: `"Hola mundo"`, `"Error en línea"`
+    /// Constante char: un carácter entre comillas simples
+    /// Formato: '[^'\\\n]' o una secuencia de escape (`\n`, `\t`, `\r`, `\0`, `\\`, `\'`)
+    /// Ejemplos: `'a'`, `'\n'`, `'\''`
+    CteChar,
+
+    /// Constante booleana verdadera: `verdadero`
+    Verdadero,
+    /// Constante booleana falsa: `falso`
+    Falso,
+
+    /// Letrero: cadena de texto entre comillas dobles, con los mismos escapes
+    /// que un char (más `\"`)
+    /// Formato: "([^"\\]|\\.)*"
+    /// Ejemplos: `"Hola mundo"`, `"dijo \"hola\"\n"`
     Letrero,
 
     // ═══════════════════════════════════════════════════════════
@@ -92,6 +121,8 @@ pub enum TipoToken {
     Multi,
     /// Operador: `/` (división)
     Div,
+    /// Operador: `%` (residuo)
+    Mod,
 
     // ═══════════════════════════════════════════════════════════
     // OPERADORES RELACIONALES
@@ -100,16 +131,44 @@ pub enum TipoToken {
     MayorQue,
     /// Operador relacional: `<` (menor que)
     MenorQue,
+    /// Operador relacional: `>=` (mayor o igual que)
+    MayorIgual,
+    /// Operador relacional: `<=` (menor o igual que)
+    MenorIgual,
     /// Operador relacional: `!=` (diferente de)
     Diferente,
     /// Operador relacional: `==` (igual a)
     Igual,
 
+    // ═══════════════════════════════════════════════════════════
+    // OPERADORES LÓGICOS
+    // ═══════════════════════════════════════════════════════════
+    /// Operador lógico: `y` (and, cortocircuito)
+    Y,
+    /// Operador lógico: `o` (or, cortocircuito)
+    O,
+    /// Operador lógico: `no` (not, unario)
+    No,
+
     // ═══════════════════════════════════════════════════════════
     // ASIGNACIÓN
     // ═══════════════════════════════════════════════════════════
     /// Operador de asignación: `=`
     Asignacion,
+    /// Asignación compuesta: `+=` (`x += e` equivale a `x = x + e`)
+    MasIgual,
+    /// Asignación compuesta: `-=`
+    MenosIgual,
+    /// Asignación compuesta: `*=`
+    MultiIgual,
+    /// Asignación compuesta: `/=`
+    DivIgual,
+    /// Asignación compuesta: `%=`
+    ModIgual,
+    /// Incremento: `++` (estatuto `x++;`)
+    Incremento,
+    /// Decremento: `--` (estatuto `x--;`)
+    Decremento,
 
     // ═══════════════════════════════════════════════════════════
     // SEPARADORES Y DELIMITADORES
@@ -126,6 +185,10 @@ pub enum TipoToken {
     ParenAbre,
     /// Delimitador: `)` (cierra paréntesis) - Cierra agrupación
     ParenCierra,
+    /// Delimitador: `[` (abre corchete) - Dimensión o índice de arreglo
+    CorcheteAbre,
+    /// Delimitador: `]` (cierra corchete) - Cierra dimensión o índice
+    CorcheteCierra,
     /// Separador: `,` (coma) - Separa elementos en listas
     Coma,
 
@@ -160,9 +223,17 @@ impl TipoToken {
             TipoToken::Entero => "entero",
             TipoToken::Flotante => "flotante",
             TipoToken::LetreroTipo => "letrero",
+            TipoToken::Char => "char",
+            TipoToken::Booleano => "booleano",
             TipoToken::Escribe => "escribe",
+            TipoToken::Lee => "lee",
             TipoToken::Mientras => "mientras",
             TipoToken::Haz => "haz",
+            TipoToken::Para => "para",
+            TipoToken::Hasta => "hasta",
+            TipoToken::Paso => "paso",
+            TipoToken::Rompe => "rompe",
+            TipoToken::Continua => "continua",
             TipoToken::Si => "si",
             TipoToken::Entonces => "entonces",
             TipoToken::Sino => "sino",
@@ -173,18 +244,34 @@ impl TipoToken {
             TipoToken::Id => "id",
             TipoToken::CteEnt => "cte_ent",
             TipoToken::CteFlot => "cte_flot",
-            TipoToken::Letrero => "letrero",
+            TipoToken::CteChar => "cte_char",
+            TipoToken::Verdadero => "verdadero",
+            TipoToken::Falso => "falso",
+            TipoToken::Letrero => "cte_letrero",
 
             // Operadores
             TipoToken::Mas => "+",
             TipoToken::Menos => "-",
             TipoToken::Multi => "*",
             TipoToken::Div => "/",
+            TipoToken::Mod => "%",
             TipoToken::MayorQue => ">",
             TipoToken::MenorQue => "<",
+            TipoToken::MayorIgual => ">=",
+            TipoToken::MenorIgual => "<=",
             TipoToken::Diferente => "!=",
             TipoToken::Igual => "==",
+            TipoToken::Y => "y",
+            TipoToken::O => "o",
+            TipoToken::No => "no",
             TipoToken::Asignacion => "=",
+            TipoToken::MasIgual => "+=",
+            TipoToken::MenosIgual => "-=",
+            TipoToken::MultiIgual => "*=",
+            TipoToken::DivIgual => "/=",
+            TipoToken::ModIgual => "%=",
+            TipoToken::Incremento => "++",
+            TipoToken::Decremento => "--",
 
             // Separadores
             TipoToken::PuntoYComa => ";",
@@ -193,6 +280,8 @@ impl TipoToken {
             TipoToken::LlaveCierra => "}",
             TipoToken::ParenAbre => "(",
             TipoToken::ParenCierra => ")",
+            TipoToken::CorcheteAbre => "[",
+            TipoToken::CorcheteCierra => "]",
             TipoToken::Coma => ",",
 
             // EOF
@@ -210,6 +299,8 @@ impl TipoToken {
 /// - `tipo`: Clasificación del token (palabra reservada, operador, etc.)
 /// - `valor`: Texto literal tal como aparece en el código fuente
 /// - `linea`: Número de línea donde aparece (para mensajes de error)
+/// - `columna`: Columna (en caracteres) donde inicia dentro de su línea
+/// - `offset`: Posición en bytes dentro del código fuente
 ///
 /// # Por qué guardamos el valor
 /// Aunque el `tipo` clasifica el token, el `valor` es necesario para:
@@ -228,4 +319,29 @@ pub struct Token {
     /// Número de línea donde aparece el token (1-indexed)
     /// Usado para reportar errores con ubicación precisa
     pub linea: usize,
+
+    /// Columna donde inicia el token (1-indexed, contada en caracteres)
+    pub columna: usize,
+
+    /// Offset en bytes del inicio del token dentro del código fuente
+    pub offset: usize,
+}
+
+impl Token {
+    /// Crea un nuevo token con su ubicación en el código fuente
+    pub fn new(tipo: TipoToken, valor: &str, linea: usize, columna: usize, offset: usize) -> Self {
+        Token {
+            tipo,
+            valor: valor.to_string(),
+            linea,
+            columna,
+            offset,
+        }
+    }
+
+    /// Región del código fuente que ocupa el token (para diagnósticos)
+    pub fn span(&self) -> Span {
+        let largo = if self.tipo == TipoToken::EOF { 0 } else { self.valor.len() };
+        Span::new(self.offset, self.offset + largo, self.linea, self.columna)
+    }
 }
\ No newline at end of file
@@ -5,3 +5,5 @@ pub mod lexico;
 pub mod semantico;
 pub mod intermedio;
 pub mod vm;
+pub mod diagnostico;
+pub mod cli;
@@ -1,22 +1,25 @@
 //! # Compilador Principal
 //!
 //! Este es el punto de entrada principal del compilador. Coordina las diferentes
-//! fases de compilación: análisis léxico y análisis sintáctico.
+//! fases de compilación (análisis léxico, sintáctico/semántico y generación del
+//! programa objeto) y la ejecución en la máquina virtual.
 //!
 //! ## Uso
 //! ```bash
-//! cargo run -- archivo.txt          # Compilar sin detalles
-//! cargo run -- archivo.txt -v       # Compilar con salida detallada
-//! cargo run -- archivo.txt --verbose # Compilar con salida detallada
+//! cargo run -- archivo.txt                          # Compilar y ejecutar
+//! cargo run -- archivo.txt -v                       # Compilar y ejecutar con salida detallada
+//! cargo run -- check archivo.txt                    # Solo validar el fuente
+//! cargo run -- compile archivo.txt -o programa.obj  # Solo compilar a archivo objeto
+//! cargo run -- run programa.obj                     # Ejecutar un archivo objeto
+//! cargo run -- dump-quads archivo.txt               # Listar cuádruplos
+//! cargo run -- --help                               # Subcomandos, opciones y códigos de salida
 //! ```
 //!
-//! ## Fases de Compilación
-//! 1. **Análisis Léxico**: Convierte el código fuente en tokens
-//! 2. **Análisis Sintáctico**: Valida la estructura gramatical usando SLR(1)
+//! Ver `compilador_rust::cli` para la lista completa de opciones y códigos de salida.
 //!
 //! ## Ejemplo de Salida (Modo Verbose)
 //! ```
-//! Analizando archivo: /path/to/programa.txt
+//! Analizando archivo: programa.txt
 //!
 //! === Iniciando análisis léxico ===
 //! Token encontrado: Programa ('programa') en linea 1
@@ -31,64 +34,16 @@
 //! ```
 
 // Usar módulos de la biblioteca
-use compilador_rust::lexico;
+use compilador_rust::cli::{self, codigos_salida, Emision, Opciones, Subcomando};
+use compilador_rust::intermedio::{objeto, ProgramaObjeto};
+use compilador_rust::lexico::{self, token::Token};
 use compilador_rust::sintactico;
 use compilador_rust::vm::{MaquinaVirtual, ConsolaIO};
+use std::path::Path;
 
-/// Obtiene la ruta del archivo a compilar y determina el nivel de verbose.
-///
-/// # Comportamiento
-/// - Si hay argumentos en la línea de comandos, los usa
-/// - Si no, solicita interactivamente la ruta al usuario
-/// - Detecta flags `-v`, `-vv`, `-vvv` para niveles de verbose (1, 2, 3)
-/// - Convierte rutas relativas a absolutas usando `canonicalize`
-///
-/// # Retorna
-/// Una tupla `(ruta_absoluta, nivel_verbose)`:
-/// - `ruta_absoluta`: Path completo del archivo a compilar
-/// - `nivel_verbose`: 0 (sin debug), 1 (-v), 2 (-vv), 3 (-vvv)
-///
-/// # Panics
-/// Termina el programa (exit 1) si no se puede resolver la ruta del archivo
-fn obtain_file_path() -> (String, usize) {
-    // Se obtiene el path del archivo por argumentos o se solicita al usuario
-    let mut path = String::new();
-    let args: Vec<String> = std::env::args().collect();
-
-    // Verificar si hay argumentos adicionales
-    if args.len() > 1 {
-        // Concatenar todos los argumentos (excepto el nombre del programa)
-        path = args[1..].join(" ");
-    } else {
-        println!("Ingrese el path del archivo a analizar (agregue -v/-vv/-vvv para debug):");
-        std::io::stdin().read_line(&mut path).expect("Error al leer la entrada");
-    }
-
-    // Determinar nivel de verbose
-    let nivel_verbose = if path.contains("-vvv") {
-        3
-    } else if path.contains("-vv") {
-        2
-    } else if path.contains("-v") {
-        1
-    } else {
-        0
-    };
-
-    // Limpiar flags de verbose del path
-    path = path.replace("-vvv", "").replace("-vv", "").replace("-v", "").trim().to_string();
-
-    // Se transforma el path a un full path
-    let full_path = match std::fs::canonicalize(&path) {
-        Ok(p) => p.to_string_lossy().to_string(),
-        Err(e) => {
-            eprintln!("Error al obtener el path completo de '{}': {}", path, e);
-            std::process::exit(1);
-        }
-    };
-
-    (full_path, nivel_verbose)
-}
+/// Resultado de una fase: en error lleva el código de salida del proceso
+/// (el mensaje ya se mostró en stderr)
+type Resultado<T> = Result<T, i32>;
 
 /// Imprime un mensaje según el nivel de verbose configurado.
 ///
@@ -116,119 +71,285 @@ fn verbose_log(message: &str, nivel_requerido: usize, nivel_actual: usize) {
 
 /// Función principal del compilador.
 ///
-/// # Flujo de Ejecución
-/// 1. Obtener ruta del archivo y modo verbose
-/// 2. Leer contenido del archivo fuente
-/// 3. **Fase 1 - Análisis Léxico**: Convertir código en tokens
-/// 4. **Fase 2 - Análisis Sintáctico**: Validar estructura con parser SLR
-/// 5. Reportar éxito o error
-///
-/// # Manejo de Errores
-/// - Errores de lectura de archivo → Termina con mensaje de error
-/// - Errores léxicos → Termina mostrando línea del error
-/// - Errores sintácticos → Termina mostrando token y línea del error
+/// Interpreta los argumentos con `cli::parsear_argumentos`, ejecuta el
+/// subcomando y termina el proceso con su código de salida:
+/// 0 éxito, 1 uso/E-S/objeto inválido, 2 léxico, 3 sintáctico, 4 semántico, 5 ejecución.
 ///
 /// # Ejemplo de Uso
 /// ```bash
-/// # Compilar programa simple
-/// cargo run -- ejemplos/test.txt
-///
-/// # Compilar con detalles del proceso
-/// cargo run -- ejemplos/test.txt -v
+/// # Compilar a archivo objeto y ejecutarlo después
+/// cargo run -- compile ejemplos/test.txt -o test.obj
+/// cargo run -- run test.obj
 /// ```
 fn main() {
-    // PASO 1: Obtener configuración (ruta y nivel de verbose)
-    let (full_path, nivel_verbose) = obtain_file_path();
+    let args: Vec<String> = std::env::args().skip(1).collect();
 
-    // PASO 2: Leer archivo fuente
-    // El compilador trabaja sobre el contenido completo en memoria
-    let contenido = match std::fs::read_to_string(&full_path) {
-        Ok(cont) => cont,
+    let opciones = match cli::parsear_argumentos(&args) {
+        Ok(opciones) => opciones,
         Err(e) => {
-            eprintln!("Error al leer el archivo '{}': {}", full_path, e);
-            return;
+            eprintln!("Error: {}\n\n{}", e, cli::AYUDA);
+            std::process::exit(codigos_salida::USO);
         }
     };
 
-    verbose_log(&format!("Analizando archivo: {}", full_path), 1, nivel_verbose);
+    let resultado = match opciones.subcomando {
+        Subcomando::Ayuda => {
+            println!("{}", cli::AYUDA);
+            Ok(())
+        }
+        Subcomando::Check => comando_check(&opciones),
+        Subcomando::Compile => comando_compile(&opciones),
+        Subcomando::Run => comando_run(&opciones),
+        Subcomando::DumpTokens => comando_dump_tokens(&opciones),
+        Subcomando::DumpQuads => comando_dump_quads(&opciones),
+        Subcomando::DumpTables => comando_dump_tablas(&opciones),
+    };
+
+    std::process::exit(resultado.err().unwrap_or(codigos_salida::EXITO));
+}
+
+// ==================== SUBCOMANDOS ====================
+
+/// `check`: análisis léxico, sintáctico y semántico sin ejecutar
+fn comando_check(opciones: &Opciones) -> Resultado<()> {
+    compilar(&opciones.entrada, opciones)?;
+    println!("✓ {}: sin errores", opciones.entrada.display());
+    Ok(())
+}
+
+/// `compile`: genera los artefactos pedidos con `--emit` (por defecto `.obj`)
+fn comando_compile(opciones: &Opciones) -> Resultado<()> {
+    let (tokens, programa) = compilar(&opciones.entrada, opciones)?;
+
+    for &emision in &opciones.emitir {
+        let ruta = opciones.ruta_salida(emision);
+        let contenido = match emision {
+            Emision::Obj => programa.a_texto().into_bytes(),
+            Emision::Objb => programa.a_binario().map_err(|e| {
+                eprintln!("✗ No se puede generar '{}': {}", ruta.display(), e);
+                codigos_salida::USO
+            })?,
+            Emision::Cuadruplos => listar_cuadruplos(&programa).into_bytes(),
+            Emision::Tokens => listar_tokens(&tokens).into_bytes(),
+        };
+
+        if let Err(e) = std::fs::write(&ruta, contenido) {
+            eprintln!("✗ Error al escribir '{}': {}", ruta.display(), e);
+            return Err(codigos_salida::USO);
+        }
+        println!("✓ {} guardado en {}", emision.extension(), ruta.display());
+    }
+    Ok(())
+}
+
+/// `run`: ejecuta un programa objeto, o compila y ejecuta un fuente
+fn comando_run(opciones: &Opciones) -> Resultado<()> {
+    let programa = cargar_programa(&opciones.entrada, opciones)?;
+    ejecutar(programa, opciones)
+}
+
+/// `dump-tokens`: lista los tokens del fuente
+fn comando_dump_tokens(opciones: &Opciones) -> Resultado<()> {
+    let contenido = leer_fuente(&opciones.entrada)?;
+    let tokens = analizar_lexico(&contenido, &opciones.entrada, opciones.nivel_verbose)?;
+    print!("{}", listar_tokens(&tokens));
+    Ok(())
+}
+
+/// `dump-quads`: lista los cuádruplos de un fuente o programa objeto
+fn comando_dump_quads(opciones: &Opciones) -> Resultado<()> {
+    let programa = cargar_programa(&opciones.entrada, opciones)?;
+    print!("{}", listar_cuadruplos(&programa));
+    Ok(())
+}
+
+/// `dump-tables`: lista funciones, constantes y strings de un fuente o programa objeto
+fn comando_dump_tablas(opciones: &Opciones) -> Resultado<()> {
+    let programa = cargar_programa(&opciones.entrada, opciones)?;
+    print!("{}", listar_tablas(&programa));
+    Ok(())
+}
+
+// ==================== FASES ====================
+
+/// Lee el archivo fuente completo en memoria
+fn leer_fuente(ruta: &Path) -> Resultado<String> {
+    std::fs::read_to_string(ruta).map_err(|e| {
+        eprintln!("Error al leer el archivo '{}': {}", ruta.display(), e);
+        codigos_salida::USO
+    })
+}
+
+/// **Fase 1 - Análisis Léxico**: convierte el código fuente en tokens
+fn analizar_lexico(contenido: &str, ruta: &Path, nivel_verbose: usize) -> Resultado<Vec<Token>> {
+    verbose_log(&format!("Analizando archivo: {}", ruta.display()), 1, nivel_verbose);
     verbose_log("\n=== Iniciando análisis léxico ===\n", 1, nivel_verbose);
 
-    // PASO 3: ANÁLISIS LÉXICO
-    // Convierte el código fuente (String) en una secuencia de tokens
-    // Cada token representa un elemento léxico: palabra reservada, identificador, operador, etc.
-    let tokens = match lexico::analyze(&contenido, nivel_verbose) {
-        Ok(toks) => {
-            verbose_log(&format!("\n✓ Análisis léxico completado. Tokens generados: {}\n", toks.len()), 1, nivel_verbose);
-            toks
-        },
-        Err(e) => {
-            eprintln!("✗ Error en el análisis léxico: {}", e);
-            return;
+    match lexico::analyze(contenido, nivel_verbose) {
+        Ok(tokens) => {
+            verbose_log(&format!("\n✓ Análisis léxico completado. Tokens generados: {}\n", tokens.len()), 1, nivel_verbose);
+            Ok(tokens)
         }
-    };
+        Err(diagnostico) => {
+            eprintln!("✗ Error en el análisis léxico");
+            eprint!("{}", diagnostico.renderizar(contenido, &ruta.display().to_string()));
+            Err(codigos_salida::LEXICO)
+        }
+    }
+}
+
+/// Compila un archivo fuente hasta el programa objeto.
+///
+/// # Flujo
+/// 1. Leer contenido del archivo fuente
+/// 2. **Fase 1 - Análisis Léxico**: Convertir código en tokens
+/// 3. **Fase 2 - Análisis Sintáctico**: Validar estructura con parser SLR (con
+///    acciones semánticas y generación de cuádruplos)
+/// 4. Exportar el programa objeto
+///
+/// Los errores sintácticos/semánticos se muestran todos (el parser se recupera
+/// en modo pánico) con código, línea, columna y el fragmento culpable subrayado
+fn compilar(ruta: &Path, opciones: &Opciones) -> Resultado<(Vec<Token>, ProgramaObjeto)> {
+    let nivel_verbose = opciones.nivel_verbose;
+    let contenido = leer_fuente(ruta)?;
+    let tokens = analizar_lexico(&contenido, ruta, nivel_verbose)?;
 
     verbose_log("=== Iniciando análisis sintáctico ===\n", 1, nivel_verbose);
 
-    // PASO 4: ANÁLISIS SINTÁCTICO
-    // Valida que la secuencia de tokens cumple con la gramática del lenguaje
     // Usa un parser SLR(1) basado en tablas ACTION y GOTO
     // Retorna el generador de cuádruplos para la siguiente fase
-    let generador = match sintactico::analyze(&tokens, nivel_verbose) {
+    let generador = match sintactico::analyze_con_distribucion(&tokens, nivel_verbose, opciones.distribucion) {
         Ok(generador_cuadruplos) => {
-            // El programa es sintácticamente correcto
             verbose_log("\n✓ Análisis sintáctico completado\n", 1, nivel_verbose);
             generador_cuadruplos
         },
-        Err(e) => {
-            // Hay un error de sintaxis
-            eprintln!("\n✗✗✗ Error en el análisis sintáctico ✗✗✗");
-            eprintln!("{}", e);
-            return;
+        Err(errores) => {
+            // Hay errores de sintaxis o semánticos: se muestran todos con la línea subrayada
+            eprintln!("\n{}", cli::encabezado_diagnosticos(&errores));
+            for diagnostico in &errores {
+                eprintln!("{}", diagnostico.renderizar(&contenido, &ruta.display().to_string()));
+            }
+            eprintln!("{} error(es) encontrados", errores.len());
+            return Err(cli::codigo_salida_diagnosticos(&errores));
         }
     };
 
-    // PASO 5: GENERACIÓN DE CÓDIGO INTERMEDIO Y EJECUCIÓN
-    verbose_log("=== Preparando ejecución ===\n", 1, nivel_verbose);
-
-    // Extraer nombre del programa desde la ruta del archivo
-    let nombre_programa = std::path::Path::new(&full_path)
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("programa")
-        .to_string();
+    verbose_log("=== Preparando programa objeto ===\n", 1, nivel_verbose);
 
     // Exportar el "programa objeto" con función tabla y cuádruplos
-    let programa = match generador.exportar_programa(nombre_programa) {
-        Ok(prog) => {
-            verbose_log(&format!("✓ Código intermedio preparado: {} cuádruplos\n", prog.cuadruplos.len()), 1, nivel_verbose);
-            prog
+    match generador.exportar_programa(cli::nombre_programa(ruta)) {
+        Ok(programa) => {
+            verbose_log(&format!("✓ Código intermedio preparado: {} cuádruplos\n", programa.cuadruplos.len()), 1, nivel_verbose);
+            Ok((tokens, programa))
         },
         Err(e) => {
             eprintln!("✗ Error al exportar programa: {}", e);
-            return;
+            Err(codigos_salida::SEMANTICO)
         }
-    };
+    }
+}
+
+/// Obtiene el programa objeto de un archivo: lo carga si es un archivo objeto
+/// (`.obj` / `.objb`, detectado por su cabecera) o compila el fuente
+fn cargar_programa(ruta: &Path, opciones: &Opciones) -> Resultado<ProgramaObjeto> {
+    let nivel_verbose = opciones.nivel_verbose;
+    let bytes = std::fs::read(ruta).map_err(|e| {
+        eprintln!("Error al leer el archivo '{}': {}", ruta.display(), e);
+        codigos_salida::USO
+    })?;
 
+    if !objeto::es_archivo_objeto(&bytes) {
+        return compilar(ruta, opciones).map(|(_, programa)| programa);
+    }
+
+    match ProgramaObjeto::desde_bytes(&bytes) {
+        Ok(programa) => {
+            verbose_log(&format!("✓ Programa objeto cargado: {} cuádruplos\n", programa.cuadruplos.len()), 1, nivel_verbose);
+            Ok(programa)
+        }
+        Err(e) => {
+            eprintln!("✗ {}: {}", ruta.display(), e);
+            Err(codigos_salida::USO)
+        }
+    }
+}
+
+/// Ejecuta un programa objeto en la máquina virtual
+fn ejecutar(programa: ProgramaObjeto, opciones: &Opciones) -> Resultado<()> {
+    let nivel_verbose = opciones.nivel_verbose;
     verbose_log("=== Ejecutando programa en máquina virtual ===\n", 1, nivel_verbose);
 
-    // Crear VM con sistema de IO (usa consola real para stdin/stdout)
-    let mut vm = MaquinaVirtual::new(Box::new(ConsolaIO::new()));
+    // Crear VM con sistema de IO (usa consola real para stdin/stdout) y la
+    // distribución de memoria pedida; rechaza programas compilados con otra
+    let mut vm = MaquinaVirtual::con_distribucion(Box::new(ConsolaIO::new()), opciones.distribucion)
+        .con_desbordamiento(opciones.desbordamiento);
 
-    // Cargar el programa objeto (inicializa tabla de funciones, constantes, etc.)
+    // Cargar el programa objeto (inicializa tabla de funciones, constantes, etc.).
+    // Un objeto que no corresponde a esta VM es archivo objeto inválido, no error
+    // de ejecución
     if let Err(e) = vm.cargar_programa(programa) {
-        eprintln!("✗ Error al cargar programa en VM: {}", e);
-        return;
+        eprintln!("✗ {}: {}", opciones.entrada.display(), e);
+        return Err(codigos_salida::USO);
     }
 
-    // Ejecutar el programa
     match vm.ejecutar() {
         Ok(_) => {
             verbose_log("\n✓✓✓ Ejecución completada exitosamente ✓✓✓", 1, nivel_verbose);
+            Ok(())
         },
         Err(e) => {
             eprintln!("\n✗✗✗ Error durante la ejecución ✗✗✗");
             eprintln!("{}", e);
-            return;
+            Err(codigos_salida::EJECUCION)
         }
     }
 }
+
+// ==================== LISTADOS ====================
+
+/// Un token por línea: `línea:columna  Tipo  'valor'`
+fn listar_tokens(tokens: &[Token]) -> String {
+    tokens.iter()
+        .map(|t| format!("{}:{}\t{:?}\t'{}'\n", t.linea, t.columna, t.tipo, t.valor))
+        .collect()
+}
+
+/// Un cuádruplo por línea: `índice: (operador, izq, der, resultado)`
+fn listar_cuadruplos(programa: &ProgramaObjeto) -> String {
+    programa.cuadruplos.iter()
+        .enumerate()
+        .map(|(i, cuadruplo)| format!("{}: {}\n", i, cuadruplo))
+        .collect()
+}
+
+/// Directorio de funciones, tabla de constantes y tabla de strings
+fn listar_tablas(programa: &ProgramaObjeto) -> String {
+    let mut texto = format!("=== Programa: {} ===\n", programa.nombre_programa);
+
+    let mut funciones: Vec<_> = programa.mapa_funciones.values().collect();
+    funciones.sort_by_key(|f| f.direccion_inicio);
+    texto.push_str(&format!("\n--- Funciones ({}) ---\n", funciones.len()));
+    for f in funciones {
+        texto.push_str(&format!(
+            "{}\tinicio: {}\tretorno: {}\tparámetros: {}\n",
+            f.nombre,
+            f.direccion_inicio,
+            f.tipo_retorno.as_deref().unwrap_or("nula"),
+            f.num_parametros,
+        ));
+    }
+
+    let mut constantes: Vec<_> = programa.mapa_constantes.iter().collect();
+    constantes.sort_by_key(|(direccion, _)| **direccion);
+    texto.push_str(&format!("\n--- Constantes ({}) ---\n", constantes.len()));
+    for (direccion, valor) in constantes {
+        texto.push_str(&format!("@{}\t{:?}\n", direccion, valor));
+    }
+
+    texto.push_str(&format!("\n--- Strings ({}) ---\n", programa.tabla_strings.len()));
+    for (i, s) in programa.tabla_strings.iter().enumerate() {
+        texto.push_str(&format!("STR[{}]\t{:?}\n", i, s));
+    }
+
+    texto
+}
@@ -12,6 +12,8 @@ use crate::semantico::{
 };
 use crate::semantico::tabla_variables::EntradaVariable;
 use crate::intermedio::memoria_virtual::{MemoriaVirtual, TipoSegmento};
+use crate::intermedio::DistribucionMemoria;
+use crate::diagnostico::Diagnostico;
 
 /// Contexto Semántico
 /// Mantiene el estado del análisis semántico durante el parsing.
@@ -35,25 +37,35 @@ pub struct ContextoSemantico {
 
     /// Gestor de memoria virtual para asignar direcciones a variables
     memoria_virtual: MemoriaVirtual,
+
+    /// Número de ciclos abiertos (`mientras` / `para`) en el punto actual
+    /// Se usa para validar que `rompe` y `continua` estén dentro de un ciclo
+    profundidad_ciclos: usize,
 }
 
 impl ContextoSemantico {
     /// Crea un nuevo contexto semántico
     pub fn new() -> Self {
+        Self::con_distribucion(DistribucionMemoria::default())
+    }
+
+    /// Crea un contexto semántico que asigna direcciones con la distribución dada
+    pub fn con_distribucion(distribucion: DistribucionMemoria) -> Self {
         ContextoSemantico {
             dir_funciones: DirectorioFunciones::new(),
             cubo_semantico: CuboSemantico::new(),
             alcance_actual: String::new(),
             tipo_actual: None,
             nombre_programa: String::new(),
-            memoria_virtual: MemoriaVirtual::new(),
+            memoria_virtual: MemoriaVirtual::con_distribucion(distribucion),
+            profundidad_ciclos: 0,
         }
     }
 
     /// GESTIÓN DE ALCANCES
     /// Establece el nombre del programa (alcance global)
     /// Esta función se llama en el punto neurálgico PN1 (después de `programa id;`)
-    pub fn inicializar_programa(&mut self, nombre: &str) -> Result<(), String> {
+    pub fn inicializar_programa(&mut self, nombre: &str) -> Result<(), Diagnostico> {
         self.nombre_programa = nombre.to_string();
         self.alcance_actual = nombre.to_string();
 
@@ -65,13 +77,13 @@ impl ContextoSemantico {
 
     /// Inicia una nueva función
     /// Esta función se llama en el punto neurálgico PN4 (al iniciar `<FUNCS>`)
-    pub fn iniciar_funcion(&mut self, nombre: &str, tipo_retorno: TipoRetorno) -> Result<(), String> {
+    pub fn iniciar_funcion(&mut self, nombre: &str, tipo_retorno: TipoRetorno) -> Result<(), Diagnostico> {
         // Validar que no sea el nombre del programa
         if nombre == self.nombre_programa {
-            return Err(format!(
+            return Err(Diagnostico::semantico(format!(
                 "Error semántico: El nombre de función '{}' no puede ser igual al nombre del programa",
                 nombre
-            ));
+            )));
         }
 
         // Agregar la función al directorio
@@ -85,10 +97,10 @@ impl ContextoSemantico {
 
     /// Agrega un parámetro a la función actual
     /// Se llama durante el procesamiento de <ARG_LIST>
-    pub fn agregar_parametro(&mut self, nombre: &str, tipo: TipoDato) -> Result<(), String> {
+    pub fn agregar_parametro(&mut self, nombre: &str, tipo: TipoDato) -> Result<(), Diagnostico> {
         // Los parámetros son como variables locales de la función
         // Asignar dirección de memoria en segmento local
-        let direccion = self.memoria_virtual.asignar_variable(tipo, TipoSegmento::Local)?;
+        let direccion = self.memoria_virtual.asignar_variable(tipo, TipoSegmento::Local).map_err(Diagnostico::memoria_agotada)?;
 
         // Agregar el parámetro como variable de la función
         self.dir_funciones.agregar_variable_con_direccion(
@@ -106,29 +118,29 @@ impl ContextoSemantico {
 
     /// Finaliza la declaración de la firma de la función
     /// Se llama después de procesar todos los parámetros, antes de entrar al cuerpo
-    pub fn finalizar_declaracion_funcion(&mut self) -> Result<(), String> {
+    pub fn finalizar_declaracion_funcion(&mut self) -> Result<(), Diagnostico> {
         // Por ahora no hace nada especial, pero mantiene consistencia
         Ok(())
     }
 
     /// Entra al ámbito de ejecución de una función
     /// Se llama justo antes de procesar el cuerpo de la función
-    pub fn entrar_ambito_funcion(&mut self, nombre: &str) -> Result<(), String> {
+    pub fn entrar_ambito_funcion(&mut self, nombre: &str) -> Result<(), Diagnostico> {
         // El ámbito ya está en la función desde iniciar_funcion
         if self.alcance_actual != nombre {
-            return Err(format!(
+            return Err(Diagnostico::interno(format!(
                 "Error interno: Intentando entrar al ámbito de '{}' pero el alcance actual es '{}'",
                 nombre, self.alcance_actual
-            ));
+            )));
         }
         Ok(())
     }
 
     /// Sale del ámbito de una función y vuelve al global
     /// Se llama al terminar de procesar una función completa
-    pub fn salir_ambito_funcion(&mut self) -> Result<(), String> {
+    pub fn salir_ambito_funcion(&mut self) -> Result<(), Diagnostico> {
         if self.alcance_actual == self.nombre_programa {
-            return Err("Error interno: Intentando salir del ámbito pero ya estamos en el global".to_string());
+            return Err(Diagnostico::interno("Error interno: Intentando salir del ámbito pero ya estamos en el global"));
         }
 
         // Resetear contadores locales y temporales en memoria virtual
@@ -157,6 +169,28 @@ impl ContextoSemantico {
         &self.nombre_programa
     }
 
+    // GESTIÓN DE CICLOS
+    /// Registra la entrada al cuerpo de un ciclo (al leer `haz`)
+    pub fn entrar_ciclo(&mut self) {
+        self.profundidad_ciclos += 1;
+    }
+
+    /// Registra la salida de un ciclo (al reducir `<CICLO>` / `<CICLO_PARA>`)
+    pub fn salir_ciclo(&mut self) {
+        self.profundidad_ciclos = self.profundidad_ciclos.saturating_sub(1);
+    }
+
+    /// Verifica que un estatuto de control de ciclo (`rompe`, `continua`) esté dentro de un ciclo
+    pub fn validar_en_ciclo(&self, estatuto: &str) -> Result<(), Diagnostico> {
+        if self.profundidad_ciclos == 0 {
+            return Err(Diagnostico::semantico(format!(
+                "Error semántico: '{}' solo puede usarse dentro de un ciclo",
+                estatuto
+            )));
+        }
+        Ok(())
+    }
+
     // GESTIÓN DE VARIABLES
     /// Establece el tipo actual para declaración de variables
     /// Esta función se llama en el punto neurálgico PN2 (después de reconocer `<TIPO>`)
@@ -171,10 +205,10 @@ impl ContextoSemantico {
 
     /// Agrega una variable al alcance actual
     /// Esta función se llama en el punto neurálgico PN3 (al reconocer un `id` en `<VAR_LIST>`)
-    pub fn agregar_variable(&mut self, nombre: &str) -> Result<(), String> {
+    pub fn agregar_variable(&mut self, nombre: &str) -> Result<(), Diagnostico> {
         // Obtener el tipo actual
         let tipo = self.tipo_actual.ok_or_else(||
-            "Error interno: No hay tipo actual establecido para declaración de variable".to_string()
+            Diagnostico::interno("Error interno: No hay tipo actual establecido para declaración de variable")
         )?;
 
         // Determinar si es global o local
@@ -182,9 +216,9 @@ impl ContextoSemantico {
 
         // Asignar dirección de memoria
         let direccion = if es_global {
-            self.memoria_virtual.asignar_variable(tipo, TipoSegmento::Global)?
+            self.memoria_virtual.asignar_variable(tipo, TipoSegmento::Global).map_err(Diagnostico::memoria_agotada)?
         } else {
-            self.memoria_virtual.asignar_variable(tipo, TipoSegmento::Local)?
+            self.memoria_virtual.asignar_variable(tipo, TipoSegmento::Local).map_err(Diagnostico::memoria_agotada)?
         };
 
         // Agregar la variable al alcance actual con su dirección
@@ -196,6 +230,43 @@ impl ContextoSemantico {
         )
     }
 
+    /// Agrega un arreglo al alcance actual reservando un bloque contiguo de memoria
+    /// Se llama desde `<VARS>` cuando un `id` trae dimensiones (`v[10]`, `m[3][4]`)
+    pub fn agregar_arreglo(&mut self, nombre: &str, dimensiones: Vec<usize>) -> Result<(), Diagnostico> {
+        let tipo = self.tipo_actual.ok_or_else(||
+            Diagnostico::interno("Error interno: No hay tipo actual establecido para declaración de variable")
+        )?;
+
+        if dimensiones.contains(&0) {
+            return Err(Diagnostico::arreglo_invalido(format!(
+                "Error semántico: El arreglo '{}' debe tener dimensiones mayores a cero",
+                nombre
+            )));
+        }
+
+        let segmento = if self.alcance_actual == self.nombre_programa {
+            TipoSegmento::Global
+        } else {
+            TipoSegmento::Local
+        };
+
+        let tamano = dimensiones.iter()
+            .try_fold(1usize, |tamano, &dimension| tamano.checked_mul(dimension))
+            .ok_or_else(|| Diagnostico::memoria_agotada(format!(
+                "Error: El arreglo '{}' tiene más casillas de las que caben en memoria",
+                nombre
+            )))?;
+        let direccion = self.memoria_virtual.asignar_bloque(tipo, segmento, tamano).map_err(Diagnostico::memoria_agotada)?;
+
+        self.dir_funciones.agregar_arreglo_con_direccion(
+            &self.alcance_actual,
+            nombre,
+            tipo,
+            direccion,
+            dimensiones
+        )
+    }
+
     /// Busca una variable (primero en alcance local, luego en global)
     /// Esta función se llama en el punto neurálgico PN7 (al usar un `id`)
     pub fn buscar_variable(&self, nombre: &str) -> Option<&EntradaVariable> {
@@ -205,54 +276,93 @@ impl ContextoSemantico {
         }
 
         // Si no está en local y no estamos en el global, buscar en global
-        if self.alcance_actual != self.nombre_programa {
-            if let Some(var) = self.dir_funciones.buscar_variable(&self.nombre_programa, nombre) {
-                return Some(var);
-            }
+        if self.alcance_actual != self.nombre_programa
+            && let Some(var) = self.dir_funciones.buscar_variable(&self.nombre_programa, nombre)
+        {
+            return Some(var);
         }
 
         None
     }
 
     /// Valida que una variable exista y retorna su tipo
-    pub fn obtener_tipo_variable(&self, nombre: &str) -> Result<TipoDato, String> {
+    pub fn obtener_tipo_variable(&self, nombre: &str) -> Result<TipoDato, Diagnostico> {
         self.buscar_variable(nombre)
             .map(|entrada| entrada.tipo)
-            .ok_or_else(|| format!(
+            .ok_or_else(|| Diagnostico::no_declarado(format!(
                 "Error semántico: Variable '{}' no declarada",
                 nombre
-            ))
+            )))
     }
 
     /// Obtiene la dirección de memoria asignada a una variable
-    pub fn obtener_direccion_variable(&self, nombre: &str) -> Result<usize, String> {
+    pub fn obtener_direccion_variable(&self, nombre: &str) -> Result<usize, Diagnostico> {
         self.buscar_variable(nombre)
             .map(|entrada| entrada.direccion)
-            .ok_or_else(|| format!(
+            .ok_or_else(|| Diagnostico::no_declarado(format!(
                 "Error semántico: Variable '{}' no declarada",
                 nombre
-            ))
+            )))
+    }
+
+    /// Obtiene la dirección del parámetro formal `posicion` de una función
+    pub fn obtener_direccion_parametro(&self, funcion: &str, posicion: usize) -> Result<usize, Diagnostico> {
+        let parametros = self.dir_funciones.obtener_parametros(funcion);
+        let (nombre, _) = parametros.get(posicion).ok_or_else(|| Diagnostico::semantico(format!(
+            "Error semántico: La función '{}' recibe {} parámetro(s)",
+            funcion, parametros.len()
+        )))?;
+
+        self.dir_funciones.buscar_variable(funcion, nombre)
+            .map(|entrada| entrada.direccion)
+            .ok_or_else(|| Diagnostico::interno(format!("Error interno: Parámetro '{}' de '{}' sin dirección", nombre, funcion)))
+    }
+
+    /// Número de parámetros formales de una función
+    pub fn num_parametros(&self, funcion: &str) -> usize {
+        self.dir_funciones.obtener_parametros(funcion).len()
+    }
+
+    /// Obtiene el tipo del parámetro formal `posicion` de una función
+    pub fn obtener_tipo_parametro(&self, funcion: &str, posicion: usize) -> Result<TipoDato, Diagnostico> {
+        let parametros = self.dir_funciones.obtener_parametros(funcion);
+        parametros.get(posicion)
+            .map(|(_, tipo)| *tipo)
+            .ok_or_else(|| Diagnostico::semantico(format!(
+                "Error semántico: La función '{}' recibe {} parámetro(s)",
+                funcion, parametros.len()
+            )))
+    }
+
+    /// Obtiene las dimensiones de una variable (vacío si es escalar)
+    pub fn obtener_dimensiones_variable(&self, nombre: &str) -> Result<Vec<usize>, Diagnostico> {
+        self.buscar_variable(nombre)
+            .map(|entrada| entrada.dimensiones.clone())
+            .ok_or_else(|| Diagnostico::no_declarado(format!(
+                "Error semántico: Variable '{}' no declarada",
+                nombre
+            )))
     }
 
     /// Verifica que una función exista en el directorio
-    pub fn verificar_funcion_existe(&self, nombre: &str) -> Result<(), String> {
+    pub fn verificar_funcion_existe(&self, nombre: &str) -> Result<(), Diagnostico> {
         if self.dir_funciones.buscar_funcion(nombre).is_some() {
             Ok(())
         } else {
-            Err(format!("Error semántico: Función '{}' no declarada", nombre))
+            Err(Diagnostico::no_declarado(format!("Error semántico: Función '{}' no declarada", nombre)))
         }
     }
 
     /// Obtiene el tipo de retorno de una función
     /// Retorna Ok(tipo) si la función tiene retorno, Err si es nula
-    pub fn obtener_tipo_retorno_funcion(&self, nombre: &str) -> Result<TipoDato, String> {
+    pub fn obtener_tipo_retorno_funcion(&self, nombre: &str) -> Result<TipoDato, Diagnostico> {
         if let Some(funcion) = self.dir_funciones.buscar_funcion(nombre) {
             match funcion.tipo_retorno {
                 TipoRetorno::Tipo(tipo) => Ok(tipo),
-                TipoRetorno::Nula => Err(format!("Función '{}' no tiene retorno (nula)", nombre)),
+                TipoRetorno::Nula => Err(Diagnostico::semantico(format!("Función '{}' no tiene retorno (nula)", nombre))),
             }
         } else {
-            Err(format!("Error semántico: Función '{}' no declarada", nombre))
+            Err(Diagnostico::no_declarado(format!("Error semántico: Función '{}' no declarada", nombre)))
         }
     }
 
@@ -320,6 +430,24 @@ mod tests {
         assert!(ctx.buscar_variable("y").is_none());
     }
 
+    #[test]
+    fn test_arreglos_bloque_contiguo() {
+        let mut ctx = ContextoSemantico::new();
+        ctx.inicializar_programa("test").unwrap();
+
+        ctx.establecer_tipo_actual(TipoDato::Entero);
+        ctx.agregar_arreglo("v", vec![10]).unwrap();
+        ctx.agregar_variable("x").unwrap();
+
+        // `x` queda después de las 10 casillas de `v`
+        let base_v = ctx.obtener_direccion_variable("v").unwrap();
+        assert_eq!(ctx.obtener_direccion_variable("x").unwrap(), base_v + 10);
+        assert_eq!(ctx.obtener_dimensiones_variable("v").unwrap(), vec![10]);
+
+        // Dimensión cero es inválida
+        assert!(ctx.agregar_arreglo("w", vec![0]).is_err());
+    }
+
     #[test]
     fn test_alcance_variables() {
         let mut ctx = ContextoSemantico::new();
@@ -345,4 +473,21 @@ mod tests {
         assert!(ctx.buscar_variable("global_var").is_some());
         assert!(ctx.buscar_variable("local_var").is_none());
     }
+
+    #[test]
+    fn test_control_de_ciclo() {
+        let mut ctx = ContextoSemantico::new();
+
+        assert!(ctx.validar_en_ciclo("rompe").is_err());
+
+        ctx.entrar_ciclo();
+        ctx.entrar_ciclo();
+        assert!(ctx.validar_en_ciclo("rompe").is_ok());
+        ctx.salir_ciclo();
+        assert!(ctx.validar_en_ciclo("continua").is_ok());
+        ctx.salir_ciclo();
+
+        let error = ctx.validar_en_ciclo("continua").unwrap_err();
+        assert!(error.mensaje.contains("'continua'"));
+    }
 }
@@ -1,7 +1,8 @@
 //! # Cubo Semántico
 //! El Cubo Semántico define las reglas de tipos para:
-//! - Operadores aritméticos: +, -, *, /
-//! - Operadores relacionales: >, <, ==, !=
+//! - Operadores aritméticos: +, -, *, /, % y los unarios + y -
+//! - Operadores relacionales: >, <, >=, <=, ==, !=
+//! - Operadores lógicos: y, o, no
 //! - Operador de asignación: =
 
 use crate::semantico::tipos::TipoDato;
@@ -15,29 +16,44 @@ pub enum Operador {
     Resta,
     Multiplicacion,
     Division,
+    Modulo,
 
     // Relacionales
     MayorQue,
     MenorQue,
+    MayorIgual,
+    MenorIgual,
     Igual,
     Diferente,
 
+    // Lógicos
+    Y,
+    O,
+    No,
+
     // Asignación
     Asignacion,
 }
 
 impl Operador {
     /// Convierte una cadena a un Operador
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "+" => Some(Operador::Suma),
             "-" => Some(Operador::Resta),
             "*" => Some(Operador::Multiplicacion),
             "/" => Some(Operador::Division),
+            "%" => Some(Operador::Modulo),
             ">" => Some(Operador::MayorQue),
             "<" => Some(Operador::MenorQue),
+            ">=" => Some(Operador::MayorIgual),
+            "<=" => Some(Operador::MenorIgual),
             "==" => Some(Operador::Igual),
             "!=" => Some(Operador::Diferente),
+            "y" => Some(Operador::Y),
+            "o" => Some(Operador::O),
+            "no" => Some(Operador::No),
             "=" => Some(Operador::Asignacion),
             _ => None,
         }
@@ -56,11 +72,14 @@ pub enum ResultadoTipo {
 /// Cubo Semántico para validación de tipos
 ///
 /// # Reglas principales:
-/// - **Aritméticas**: Cualquier operación con `flotante` promueve a `flotante`
-/// - **Relacionales**: Siempre retornan `entero` (0 o 1 como booleano)
+/// - **Aritméticas**: Cualquier operación con `flotante` promueve a `flotante`; `letrero + letrero` concatena
+/// - **Relacionales**: Siempre retornan `booleano`; `char` solo se compara con `char`,
+///   y `letrero` y `booleano` solo consigo mismos (`==` / `!=`)
+/// - **Lógicos**: Solo operan sobre `booleano` y retornan `booleano`
 /// - **Asignación**: Fuertemente tipado, solo permite `flotante = entero` (promoción)
 pub struct CuboSemantico {
     reglas: HashMap<(TipoDato, Operador, TipoDato), TipoDato>,
+    reglas_unarias: HashMap<(Operador, TipoDato), TipoDato>,
 }
 
 impl CuboSemantico {
@@ -68,6 +87,7 @@ impl CuboSemantico {
     pub fn new() -> Self {
         let mut cubo = CuboSemantico {
             reglas: HashMap::new(),
+            reglas_unarias: HashMap::new(),
         };
 
         cubo.inicializar_reglas();
@@ -76,13 +96,14 @@ impl CuboSemantico {
 
     /// Inicializa todas las reglas del cubo semántico
     fn inicializar_reglas(&mut self) {
-        // OPERADORES ARITMÉTICOS (+, -, *, /)
+        // OPERADORES ARITMÉTICOS (+, -, *, /, %)
         // Regla: Cualquier operación con flotante promueve a flotante
         let operadores_aritmeticos = [
             Operador::Suma,
             Operador::Resta,
             Operador::Multiplicacion,
             Operador::Division,
+            Operador::Modulo,
         ];
 
         for &op in &operadores_aritmeticos {
@@ -99,29 +120,56 @@ impl CuboSemantico {
             self.agregar_regla(TipoDato::Flotante, op, TipoDato::Flotante, TipoDato::Flotante);
         }
 
-        // OPERADORES RELACIONALES (>, <, ==, !=)
-        // Regla: Siempre retornan entero (0 = falso, 1 = verdadero)
+        // letrero + letrero = letrero (concatenación)
+        self.agregar_regla(TipoDato::Letrero, Operador::Suma, TipoDato::Letrero, TipoDato::Letrero);
+
+        // + y - unarios: conservan el tipo numérico
+        for op in [Operador::Suma, Operador::Resta] {
+            self.reglas_unarias.insert((op, TipoDato::Entero), TipoDato::Entero);
+            self.reglas_unarias.insert((op, TipoDato::Flotante), TipoDato::Flotante);
+        }
+
+        // OPERADORES RELACIONALES (>, <, >=, <=, ==, !=)
+        // Regla: Siempre retornan booleano
         let operadores_relacionales = [
             Operador::MayorQue,
             Operador::MenorQue,
+            Operador::MayorIgual,
+            Operador::MenorIgual,
             Operador::Igual,
             Operador::Diferente,
         ];
 
         for &op in &operadores_relacionales {
-            // entero OP entero = entero (booleano)
-            self.agregar_regla(TipoDato::Entero, op, TipoDato::Entero, TipoDato::Entero);
+            // entero OP entero = booleano
+            self.agregar_regla(TipoDato::Entero, op, TipoDato::Entero, TipoDato::Booleano);
 
-            // entero OP flotante = entero (booleano)
-            self.agregar_regla(TipoDato::Entero, op, TipoDato::Flotante, TipoDato::Entero);
+            // entero OP flotante = booleano
+            self.agregar_regla(TipoDato::Entero, op, TipoDato::Flotante, TipoDato::Booleano);
 
-            // flotante OP entero = entero (booleano)
-            self.agregar_regla(TipoDato::Flotante, op, TipoDato::Entero, TipoDato::Entero);
+            // flotante OP entero = booleano
+            self.agregar_regla(TipoDato::Flotante, op, TipoDato::Entero, TipoDato::Booleano);
 
-            // flotante OP flotante = entero (booleano)
-            self.agregar_regla(TipoDato::Flotante, op, TipoDato::Flotante, TipoDato::Entero);
+            // flotante OP flotante = booleano
+            self.agregar_regla(TipoDato::Flotante, op, TipoDato::Flotante, TipoDato::Booleano);
+
+            // char OP char = booleano (compara el código del carácter)
+            self.agregar_regla(TipoDato::Char, op, TipoDato::Char, TipoDato::Booleano);
+        }
+
+        // letrero == letrero, letrero != letrero = booleano (compara el contenido)
+        // booleano == booleano, booleano != booleano = booleano
+        for tipo in [TipoDato::Letrero, TipoDato::Booleano] {
+            self.agregar_regla(tipo, Operador::Igual, tipo, TipoDato::Booleano);
+            self.agregar_regla(tipo, Operador::Diferente, tipo, TipoDato::Booleano);
         }
 
+        // OPERADORES LÓGICOS (y, o, no)
+        // Regla: Solo booleanos, retornan booleano
+        self.agregar_regla(TipoDato::Booleano, Operador::Y, TipoDato::Booleano, TipoDato::Booleano);
+        self.agregar_regla(TipoDato::Booleano, Operador::O, TipoDato::Booleano, TipoDato::Booleano);
+        self.reglas_unarias.insert((Operador::No, TipoDato::Booleano), TipoDato::Booleano);
+
         // OPERADOR DE ASIGNACIÓN (=)
         // Regla: Fuertemente tipado
         // entero = entero
@@ -130,6 +178,15 @@ impl CuboSemantico {
         // flotante = flotante
         self.agregar_regla(TipoDato::Flotante, Operador::Asignacion, TipoDato::Flotante, TipoDato::Flotante);
 
+        // char = char
+        self.agregar_regla(TipoDato::Char, Operador::Asignacion, TipoDato::Char, TipoDato::Char);
+
+        // letrero = letrero
+        self.agregar_regla(TipoDato::Letrero, Operador::Asignacion, TipoDato::Letrero, TipoDato::Letrero);
+
+        // booleano = booleano
+        self.agregar_regla(TipoDato::Booleano, Operador::Asignacion, TipoDato::Booleano, TipoDato::Booleano);
+
         // flotante = entero (promoción permitida)
         self.agregar_regla(TipoDato::Flotante, Operador::Asignacion, TipoDato::Entero, TipoDato::Flotante);
             }
@@ -156,6 +213,14 @@ impl CuboSemantico {
         }
     }
 
+    /// Valida una operación unaria (`no`, o `+` / `-` como signo) y retorna el tipo resultante
+    pub fn validar_unario(&self, operador: Operador, tipo: TipoDato) -> ResultadoTipo {
+        match self.reglas_unarias.get(&(operador, tipo)) {
+            Some(&tipo_resultado) => ResultadoTipo::Ok(tipo_resultado),
+            None => ResultadoTipo::Error,
+        }
+    }
+
     /// Imprime el cubo semántico completo
     pub fn imprimir(&self) {
         println!("\n╔══════════════════════════════════════════════════════════════╗");
@@ -166,17 +231,27 @@ impl CuboSemantico {
         println!("─────────────────────────────────────");
         self.imprimir_operador(Operador::Suma, "+");
 
-        println!("\nOPERADORES RELACIONALES (>, <, ==, !=):");
-        println!("────────────────────────────────────────");
+        println!("\nOPERADORES RELACIONALES (>, <, >=, <=, ==, !=):");
+        println!("────────────────────────────────────────────────");
         self.imprimir_operador(Operador::MayorQue, ">");
 
+        println!("\nOPERADORES LÓGICOS (y, o, no):");
+        println!("──────────────────────────────");
+        self.imprimir_operador(Operador::Y, "y");
+        self.imprimir_operador(Operador::O, "o");
+        for (&(op, tipo), &resultado) in &self.reglas_unarias {
+            if op == Operador::No {
+                println!("  no {} = {}", tipo, resultado);
+            }
+        }
+
         println!("\nOPERADOR DE ASIGNACIÓN (=):");
         println!("───────────────────────────");
         self.imprimir_asignacion();
     }
 
     fn imprimir_operador(&self, op: Operador, simbolo: &str) {
-        let tipos = [TipoDato::Entero, TipoDato::Flotante];
+        let tipos = [TipoDato::Entero, TipoDato::Flotante, TipoDato::Booleano];
 
         for &t1 in &tipos {
             for &t2 in &tipos {
@@ -244,24 +319,183 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_modulo_y_signo() {
+        let cubo = CuboSemantico::new();
+
+        assert_eq!(
+            cubo.validar(TipoDato::Entero, Operador::Modulo, TipoDato::Entero),
+            ResultadoTipo::Ok(TipoDato::Entero)
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Flotante, Operador::Modulo, TipoDato::Entero),
+            ResultadoTipo::Ok(TipoDato::Flotante)
+        );
+        assert_eq!(
+            cubo.validar_unario(Operador::Resta, TipoDato::Flotante),
+            ResultadoTipo::Ok(TipoDato::Flotante)
+        );
+        assert_eq!(
+            cubo.validar_unario(Operador::Suma, TipoDato::Entero),
+            ResultadoTipo::Ok(TipoDato::Entero)
+        );
+        assert_eq!(
+            cubo.validar_unario(Operador::Resta, TipoDato::Booleano),
+            ResultadoTipo::Error
+        );
+        assert_eq!(
+            cubo.validar_unario(Operador::Suma, TipoDato::Letrero),
+            ResultadoTipo::Error
+        );
+    }
+
     #[test]
     fn test_operadores_relacionales() {
         let cubo = CuboSemantico::new();
 
-        // Todos los operadores relacionales retornan entero
+        // Todos los operadores relacionales retornan booleano
         assert_eq!(
             cubo.validar(TipoDato::Entero, Operador::MayorQue, TipoDato::Entero),
-            ResultadoTipo::Ok(TipoDato::Entero)
+            ResultadoTipo::Ok(TipoDato::Booleano)
         );
 
         assert_eq!(
             cubo.validar(TipoDato::Flotante, Operador::MenorQue, TipoDato::Entero),
-            ResultadoTipo::Ok(TipoDato::Entero)
+            ResultadoTipo::Ok(TipoDato::Booleano)
         );
 
         assert_eq!(
             cubo.validar(TipoDato::Entero, Operador::Igual, TipoDato::Flotante),
-            ResultadoTipo::Ok(TipoDato::Entero)
+            ResultadoTipo::Ok(TipoDato::Booleano)
+        );
+
+        // Un resultado relacional no se asigna a un entero
+        assert_eq!(
+            cubo.validar(TipoDato::Entero, Operador::Asignacion, TipoDato::Booleano),
+            ResultadoTipo::Error
+        );
+
+        // >= y <= siguen las reglas de > y <
+        assert_eq!(
+            cubo.validar(TipoDato::Entero, Operador::MenorIgual, TipoDato::Flotante),
+            ResultadoTipo::Ok(TipoDato::Booleano)
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Char, Operador::MayorIgual, TipoDato::Char),
+            ResultadoTipo::Ok(TipoDato::Booleano)
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Letrero, Operador::MayorIgual, TipoDato::Letrero),
+            ResultadoTipo::Error
+        );
+    }
+
+    #[test]
+    fn test_operadores_logicos() {
+        let cubo = CuboSemantico::new();
+
+        assert_eq!(
+            cubo.validar(TipoDato::Booleano, Operador::Y, TipoDato::Booleano),
+            ResultadoTipo::Ok(TipoDato::Booleano)
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Entero, Operador::Y, TipoDato::Entero),
+            ResultadoTipo::Error
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Booleano, Operador::O, TipoDato::Entero),
+            ResultadoTipo::Error
+        );
+        assert_eq!(
+            cubo.validar_unario(Operador::No, TipoDato::Booleano),
+            ResultadoTipo::Ok(TipoDato::Booleano)
+        );
+        assert_eq!(
+            cubo.validar_unario(Operador::No, TipoDato::Entero),
+            ResultadoTipo::Error
+        );
+    }
+
+    #[test]
+    fn test_letrero() {
+        let cubo = CuboSemantico::new();
+
+        assert_eq!(
+            cubo.validar(TipoDato::Letrero, Operador::Suma, TipoDato::Letrero),
+            ResultadoTipo::Ok(TipoDato::Letrero)
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Letrero, Operador::Diferente, TipoDato::Letrero),
+            ResultadoTipo::Ok(TipoDato::Booleano)
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Letrero, Operador::Asignacion, TipoDato::Letrero),
+            ResultadoTipo::Ok(TipoDato::Letrero)
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Letrero, Operador::MenorQue, TipoDato::Letrero),
+            ResultadoTipo::Error
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Letrero, Operador::Suma, TipoDato::Entero),
+            ResultadoTipo::Error
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Char, Operador::Asignacion, TipoDato::Letrero),
+            ResultadoTipo::Error
+        );
+    }
+
+    #[test]
+    fn test_booleano() {
+        let cubo = CuboSemantico::new();
+
+        assert_eq!(
+            cubo.validar(TipoDato::Booleano, Operador::Asignacion, TipoDato::Booleano),
+            ResultadoTipo::Ok(TipoDato::Booleano)
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Booleano, Operador::Igual, TipoDato::Booleano),
+            ResultadoTipo::Ok(TipoDato::Booleano)
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Booleano, Operador::MayorQue, TipoDato::Booleano),
+            ResultadoTipo::Error
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Booleano, Operador::Suma, TipoDato::Booleano),
+            ResultadoTipo::Error
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Booleano, Operador::Asignacion, TipoDato::Entero),
+            ResultadoTipo::Error
+        );
+    }
+
+    #[test]
+    fn test_char() {
+        let cubo = CuboSemantico::new();
+
+        // char se compara y se asigna solo con char
+        assert_eq!(
+            cubo.validar(TipoDato::Char, Operador::MenorQue, TipoDato::Char),
+            ResultadoTipo::Ok(TipoDato::Booleano)
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Char, Operador::Asignacion, TipoDato::Char),
+            ResultadoTipo::Ok(TipoDato::Char)
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Char, Operador::Igual, TipoDato::Entero),
+            ResultadoTipo::Error
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Char, Operador::Suma, TipoDato::Char),
+            ResultadoTipo::Error
+        );
+        assert_eq!(
+            cubo.validar(TipoDato::Entero, Operador::Asignacion, TipoDato::Char),
+            ResultadoTipo::Error
         );
     }
 
@@ -6,6 +6,7 @@
 
 use crate::semantico::tipos::TipoRetorno;
 use crate::semantico::tabla_variables::TablaVariables;
+use crate::diagnostico::Diagnostico;
 use std::collections::HashMap;
 
 /// Entrada de una función en el directorio
@@ -48,13 +49,13 @@ impl DirectorioFunciones {
 
     /// Agrega una función al directorio
     /// Lanza error si la función ya existe (declaración duplicada)
-    pub fn agregar_funcion(&mut self, nombre: &str, tipo_retorno: TipoRetorno) -> Result<(), String> {
+    pub fn agregar_funcion(&mut self, nombre: &str, tipo_retorno: TipoRetorno) -> Result<(), Diagnostico> {
         // Validar que la función no exista
         if self.funciones.contains_key(nombre) {
-            return Err(format!(
+            return Err(Diagnostico::doble_declaracion(format!(
                 "Error semántico: Función '{}' doblemente declarada",
                 nombre
-            ));
+            )));
         }
 
         // Agregar la función
@@ -87,13 +88,13 @@ impl DirectorioFunciones {
         nombre_funcion: &str,
         nombre_variable: &str,
         tipo: crate::semantico::tipos::TipoDato,
-    ) -> Result<(), String> {
+    ) -> Result<(), Diagnostico> {
         // Buscar la función
         let funcion = self.funciones.get_mut(nombre_funcion)
-            .ok_or_else(|| format!(
+            .ok_or_else(|| Diagnostico::no_declarado(format!(
                 "Error semántico: Función '{}' no existe",
                 nombre_funcion
-            ))?;
+            )))?;
 
         // Agregar la variable a la tabla de variables de la función
         // NOTA: Este método no asigna dirección (se usa solo en tests)
@@ -107,31 +108,49 @@ impl DirectorioFunciones {
         nombre_variable: &str,
         tipo: crate::semantico::tipos::TipoDato,
         direccion: usize,
-    ) -> Result<(), String> {
+    ) -> Result<(), Diagnostico> {
         // Buscar la función
         let funcion = self.funciones.get_mut(nombre_funcion)
-            .ok_or_else(|| format!(
+            .ok_or_else(|| Diagnostico::no_declarado(format!(
                 "Error semántico: Función '{}' no existe",
                 nombre_funcion
-            ))?;
+            )))?;
 
         // Agregar la variable a la tabla de variables de la función
         funcion.tabla_variables.agregar(nombre_variable, tipo, direccion)
     }
 
+    /// Agrega un arreglo con dirección base y dimensiones a una función específica
+    pub fn agregar_arreglo_con_direccion(
+        &mut self,
+        nombre_funcion: &str,
+        nombre_variable: &str,
+        tipo: crate::semantico::tipos::TipoDato,
+        direccion: usize,
+        dimensiones: Vec<usize>,
+    ) -> Result<(), Diagnostico> {
+        let funcion = self.funciones.get_mut(nombre_funcion)
+            .ok_or_else(|| Diagnostico::no_declarado(format!(
+                "Error semántico: Función '{}' no existe",
+                nombre_funcion
+            )))?;
+
+        funcion.tabla_variables.agregar_arreglo(nombre_variable, tipo, direccion, dimensiones)
+    }
+
     /// Agrega un parámetro formal a una función específica
     pub fn agregar_parametro_a_funcion(
         &mut self,
         nombre_funcion: &str,
         nombre_parametro: &str,
         _tipo: crate::semantico::tipos::TipoDato,
-    ) -> Result<(), String> {
+    ) -> Result<(), Diagnostico> {
         // Buscar la función
         let funcion = self.funciones.get_mut(nombre_funcion)
-            .ok_or_else(|| format!(
+            .ok_or_else(|| Diagnostico::interno(format!(
                 "Error interno: Función '{}' no existe al agregar parámetro",
                 nombre_funcion
-            ))?;
+            )))?;
 
         // Contar cuántos parámetros ya tiene la función
         let posicion = funcion.tabla_variables.obtener_parametros().len();
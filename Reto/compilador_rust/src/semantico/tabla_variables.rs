@@ -4,6 +4,7 @@
 //! - Proporcionar acceso rápido al tipo de una variable
 
 use crate::semantico::tipos::TipoDato;
+use crate::diagnostico::Diagnostico;
 use std::collections::HashMap;
 
 /// Entrada de una variable en la tabla
@@ -21,6 +22,9 @@ pub struct EntradaVariable {
 
     /// Posición del parámetro (si es parámetro), None si es variable local
     pub posicion_parametro: Option<usize>,
+
+    /// Dimensiones del arreglo (vacío si es escalar, ej. `[3, 4]` para `m[3][4]`)
+    pub dimensiones: Vec<usize>,
 }
 
 impl EntradaVariable {
@@ -31,6 +35,15 @@ impl EntradaVariable {
             direccion,
             es_parametro: false,
             posicion_parametro: None,
+            dimensiones: Vec::new(),
+        }
+    }
+
+    /// Crea una nueva entrada de arreglo con su dirección base y dimensiones
+    pub fn new_arreglo(tipo: TipoDato, direccion: usize, dimensiones: Vec<usize>) -> Self {
+        EntradaVariable {
+            dimensiones,
+            ..EntradaVariable::new(tipo, direccion)
         }
     }
 
@@ -41,8 +54,19 @@ impl EntradaVariable {
             direccion,
             es_parametro: true,
             posicion_parametro: Some(posicion),
+            dimensiones: Vec::new(),
         }
     }
+
+    /// Indica si la variable es un arreglo (tiene al menos una dimensión)
+    pub fn es_arreglo(&self) -> bool {
+        !self.dimensiones.is_empty()
+    }
+
+    /// Número de casillas que ocupa en memoria (1 para escalares)
+    pub fn tamano(&self) -> usize {
+        self.dimensiones.iter().product()
+    }
 }
 
 /// Tabla de Variables
@@ -64,13 +88,13 @@ impl TablaVariables {
 
     /// Agrega una variable a la tabla
     /// Lanza error si la variable ya existe en la tabla (declaración duplicada)
-    pub fn agregar(&mut self, nombre: &str, tipo: TipoDato, direccion: usize) -> Result<(), String> {
+    pub fn agregar(&mut self, nombre: &str, tipo: TipoDato, direccion: usize) -> Result<(), Diagnostico> {
         // Validar que la variable no exista
         if self.variables.contains_key(nombre) {
-            return Err(format!(
+            return Err(Diagnostico::doble_declaracion(format!(
                 "Error semántico: Variable '{}' doblemente declarada",
                 nombre
-            ));
+            )));
         }
 
         // Agregar la variable
@@ -82,13 +106,31 @@ impl TablaVariables {
         Ok(())
     }
 
+    /// Agrega un arreglo a la tabla con su dirección base y dimensiones
+    /// Lanza error si el nombre ya existe en la tabla (declaración duplicada)
+    pub fn agregar_arreglo(&mut self, nombre: &str, tipo: TipoDato, direccion: usize, dimensiones: Vec<usize>) -> Result<(), Diagnostico> {
+        if self.variables.contains_key(nombre) {
+            return Err(Diagnostico::doble_declaracion(format!(
+                "Error semántico: Variable '{}' doblemente declarada",
+                nombre
+            )));
+        }
+
+        self.variables.insert(
+            nombre.to_string(),
+            EntradaVariable::new_arreglo(tipo, direccion, dimensiones),
+        );
+
+        Ok(())
+    }
+
     /// Marca una variable existente como parámetro
-    pub fn marcar_como_parametro(&mut self, nombre: &str, posicion: usize) -> Result<(), String> {
+    pub fn marcar_como_parametro(&mut self, nombre: &str, posicion: usize) -> Result<(), Diagnostico> {
         let entrada = self.variables.get_mut(nombre)
-            .ok_or_else(|| format!(
+            .ok_or_else(|| Diagnostico::interno(format!(
                 "Error interno: Variable '{}' no existe para marcar como parámetro",
                 nombre
-            ))?;
+            )))?;
 
         entrada.es_parametro = true;
         entrada.posicion_parametro = Some(posicion);
@@ -177,17 +219,17 @@ mod tests {
         let mut tabla = TablaVariables::new();
 
         // Agregar variable exitosamente
-        assert!(tabla.agregar("x", TipoDato::Entero).is_ok());
-        assert!(tabla.agregar("y", TipoDato::Flotante).is_ok());
+        assert!(tabla.agregar("x", TipoDato::Entero, 1000).is_ok());
+        assert!(tabla.agregar("y", TipoDato::Flotante, 3000).is_ok());
 
         // Variable duplicada debe fallar
-        assert!(tabla.agregar("x", TipoDato::Entero).is_err());
+        assert!(tabla.agregar("x", TipoDato::Entero, 1000).is_err());
     }
 
     #[test]
     fn test_buscar_variable() {
         let mut tabla = TablaVariables::new();
-        tabla.agregar("contador", TipoDato::Entero).unwrap();
+        tabla.agregar("contador", TipoDato::Entero, 1000).unwrap();
 
         // Buscar variable existente
         let entrada = tabla.buscar("contador");
@@ -201,21 +243,35 @@ mod tests {
     #[test]
     fn test_existe() {
         let mut tabla = TablaVariables::new();
-        tabla.agregar("total", TipoDato::Flotante).unwrap();
+        tabla.agregar("total", TipoDato::Flotante, 3000).unwrap();
 
         assert!(tabla.existe("total"));
         assert!(!tabla.existe("inexistente"));
     }
 
+    #[test]
+    fn test_agregar_arreglo() {
+        let mut tabla = TablaVariables::new();
+        tabla.agregar_arreglo("m", TipoDato::Flotante, 3000, vec![3, 4]).unwrap();
+
+        let entrada = tabla.buscar("m").unwrap();
+        assert!(entrada.es_arreglo());
+        assert_eq!(entrada.tamano(), 12);
+
+        // Nombre duplicado (arreglo o escalar) debe fallar
+        assert!(tabla.agregar("m", TipoDato::Entero, 1000).is_err());
+        assert!(tabla.agregar_arreglo("m", TipoDato::Entero, 1000, vec![2]).is_err());
+    }
+
     #[test]
     fn test_cantidad() {
         let mut tabla = TablaVariables::new();
         assert_eq!(tabla.cantidad(), 0);
 
-        tabla.agregar("a", TipoDato::Entero).unwrap();
+        tabla.agregar("a", TipoDato::Entero, 1000).unwrap();
         assert_eq!(tabla.cantidad(), 1);
 
-        tabla.agregar("b", TipoDato::Flotante).unwrap();
+        tabla.agregar("b", TipoDato::Flotante, 3000).unwrap();
         assert_eq!(tabla.cantidad(), 2);
     }
 }
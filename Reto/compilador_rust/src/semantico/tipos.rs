@@ -12,6 +12,8 @@ pub enum TipoDato {
     Char,
     /// Tipo de dato string/letrero
     Letrero,
+    /// Tipo de dato booleano (resultado de relacionales y lógicos)
+    Booleano,
 }
 
 impl fmt::Display for TipoDato {
@@ -21,18 +23,21 @@ impl fmt::Display for TipoDato {
             TipoDato::Flotante => write!(f, "flotante"),
             TipoDato::Char => write!(f, "char"),
             TipoDato::Letrero => write!(f, "letrero"),
+            TipoDato::Booleano => write!(f, "booleano"),
         }
     }
 }
 
 impl TipoDato {
     /// Convierte una cadena de texto a un TipoDato
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "entero" => Some(TipoDato::Entero),
             "flotante" => Some(TipoDato::Flotante),
             "char" => Some(TipoDato::Char),
             "letrero" => Some(TipoDato::Letrero),
+            "booleano" => Some(TipoDato::Booleano),
             _ => None,
         }
     }
@@ -58,6 +63,7 @@ impl fmt::Display for TipoRetorno {
 
 impl TipoRetorno {
     /// Convierte una cadena de texto a un TipoRetorno
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "nula" => Some(TipoRetorno::Nula),
@@ -75,6 +81,7 @@ mod tests {
         assert_eq!(TipoDato::from_str("entero"), Some(TipoDato::Entero));
         assert_eq!(TipoDato::from_str("flotante"), Some(TipoDato::Flotante));
         assert_eq!(TipoDato::from_str("char"), Some(TipoDato::Char));
+        assert_eq!(TipoDato::from_str("booleano"), Some(TipoDato::Booleano));
         assert_eq!(TipoDato::from_str("invalido"), None);
     }
 
@@ -2,9 +2,9 @@
 // Este módulo contiene la lógica de acciones semánticas para el parser SLR bottom-up
 // Cada producción tiene una acción específica basada en (cabeza, longitud_cuerpo)
 
-use crate::lexico::token::Token;
 use crate::semantico::{ContextoSemantico, TipoDato};
 use crate::intermedio::GeneradorCuadruplos;
+use crate::diagnostico::{Diagnostico, Span};
 
 /// Ejecuta la acción semántica asociada a una reducción en el parser SLR
 ///
@@ -15,7 +15,8 @@ use crate::intermedio::GeneradorCuadruplos;
 /// * `cabeza` - Cabeza de la regla de producción (lado izquierdo)
 /// * `cuerpo_len` - Longitud del cuerpo de la producción (lado derecho)
 /// * `atributos` - Atributos semánticos de los símbolos del lado derecho
-/// * `_tokens` - Vector de tokens completo (no usado actualmente)
+/// * `spans` - Ubicación de cada símbolo del lado derecho (para los errores que
+///   señalan un identificador; el resto se ubica en la producción completa)
 /// * `contexto` - Contexto semántico para tracking de variables/funciones
 /// * `generador` - Generador de cuádruplos para código intermedio
 /// * `nivel_verbose` - Nivel de debug (0=ninguno, 1=básico, 2=semántica, 3=completo)
@@ -27,16 +28,21 @@ pub fn ejecutar_accion_semantica(
     cabeza: &str,
     cuerpo_len: usize,
     atributos: &[String],
-    _tokens: &[Token],
+    spans: &[Option<Span>],
     contexto: &mut ContextoSemantico,
     generador: &mut GeneradorCuadruplos,
     nivel_verbose: usize,
-) -> Result<String, String> {
+) -> Result<String, Diagnostico> {
 
     if nivel_verbose >= 3 {
         println!("[DEBUG] Acción semántica: cabeza='{}', len={}, atributos={:?}", cabeza, cuerpo_len, atributos);
     }
 
+    // Un literal 2147483648 solo es válido si la reducción que sigue es su `-` unario
+    if !(cabeza == "<FACTOR>" && cuerpo_len == 2 && atributos.first().map(|s| s.as_str()) == Some("-")) {
+        generador.verificar_literal_sin_signo()?;
+    }
+
     // Match directo sobre (cabeza, longitud del cuerpo)
     // Cada patrón corresponde a una producción específica de la gramática
     match (cabeza, cuerpo_len) {
@@ -57,13 +63,16 @@ pub fn ejecutar_accion_semantica(
 
         // ==================== VARIABLES ====================
 
-        // <TIPO> → entero | flotante
+        // <TIPO> → entero | flotante | char | letrero | booleano
         ("<TIPO>", 1) => {
             if let Some(tipo_str) = atributos.first() {
                 let tipo = match tipo_str.as_str() {
                     "entero" => TipoDato::Entero,
                     "flotante" => TipoDato::Flotante,
-                    _ => return Err(format!("Tipo desconocido: {}", tipo_str)),
+                    "char" => TipoDato::Char,
+                    "letrero" => TipoDato::Letrero,
+                    "booleano" => TipoDato::Booleano,
+                    _ => return Err(Diagnostico::interno(format!("Tipo desconocido: {}", tipo_str))),
                 };
                 if nivel_verbose >= 2 {
                     println!("[SEMANTICA] Estableciendo tipo actual: {:?}", tipo);
@@ -75,12 +84,12 @@ pub fn ejecutar_accion_semantica(
             }
         }
 
-        // <VAR_LIST> → id <VAR_LIST_PRIMA>
-        ("<VAR_LIST>", 2) => {
-            let mut nombres = atributos[0].clone();
-            if !atributos[1].is_empty() {
+        // <VAR_LIST> → id <DIM_DECL> <VAR_LIST_PRIMA>
+        ("<VAR_LIST>", 3) => {
+            let mut nombres = format!("{}{}", atributos[0], atributos[1]);
+            if !atributos[2].is_empty() {
                 nombres.push(',');
-                nombres.push_str(&atributos[1]);
+                nombres.push_str(&atributos[2]);
             }
             if nivel_verbose >= 3 {
                 println!("[SEMANTICA] VAR_LIST acumulado: '{}'", nombres);
@@ -88,12 +97,12 @@ pub fn ejecutar_accion_semantica(
             Ok(nombres)
         }
 
-        // <VAR_LIST_PRIMA> → , id <VAR_LIST_PRIMA>
-        ("<VAR_LIST_PRIMA>", 3) => {
-            let mut nombres = atributos[1].clone();
-            if !atributos[2].is_empty() {
+        // <VAR_LIST_PRIMA> → , id <DIM_DECL> <VAR_LIST_PRIMA>
+        ("<VAR_LIST_PRIMA>", 4) => {
+            let mut nombres = format!("{}{}", atributos[1], atributos[2]);
+            if !atributos[3].is_empty() {
                 nombres.push(',');
-                nombres.push_str(&atributos[2]);
+                nombres.push_str(&atributos[3]);
             }
             if nivel_verbose >= 3 {
                 println!("[SEMANTICA] VAR_LIST_PRIMA acumulado: '{}'", nombres);
@@ -104,6 +113,15 @@ pub fn ejecutar_accion_semantica(
         // <VAR_LIST'> → ε
         ("<VAR_LIST'>", 0) => Ok(String::new()),
 
+        // <DIM_DECL> → [ cte_ent ]
+        ("<DIM_DECL>", 3) => Ok(format!("[{}]", atributos[1])),
+
+        // <DIM_DECL> → [ cte_ent ] [ cte_ent ]
+        ("<DIM_DECL>", 6) => Ok(format!("[{}][{}]", atributos[1], atributos[4])),
+
+        // <DIM_DECL> → ε
+        ("<DIM_DECL>", 0) => Ok(String::new()),
+
         // <VARS> → vars <VAR_LIST> : <TIPO> ;
         ("<VARS>", 5) => {
             if nivel_verbose >= 2 {
@@ -122,11 +140,18 @@ pub fn ejecutar_accion_semantica(
                     println!("[SEMANTICA] Variables a agregar: {:?}", nombres);
                 }
 
-                for nombre_var in nombres {
+                for declaracion in nombres {
+                    let (nombre_var, dimensiones) = separar_dimensiones(declaracion)
+                        .map_err(|e| e.con_span_por_omision(spans[1]))?;
                     if contexto.buscar_variable(nombre_var).is_some() {
-                        return Err(format!("Error: Declaración múltiple de variable '{}'", nombre_var));
+                        return Err(Diagnostico::doble_declaracion(format!("Error: Declaración múltiple de variable '{}'", nombre_var))
+                            .con_span_por_omision(spans[1]));
                     }
-                    contexto.agregar_variable(nombre_var)?;
+                    if dimensiones.is_empty() {
+                        contexto.agregar_variable(nombre_var)
+                    } else {
+                        contexto.agregar_arreglo(nombre_var, dimensiones)
+                    }.map_err(|e| e.con_span_por_omision(spans[1]))?;
                     if nivel_verbose >= 3 {
                         println!("[SEMANTICA] Variable '{}' agregada exitosamente", nombre_var);
                     }
@@ -138,7 +163,7 @@ pub fn ejecutar_accion_semantica(
         // ==================== FUNCIONES (con reglas intermedias) ====================
 
         // <TIPO_OPT> → nula
-        ("<TIPO_OPT>", 1) if atributos.get(0) == Some(&"nula".to_string()) => {
+        ("<TIPO_OPT>", 1) if atributos.first() == Some(&"nula".to_string()) => {
             if nivel_verbose >= 2 {
                 println!("[SEMANTICA] Tipo de retorno: nula");
             }
@@ -167,11 +192,12 @@ pub fn ejecutar_accion_semantica(
                 crate::semantico::TipoRetorno::Nula
             } else {
                 let tipo = TipoDato::from_str(tipo_retorno_str)
-                    .ok_or_else(|| format!("Tipo desconocido: {}", tipo_retorno_str))?;
+                    .ok_or_else(|| Diagnostico::interno(format!("Tipo desconocido: {}", tipo_retorno_str)))?;
                 crate::semantico::TipoRetorno::Tipo(tipo)
             };
 
-            contexto.iniciar_funcion(nombre_func, tipo_retorno)?;
+            contexto.iniciar_funcion(nombre_func, tipo_retorno)
+                .map_err(|e| e.con_span_por_omision(spans[1]))?;
 
             if nivel_verbose >= 2 {
                 println!("[SEMANTICA] Función '{}' creada", nombre_func);
@@ -191,8 +217,9 @@ pub fn ejecutar_accion_semantica(
 
             // Agregar el parámetro a la función actual
             let tipo = TipoDato::from_str(tipo_str)
-                .ok_or_else(|| format!("Tipo desconocido: {}", tipo_str))?;
-            contexto.agregar_parametro(nombre_param, tipo)?;
+                .ok_or_else(|| Diagnostico::interno(format!("Tipo desconocido: {}", tipo_str)))?;
+            contexto.agregar_parametro(nombre_param, tipo)
+                .map_err(|e| e.con_span_por_omision(spans[0]))?;
 
             if nivel_verbose >= 3 {
                 println!("[SEMANTICA] Parámetro '{}' agregado", nombre_param);
@@ -280,55 +307,34 @@ pub fn ejecutar_accion_semantica(
 
         // ==================== EXPRESIONES ====================
 
-        // <CTE_OPT> → id | <CTE>
-        ("<CTE_OPT>", 1) => {
-            if let Some(operando) = atributos.first() {
-                if !operando.is_empty() {
-                    if nivel_verbose >= 3 {
-                        println!("[SEMANTICA] Procesando operando: '{}'", operando);
-                    }
-                    generador.procesar_operando(operando)?;
-                    Ok(operando.clone())
-                } else {
-                    Ok(String::new())
-                }
-            } else {
-                Ok(String::new())
-            }
-        }
-
-        // <CTE> → cte_ent | cte_flot
+        // <CTE> → cte_ent | cte_flot | cte_char | cte_letrero
         ("<CTE>", 1) => {
             Ok(atributos.first().unwrap_or(&String::new()).clone())
         }
 
-        // <*/> → * | /
-        ("<*/>", 1) => {
-            if let Some(op) = atributos.first() {
-                if nivel_verbose >= 3 {
-                    println!("[SEMANTICA] Procesando operador mult/div: '{}'", op);
-                }
-                generador.procesar_mult_div(op)?;
-                Ok(op.clone())
-            } else {
-                Ok(String::new())
+        // <EXP> → <EXP> + <EXP> | <EXP> - <EXP>
+        // <EXP> → <EXP> * <EXP> | <EXP> / <EXP> | <EXP> % <EXP>
+        // La precedencia y la asociatividad (izquierda) vienen de `%left` en gramatica.txt:
+        // al reducir, ambos operandos ya están en PilaO
+        ("<EXP>", 3) => {
+            let operador = atributos[1].as_str();
+            if nivel_verbose >= 3 {
+                println!("[SEMANTICA] Generando cuádruplo aritmético: '{}'", operador);
             }
-        }
-
-        // <+-> → + | -
-        ("<+->", 1) => {
-            if let Some(op) = atributos.first() {
-                if nivel_verbose >= 3 {
-                    println!("[SEMANTICA] Procesando operador suma/resta: '{}'", op);
+            match operador {
+                "+" | "-" => {
+                    generador.procesar_suma_resta(operador)?;
+                    generador.generar_suma_resta()?;
+                }
+                _ => {
+                    generador.procesar_mult_div(operador)?;
+                    generador.generar_mult_div()?;
                 }
-                generador.procesar_suma_resta(op)?;
-                Ok(op.clone())
-            } else {
-                Ok(String::new())
             }
+            Ok(String::new())
         }
 
-        // <OPERADOR> → > | < | == | !=
+        // <OPERADOR> → > | < | >= | <= | == | !=
         ("<OPERADOR>", 1) => {
             if let Some(op) = atributos.first() {
                 if nivel_verbose >= 3 {
@@ -341,54 +347,86 @@ pub fn ejecutar_accion_semantica(
             }
         }
 
-        // <EXP_PRIMA> → <+-> <TÉRMINO> <EXP_PRIMA>
-        ("<EXP_PRIMA>", 3) => {
-            if nivel_verbose >= 3 {
-                println!("[SEMANTICA] Generando cuádruplo suma/resta");
-            }
-            generador.generar_suma_resta()?;
-            Ok(String::new())
-        }
-
-        // <EXP_PRIMA> → ε
-        ("<EXP_PRIMA>", 0) => Ok(String::new()),
-
-        // <EXPRESION_PRIMA> → <OPERADOR> <EXP>
-        ("<EXPRESION_PRIMA>", 2) => {
+        // <EXPRESION_PRIMA> → <OPERADOR> <EXP> <EXPRESION_PRIMA>
+        // Una cola no vacía (comparación encadenada) ya la rechazó
+        // `procesar_relacional` al reducir su <OPERADOR>
+        ("<EXPRESION_PRIMA>", 3) => {
             if nivel_verbose >= 3 {
                 println!("[SEMANTICA] Generando cuádruplo relacional");
             }
             generador.generar_relacional()?;
-            Ok(String::new())
+            Ok(atributos.first().cloned().unwrap_or_default())
         }
 
         // <EXPRESION_PRIMA> → ε
         ("<EXPRESION_PRIMA>", 0) => Ok(String::new()),
 
-        // <TERMINO_PRIMA> → <*/> <FACTOR> <TERMINO_PRIMA>
-        ("<TERMINO_PRIMA>", 3) => {
+        // ==================== OPERADORES LÓGICOS ====================
+        // Los saltos de cortocircuito se generan al leer `y`/`o` (ver sintactico::analyze);
+        // las listas `_PRIMA` sintetizan cuántos operadores tiene la cadena para cerrarla
+
+        // <EXP_O_PRIMA> → o <EXP_Y> <EXP_O_PRIMA>
+        // <EXP_Y_PRIMA> → y <EXP_NO> <EXP_Y_PRIMA>
+        ("<EXP_O_PRIMA>", 3) | ("<EXP_Y_PRIMA>", 3) => {
+            let num_operadores = atributos[2].parse::<usize>().unwrap_or(0) + 1;
+            Ok(num_operadores.to_string())
+        }
+
+        // <EXP_O_PRIMA> → ε
+        // <EXP_Y_PRIMA> → ε
+        ("<EXP_O_PRIMA>", 0) | ("<EXP_Y_PRIMA>", 0) => Ok(String::new()),
+
+        // <EXPRESIÓN> → <EXP_Y> <EXP_O_PRIMA>
+        ("<EXPRESIÓN>", 2) => match atributos[1].parse::<usize>() {
+            Ok(num_operadores) => {
+                if nivel_verbose >= 3 {
+                    println!("[SEMANTICA] Cerrando cadena de {} operador(es) 'o'", num_operadores);
+                }
+                generador.generar_fin_o(num_operadores)?;
+                Ok(String::new())
+            }
+            Err(_) => Ok(atributos[0].clone()),
+        },
+
+        // <EXP_Y> → <EXP_NO> <EXP_Y_PRIMA>
+        ("<EXP_Y>", 2) => match atributos[1].parse::<usize>() {
+            Ok(num_operadores) => {
+                if nivel_verbose >= 3 {
+                    println!("[SEMANTICA] Cerrando cadena de {} operador(es) 'y'", num_operadores);
+                }
+                generador.generar_fin_y(num_operadores)?;
+                Ok(String::new())
+            }
+            Err(_) => Ok(atributos[0].clone()),
+        },
+
+        // <EXP_NO> → no <EXP_NO>
+        ("<EXP_NO>", 2) => {
             if nivel_verbose >= 3 {
-                println!("[SEMANTICA] Generando cuádruplo mult/div");
+                println!("[SEMANTICA] Generando cuádruplo de negación");
             }
-            generador.generar_mult_div()?;
+            generador.generar_no()?;
             Ok(String::new())
         }
 
-        // <TERMINO_PRIMA> → ε
-        ("<TERMINO_PRIMA>", 0) => Ok(String::new()),
-
         // <FACTOR> → ( <EXPRESIÓN> )
-        ("<FACTOR>", 3) if atributos.get(0).map(|s| s.as_str()) == Some("(") => {
+        ("<FACTOR>", 3) if atributos.first().map(|s| s.as_str()) == Some("(") => {
             if nivel_verbose >= 3 {
                 println!("[SEMANTICA] Expresión entre paréntesis - precedencia manejada por gramática");
             }
-            // No necesitamos abrir_parentesis/cerrar_parentesis porque el parser SLR
-            // ya maneja la precedencia correctamente a través de la estructura de la gramática
+            // La precedencia la da la gramática; el fondo falso de POper se pone y
+            // se quita al desplazar `(` y `)` (ver sintactico::analyze)
             Ok(String::new())
         }
 
+        // <FACTOR> → <ACCESO_ARREGLO>
+        ("<FACTOR>", 1) if atributos.first().map(|s| s.ends_with("[]")) == Some(true) => {
+            // El apuntador al elemento ya quedó en PilaO al reducir el acceso
+            Ok(atributos[0].clone())
+        }
+
         // <FACTOR> → id
-        ("<FACTOR>", 1) if atributos.get(0).map(|s| {
+        ("<FACTOR>", 1) if atributos.first().map(|s| {
             let first_opt = s.chars().next();
             !s.is_empty() && !s.starts_with('+') && !s.starts_with('-') && first_opt.map(|c| c.is_alphabetic()).unwrap_or(false)
         }) == Some(true) => {
@@ -401,7 +439,7 @@ pub fn ejecutar_accion_semantica(
         }
 
         // <FACTOR> → <CTE>
-        ("<FACTOR>", 1) if atributos.get(0).map(|s| s.chars().next().map(|c| c.is_numeric()).unwrap_or(false)) == Some(true) => {
+        ("<FACTOR>", 1) if atributos.first().map(|s| s == "verdadero" || s == "falso" || s.chars().next().map(|c| c.is_numeric() || c == '\'' || c == '"').unwrap_or(false)) == Some(true) => {
             let operando = &atributos[0];
             if nivel_verbose >= 3 {
                 println!("[SEMANTICA] Procesando operando <CTE>: '{}'", operando);
@@ -410,26 +448,34 @@ pub fn ejecutar_accion_semantica(
             Ok(operando.clone())
         }
 
-        // <FACTOR> → + <CTE_OPT>
-        ("<FACTOR>", 2) if atributos.get(0).map(|s| s.as_str()) == Some("+") => {
+        // <FACTOR> → + <FACTOR>
+        ("<FACTOR>", 2) if atributos.first().map(|s| s.as_str()) == Some("+") => {
+            // El factor ya quedó en PilaO; el signo + solo exige que sea numérico
             let operando = &atributos[1];
             if nivel_verbose >= 3 {
-                println!("[SEMANTICA] Procesando operando positivo: '+{}'", operando);
+                println!("[SEMANTICA] Operando positivo: '+{}'", operando);
             }
-            generador.procesar_operando(operando)?;
+            generador.generar_positivo()?;
             Ok(operando.clone())
         }
 
-        // <FACTOR> → - <CTE_OPT>
-        ("<FACTOR>", 2) if atributos.get(0).map(|s| s.as_str()) == Some("-") => {
+        // <FACTOR> → - <FACTOR>
+        ("<FACTOR>", 2) if atributos.first().map(|s| s.as_str()) == Some("-") => {
             let operando = &atributos[1];
             if nivel_verbose >= 3 {
                 println!("[SEMANTICA] Procesando operando negativo: '-{}'", operando);
             }
-            // Para negativos, podríamos generar una operación de negación
-            // Por ahora, solo procesamos el operando
-            generador.procesar_operando(operando)?;
-            Ok(format!("-{}", operando))
+            // El factor ya quedó en PilaO; se reemplaza por su negativo. El atributo
+            // solo conserva el literal si el resultado sigue siendo una constante
+            generador.generar_negativo(operando)?;
+            if operando.parse::<f64>().is_ok() {
+                Ok(match operando.strip_prefix('-') {
+                    Some(positivo) => positivo.to_string(),
+                    None => format!("-{}", operando),
+                })
+            } else {
+                Ok(String::new())
+            }
         }
 
         // <FACTOR> → <LLAMADA>
@@ -438,8 +484,69 @@ pub fn ejecutar_accion_semantica(
             Ok(atributos.first().unwrap_or(&String::new()).clone())
         }
 
+        // ==================== ARREGLOS ====================
+
+        // <ACCESO_ARREGLO> → id [ <EXPRESIÓN> ]
+        ("<ACCESO_ARREGLO>", 4) => {
+            if nivel_verbose >= 2 {
+                println!("[SEMANTICA] Acceso a arreglo 1D: '{}'", atributos[0]);
+            }
+            generador.generar_acceso_arreglo(&atributos[0], 1)?;
+            Ok(format!("{}[]", atributos[0]))
+        }
+
+        // <ACCESO_ARREGLO> → id [ <EXPRESIÓN> ] [ <EXPRESIÓN> ]
+        ("<ACCESO_ARREGLO>", 7) => {
+            if nivel_verbose >= 2 {
+                println!("[SEMANTICA] Acceso a arreglo 2D: '{}'", atributos[0]);
+            }
+            generador.generar_acceso_arreglo(&atributos[0], 2)?;
+            Ok(format!("{}[]", atributos[0]))
+        }
+
         // ==================== ASIGNACIÓN ====================
 
+        // <ASIGNA_OP> → += | -= | *= | /= | %=
+        // <INC_DEC> → ++ | --
+        ("<ASIGNA_OP>", 1) | ("<INC_DEC>", 1) => Ok(atributos[0].clone()),
+
+        // <ASIGNA> → id <ASIGNA_OP> <EXPRESIÓN> ;
+        // <ASIGNA> → <ACCESO_ARREGLO> <ASIGNA_OP> <EXPRESIÓN> ;
+        ("<ASIGNA>", 4) if atributos[1] != "=" => {
+            if nivel_verbose >= 2 {
+                println!("[SEMANTICA] Generando asignación compuesta: {} {}", atributos[0], atributos[1]);
+            }
+            if !atributos[0].ends_with("[]") && contexto.buscar_variable(&atributos[0]).is_none() {
+                return Err(Diagnostico::no_declarado(format!("Error: Variable '{}' no declarada", atributos[0]))
+                    .con_span_por_omision(spans[0]));
+            }
+            generador.generar_asignacion_compuesta(&atributos[0], &atributos[1])?;
+            Ok(String::new())
+        }
+
+        // <ASIGNA> → id <INC_DEC> ;
+        // <ASIGNA> → <ACCESO_ARREGLO> <INC_DEC> ;
+        ("<ASIGNA>", 3) => {
+            if nivel_verbose >= 2 {
+                println!("[SEMANTICA] Generando incremento: {}{}", atributos[0], atributos[1]);
+            }
+            if !atributos[0].ends_with("[]") && contexto.buscar_variable(&atributos[0]).is_none() {
+                return Err(Diagnostico::no_declarado(format!("Error: Variable '{}' no declarada", atributos[0]))
+                    .con_span_por_omision(spans[0]));
+            }
+            generador.generar_incremento(&atributos[0], &atributos[1])?;
+            Ok(String::new())
+        }
+
+        // <ASIGNA> → <ACCESO_ARREGLO> = <EXPRESIÓN> ;
+        ("<ASIGNA>", 4) if atributos[0].ends_with("[]") => {
+            if nivel_verbose >= 2 {
+                println!("[SEMANTICA] Generando asignación a elemento de arreglo: {}", atributos[0]);
+            }
+            generador.generar_asignacion_arreglo()?;
+            Ok(String::new())
+        }
+
         // <ASIGNA> → id = <EXPRESIÓN> ;
         ("<ASIGNA>", 4) => {
             if nivel_verbose >= 2 {
@@ -448,7 +555,8 @@ pub fn ejecutar_accion_semantica(
 
             if let Some(nombre_var) = atributos.first() {
                 if contexto.buscar_variable(nombre_var).is_none() {
-                    return Err(format!("Error: Variable '{}' no declarada", nombre_var));
+                    return Err(Diagnostico::no_declarado(format!("Error: Variable '{}' no declarada", nombre_var))
+                        .con_span_por_omision(spans[0]));
                 }
                 if nivel_verbose >= 2 {
                     println!("[SEMANTICA] Asignación a variable: '{}'", nombre_var);
@@ -461,18 +569,53 @@ pub fn ejecutar_accion_semantica(
         // ==================== IMPRESIÓN ====================
 
         // <IMPRIME> → escribe ( <OBJ_IMPRIME> <IMPRIME_LIST> ) ;
-        ("<IMPRIME>", 6) => {
+        // Cada <OBJ_IMPRIME> ya generó su escritura (se reducen de izquierda a derecha)
+        ("<IMPRIME>", 6) => Ok(String::new()),
+
+        // ==================== LECTURA ====================
+
+        // <LEE_LIST> → , id <LEE_LIST>
+        ("<LEE_LIST>", 3) => {
+            let mut nombres = atributos[1].clone();
+            if !atributos[2].is_empty() {
+                nombres.push(',');
+                nombres.push_str(&atributos[2]);
+            }
+            if nivel_verbose >= 3 {
+                println!("[SEMANTICA] LEE_LIST acumulado: '{}'", nombres);
+            }
+            Ok(nombres)
+        }
+
+        // <LEE_LIST> → ε
+        ("<LEE_LIST>", 0) => Ok(String::new()),
+
+        // <LEE> → lee ( id <LEE_LIST> ) ;
+        ("<LEE>", 6) => {
+            let mut nombres = atributos[2].clone();
+            if !atributos[3].is_empty() {
+                nombres.push(',');
+                nombres.push_str(&atributos[3]);
+            }
             if nivel_verbose >= 2 {
-                println!("[SEMANTICA] Generando escritura");
+                println!("[SEMANTICA] Generando lectura de: '{}'", nombres);
+            }
+
+            // Un cuádruplo de lectura por variable, en el orden escrito
+            for nombre_var in nombres.split(',') {
+                if contexto.buscar_variable(nombre_var).is_none() {
+                    return Err(Diagnostico::no_declarado(format!("Error: Variable '{}' no declarada", nombre_var)));
+                }
+                generador.generar_lectura(nombre_var)?;
             }
-            generador.generar_escritura()?;
             Ok(String::new())
         }
 
         // ==================== CONTROL ====================
 
-        // <CONDICIÓN> → si ( <EXPRESIÓN> ) entonces <CUERPO> <SINO_OPT> ;
-        ("<CONDICIÓN>", 7) => {
+        // <CONDICIÓN> → si ( <EXPRESIÓN> ) entonces <CUERPO> <SINO_OPT> [;]
+        // El `;` final es opcional
+        ("<CONDICIÓN>", 7) | ("<CONDICIÓN>", 6) => {
             if nivel_verbose >= 3 {
                 println!("[SEMANTICA] <CONDICIÓN> completa parseada");
             }
@@ -488,6 +631,18 @@ pub fn ejecutar_accion_semantica(
             Ok(String::new())
         }
 
+        // <SINO_OPT> → sino si ( <EXPRESIÓN> ) entonces <CUERPO> <SINO_OPT>
+        // El `si` anidado es el cuerpo del else: su SINO_OPT se reduce primero y
+        // rellena sus saltos, así que todos los GOTO de salida de la cadena
+        // apuntan al mismo cuádruplo final sin bloques ni saltos intermedios
+        ("<SINO_OPT>", 8) => {
+            if nivel_verbose >= 2 {
+                println!("[SEMANTICA] Cadena sino si: generando GOTO y FILL");
+            }
+            generador.generar_else()?;
+            Ok(String::new())
+        }
+
         // <SINO_OPT> → ε
         ("<SINO_OPT>", 0) => {
             if nivel_verbose >= 2 {
@@ -503,6 +658,74 @@ pub fn ejecutar_accion_semantica(
                 println!("[SEMANTICA] Ciclo: generando GOTO y rellenando salto");
             }
             generador.generar_fin_ciclo()?;
+            contexto.salir_ciclo();
+            Ok(String::new())
+        }
+
+        // <ROMPE> → rompe ;
+        ("<ROMPE>", 2) => {
+            contexto.validar_en_ciclo("rompe")?;
+            if nivel_verbose >= 2 {
+                println!("[SEMANTICA] rompe: generando GOTO al final del ciclo");
+            }
+            generador.generar_rompe()?;
+            Ok(String::new())
+        }
+
+        // <CONTINUA> → continua ;
+        ("<CONTINUA>", 2) => {
+            contexto.validar_en_ciclo("continua")?;
+            if nivel_verbose >= 2 {
+                println!("[SEMANTICA] continua: generando GOTO a la siguiente iteración");
+            }
+            generador.generar_continua()?;
+            Ok(String::new())
+        }
+
+        // <PARA_INICIO> → para id = <EXPRESIÓN>
+        ("<PARA_INICIO>", 4) => {
+            let variable = &atributos[1];
+            if nivel_verbose >= 2 {
+                println!("[SEMANTICA] Ciclo para: asignación inicial de '{}'", variable);
+            }
+            if contexto.buscar_variable(variable).is_none() {
+                return Err(Diagnostico::no_declarado(format!("Error: Variable '{}' no declarada", variable))
+                    .con_span_por_omision(spans[1]));
+            }
+            generador.iniciar_para(variable)
+                .map_err(|e| e.con_span_por_omision(spans[1]))?;
+            Ok(variable.clone())
+        }
+
+        // <PARA_LIMITE> → <PARA_INICIO> hasta <EXPRESIÓN>
+        ("<PARA_LIMITE>", 3) => {
+            generador.generar_limite_para()?;
+            Ok(atributos[0].clone())
+        }
+
+        // <PASO_OPT> → paso <EXPRESIÓN>
+        ("<PASO_OPT>", 2) => Ok("paso".to_string()),
+
+        // <PASO_OPT> → ε
+        ("<PASO_OPT>", 0) => Ok(String::new()),
+
+        // <PARA_CONTROL> → <PARA_LIMITE> <PASO_OPT>
+        // Deja la condición en PilaO; el GOTOF se genera al leer `haz`
+        ("<PARA_CONTROL>", 2) => {
+            if nivel_verbose >= 2 {
+                println!("[SEMANTICA] Ciclo para: generando condición (paso explícito: {})", !atributos[1].is_empty());
+            }
+            generador.generar_control_para(!atributos[1].is_empty())?;
+            Ok(atributos[0].clone())
+        }
+
+        // <CICLO_PARA> → <PARA_CONTROL> haz <CUERPO> ;
+        ("<CICLO_PARA>", 4) => {
+            if nivel_verbose >= 2 {
+                println!("[SEMANTICA] Ciclo para '{}': generando incremento, GOTO y rellenando salto", atributos[0]);
+            }
+            generador.generar_fin_para()?;
+            contexto.salir_ciclo();
             Ok(String::new())
         }
 
@@ -528,9 +751,9 @@ pub fn ejecutar_accion_semantica(
             Ok(String::new())
         }
 
-        ("<EXPRESIÓN>", 2) |
-        ("<EXP>", 2) |
-        ("<TÉRMINO>", 2) |
+        ("<EXP_NO>", 1) |
+        ("<EXP_REL>", 2) |
+        ("<EXP>", 1) |
         ("<ESTATUTO>", 1) |
         ("<VARS_OPT>", _) |
         ("<FUNCS_LIST>", _) |
@@ -551,19 +774,13 @@ pub fn ejecutar_accion_semantica(
             Ok(String::new())
         }
 
-        // <OBJ_IMPRIME> → letrero
-        ("<OBJ_IMPRIME>", 1) if atributos.get(0).map(|s| s.starts_with('"')) == Some(true) => {
-            let letrero = &atributos[0];
-            if nivel_verbose >= 3 {
-                println!("[SEMANTICA] Procesando letrero para impresión: {}", letrero);
-            }
-            generador.procesar_operando(letrero)?;
-            Ok(letrero.clone())
-        }
-
         // <OBJ_IMPRIME> → <EXPRESIÓN>
         ("<OBJ_IMPRIME>", 1) => {
-            // Propagación para expresión
+            if nivel_verbose >= 2 {
+                println!("[SEMANTICA] Generando escritura");
+            }
+            // El resultado de la expresión está en el tope de PilaO
+            generador.generar_escritura()?;
             Ok(atributos.first().unwrap_or(&String::new()).clone())
         }
 
@@ -577,3 +794,24 @@ pub fn ejecutar_accion_semantica(
         }
     }
 }
+
+/// Separa una declaración acumulada en `<VAR_LIST>` en nombre y dimensiones
+/// Ejemplo: `"m[3][4]"` → `("m", [3, 4])`, `"x"` → `("x", [])`
+fn separar_dimensiones(declaracion: &str) -> Result<(&str, Vec<usize>), Diagnostico> {
+    let Some(pos) = declaracion.find('[') else {
+        return Ok((declaracion, Vec::new()));
+    };
+
+    let nombre = &declaracion[..pos];
+    let dimensiones = declaracion[pos..]
+        .split(']')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.trim_start_matches('[')
+                .parse::<usize>()
+                .map_err(|_| Diagnostico::arreglo_invalido(format!("Error: Dimensión inválida '{}' en el arreglo '{}'", s, nombre)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((nombre, dimensiones))
+}
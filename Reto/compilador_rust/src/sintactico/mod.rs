@@ -1,12 +1,23 @@
-pub mod tabla_slr;
+/// Tablas ACTION/GOTO y producciones, generadas por build.rs a partir de gramatica.txt
+pub mod tabla_slr {
+    include!(concat!(env!("OUT_DIR"), "/tabla_slr.rs"));
+}
 mod acciones_semanticas;
 
-use crate::lexico::token::Token;
+use crate::lexico::token::{Token, TipoToken};
 use crate::sintactico::tabla_slr::*;
 use crate::semantico::ContextoSemantico;
-use crate::intermedio::GeneradorCuadruplos;
+use crate::intermedio::{DistribucionMemoria, GeneradorCuadruplos};
+use crate::diagnostico::{codigos, Diagnostico, Span};
 use acciones_semanticas::ejecutar_accion_semantica;
 
+/// Terminales donde se sincroniza la recuperación en modo pánico
+const TOKENS_SINCRONIZACION: [&str; 3] = [";", "}", "fin"];
+
+/// No-terminales cuyos estados sirven como punto de reanudación tras un error
+/// (inicio de estatuto, de declaración de variables o de función)
+const NO_TERMINALES_SINCRONIZACION: [&str; 3] = ["<ESTATUTO>", "<VARS>", "<FUNCS>"];
+
 /// Analiza una secuencia de tokens usando el analizador sintáctico SLR bottom-up
 ///
 /// Este parser utiliza tablas ACTION y GOTO generadas por el algoritmo SLR(1).
@@ -17,26 +28,55 @@ use acciones_semanticas::ejecutar_accion_semantica;
 /// - `tokens`: Secuencia de tokens del análisis léxico
 /// - `nivel_verbose`: Nivel de debug (0=ninguno, 1=básico, 2=detallado, 3=completo)
 ///
-/// Retorna el generador de cuádruplos para permitir la ejecución en la VM.
-pub fn analyze(tokens: &[Token], nivel_verbose: usize) -> Result<GeneradorCuadruplos, String> {
+/// # Recuperación de errores
+/// Ante un error de sintaxis se usa modo pánico: se descartan tokens hasta uno
+/// de sincronización (`;`, `}` o `fin`) y se sacan estados de la pila hasta uno
+/// que pueda continuar, de modo que se reportan todos los errores en una sola
+/// pasada. Tras el primer error (sintáctico o semántico) ya no se ejecutan
+/// acciones semánticas para no generar errores en cascada.
+///
+/// Retorna el generador de cuádruplos para permitir la ejecución en la VM,
+/// o todos los `Diagnostico` encontrados, en orden de aparición.
+pub fn analyze(tokens: &[Token], nivel_verbose: usize) -> Result<GeneradorCuadruplos, Vec<Diagnostico>> {
+    analyze_con_distribucion(tokens, nivel_verbose, DistribucionMemoria::default())
+}
+
+/// Igual que `analyze`, pero asigna direcciones con otra distribución de memoria
+/// (la misma debe usar la VM que ejecute el programa)
+pub fn analyze_con_distribucion(
+    tokens: &[Token],
+    nivel_verbose: usize,
+    distribucion: DistribucionMemoria,
+) -> Result<GeneradorCuadruplos, Vec<Diagnostico>> {
     let mut pila_estados: Vec<usize> = vec![0]; // Pila de estados, inicia en estado 0
     let mut cursor = 0; // Posición actual en el vector de tokens
-    let mut contexto = ContextoSemantico::new();
-    let mut generador = GeneradorCuadruplos::new();
+    let mut contexto = ContextoSemantico::con_distribucion(distribucion);
+    let mut generador = GeneradorCuadruplos::con_distribucion(distribucion);
 
     // Inicializar programa con nombre temporal
-    contexto.inicializar_programa("__programa_temp__")?;
+    contexto.inicializar_programa("__programa_temp__")
+        .map_err(|e| vec![e])?;
 
     // Establecer contexto en el generador
     generador.establecer_contexto(&contexto);
 
     // Generar GOTO al inicio del programa principal (saltar funciones)
     // Este GOTO se hará FILL cuando se encuentre el token 'inicio'
-    generador.generar_goto_inicio()?;
+    generador.generar_goto_inicio()
+        .map_err(|e| vec![e])?;
 
     // Pila semántica para tracking de atributos
     let mut pila_semantica: Vec<String> = Vec::new();
 
+    // Pila de spans paralela a la semántica (None para producciones vacías)
+    let mut pila_spans: Vec<Option<Span>> = Vec::new();
+
+    // Errores acumulados; si no está vacío se suprimen las acciones semánticas
+    let mut errores: Vec<Diagnostico> = Vec::new();
+
+    // true tras una recuperación, hasta que se desplace un token
+    let mut recuperando = false;
+
     if nivel_verbose >= 1 {
         println!("\n=== Iniciando análisis sintáctico SLR ===");
         println!("Total de tokens: {}\n", tokens.len());
@@ -73,44 +113,84 @@ pub fn analyze(tokens: &[Token], nivel_verbose: usize) -> Result<GeneradorCuadru
                     println!("  → Acción: Shift({})", nuevo_estado);
                 }
 
+                recuperando = false;
+
                 // Guardar atributo semántico del token
                 if cursor < tokens.len() {
                     pila_semantica.push(tokens[cursor].valor.clone());
+                    pila_spans.push(Some(tokens[cursor].span()));
+                }
+
+                // Acciones a mitad de regla (solo si no hay errores previos)
+                if cursor < tokens.len() && errores.is_empty() {
+                    let span_token = tokens[cursor].span();
 
-                    // Detectar token 'inicio': hacer FILL del GOTO al main
-                    let token_actual = &tokens[cursor].tipo;
-                    if matches!(token_actual, crate::lexico::token::TipoToken::Inicio) {
-                        if nivel_verbose >= 2 {
-                            println!("[PARSER] Detectado 'inicio' - haciendo FILL de GOTO al main");
+                    let resultado = match tokens[cursor].tipo {
+                        // 'inicio': hacer FILL del GOTO al main
+                        TipoToken::Inicio => {
+                            if nivel_verbose >= 2 {
+                                println!("[PARSER] Detectado 'inicio' - haciendo FILL de GOTO al main");
+                            }
+                            generador.fill_goto_inicio()
                         }
-                        generador.fill_goto_inicio()?;
-                    }
 
-                    // Detectar inicio de condición: si ( o mientras (
-                    if matches!(token_actual, crate::lexico::token::TipoToken::Si) {
-                        if cursor + 1 < tokens.len() &&
-                           matches!(tokens[cursor + 1].tipo, crate::lexico::token::TipoToken::ParenAbre) {
+                        // 'mientras (': marcar inicio de ciclo
+                        TipoToken::Mientras => {
+                            if cursor + 1 < tokens.len() && matches!(tokens[cursor + 1].tipo, TipoToken::ParenAbre) {
+                                if nivel_verbose >= 2 {
+                                    println!("[PARSER] Detectado 'mientras (' - marcando inicio de ciclo");
+                                }
+                                generador.marcar_inicio_ciclo();
+                            }
+                            Ok(())
+                        }
+
+                        // 'entonces' / 'haz': la condición ya se redujo por completo,
+                        // se genera su GOTOF antes del cuerpo
+                        TipoToken::Entonces => {
                             if nivel_verbose >= 2 {
-                                println!("[PARSER] Detectado 'si (' - marcando inicio de condición");
+                                println!("[PARSER] Detectado 'entonces' - generando GOTOF de la condición");
                             }
-                            generador.iniciar_condicion();
+                            generador.generar_gotof()
                         }
-                    } else if matches!(token_actual, crate::lexico::token::TipoToken::Mientras) {
-                        if cursor + 1 < tokens.len() &&
-                           matches!(tokens[cursor + 1].tipo, crate::lexico::token::TipoToken::ParenAbre) {
+
+                        // 'haz' además abre el ciclo para `rompe` / `continua`
+                        TipoToken::Haz => {
                             if nivel_verbose >= 2 {
-                                println!("[PARSER] Detectado 'mientras (' - marcando inicio de ciclo");
+                                println!("[PARSER] Detectado 'haz' - generando GOTOF y abriendo ciclo");
                             }
-                            generador.marcar_inicio_ciclo();
-                            generador.iniciar_condicion();
+                            generador.generar_gotof().map(|_| {
+                                generador.abrir_ciclo();
+                                contexto.entrar_ciclo();
+                            })
                         }
-                    } else if matches!(token_actual, crate::lexico::token::TipoToken::Sino) {
-                        if nivel_verbose >= 2 {
-                            println!("[PARSER] Detectado 'sino' - generando GOTO para saltar el else");
+
+                        // '(' / ')': fondo falso en POper, para que una comparación
+                        // entre paréntesis no se confunda con una encadenada
+                        TipoToken::ParenAbre => {
+                            generador.abrir_parentesis();
+                            Ok(())
                         }
-                        if let Err(e) = generador.iniciar_else() {
-                            return Err(format!("Error al iniciar else: {}", e));
+                        TipoToken::ParenCierra => generador.cerrar_parentesis(),
+
+                        // 'y' / 'o': el operando izquierdo ya se redujo, salto de cortocircuito
+                        TipoToken::Y => generador.procesar_y(),
+                        TipoToken::O => generador.procesar_o(),
+
+                        // 'sino': GOTO para saltar el else
+                        TipoToken::Sino => {
+                            if nivel_verbose >= 2 {
+                                println!("[PARSER] Detectado 'sino' - generando GOTO para saltar el else");
+                            }
+                            generador.iniciar_else()
                         }
+
+                        _ => Ok(()),
+                    };
+
+                    // Un error de la condición ya trae la ubicación de la expresión
+                    if let Err(e) = resultado {
+                        errores.push(e.con_span_por_omision(Some(span_token)));
                     }
                 }
 
@@ -133,21 +213,33 @@ pub fn analyze(tokens: &[Token], nivel_verbose: usize) -> Result<GeneradorCuadru
 
                 // Extraer atributos semánticos de la pila
                 let atributos = extraer_atributos(&mut pila_semantica, regla.longitud_cuerpo);
+                let spans = extraer_atributos(&mut pila_spans, regla.longitud_cuerpo);
 
                 if nivel_verbose >= 3 {
                     println!("[DEBUG] REDUCE: atributos extraídos = {:?}", atributos);
                 }
 
-                // Ejecutar acción semántica
-                let atributo_sintetizado = ejecutar_accion_semantica(
-                    &regla.cabeza,
-                    regla.longitud_cuerpo,
-                    &atributos,
-                    &tokens,
-                    &mut contexto,
-                    &mut generador,
-                    nivel_verbose,
-                )?;
+                // Ejecutar acción semántica (suprimida después del primer error)
+                let span_produccion = unir_spans(&spans);
+                let atributo_sintetizado = if errores.is_empty() {
+                    generador.establecer_span(span_produccion);
+                    ejecutar_accion_semantica(
+                        &regla.cabeza,
+                        regla.longitud_cuerpo,
+                        &atributos,
+                        &spans,
+                        &mut contexto,
+                        &mut generador,
+                        nivel_verbose,
+                    ).unwrap_or_else(|e| {
+                        // Los errores que no señalan un operando o identificador
+                        // apuntan a la producción completa
+                        errores.push(e.con_span_por_omision(span_produccion));
+                        String::new()
+                    })
+                } else {
+                    String::new()
+                };
 
                 if nivel_verbose >= 3 {
                     println!("[DEBUG] REDUCE: atributo_sintetizado = '{}'", atributo_sintetizado);
@@ -155,6 +247,7 @@ pub fn analyze(tokens: &[Token], nivel_verbose: usize) -> Result<GeneradorCuadru
 
                 // Pushear atributo sintetizado de vuelta
                 pila_semantica.push(atributo_sintetizado);
+                pila_spans.push(span_produccion);
 
                 // Sacar (pop) 'longitud_cuerpo' estados de la pila
                 for _ in 0..regla.longitud_cuerpo {
@@ -166,10 +259,11 @@ pub fn analyze(tokens: &[Token], nivel_verbose: usize) -> Result<GeneradorCuadru
                 let nuevo_estado = TABLA_GOTO
                     .get(&(estado_anterior, regla.cabeza.clone()))
                     .ok_or_else(|| {
-                        format!(
+                        errores.push(Diagnostico::error(codigos::INTERNO, format!(
                             "Error fatal: GOTO no encontrado para estado {} y no-terminal {}",
                             estado_anterior, regla.cabeza
-                        )
+                        )));
+                        std::mem::take(&mut errores)
                     })?;
 
                 pila_estados.push(*nuevo_estado);
@@ -180,15 +274,21 @@ pub fn analyze(tokens: &[Token], nivel_verbose: usize) -> Result<GeneradorCuadru
                     println!("  → Acción: Accept");
                 }
 
-                // Mostrar resultados
-                println!("\n✓✓✓ Compilación exitosa ✓✓✓");
+                if !errores.is_empty() {
+                    return Err(errores);
+                }
 
-                // Mostrar cuádruplos generados
-                let cuadruplos = generador.obtener_cuadruplos();
-                if !cuadruplos.is_empty() {
-                    println!("\n=== Código Intermedio Generado ===");
-                    for (i, cuadruplo) in cuadruplos.iter().enumerate() {
-                        println!("{}: {:?}", i, cuadruplo);
+                // Mostrar resultados (solo con -v; `dump-quads` los lista sin verbose)
+                if nivel_verbose >= 1 {
+                    println!("\n✓✓✓ Compilación exitosa ✓✓✓");
+
+                    // Mostrar cuádruplos generados
+                    let cuadruplos = generador.obtener_cuadruplos();
+                    if !cuadruplos.is_empty() {
+                        println!("\n=== Código Intermedio Generado ===");
+                        for (i, cuadruplo) in cuadruplos.iter().enumerate() {
+                            println!("{}: {:?}", i, cuadruplo);
+                        }
                     }
                 }
 
@@ -196,40 +296,458 @@ pub fn analyze(tokens: &[Token], nivel_verbose: usize) -> Result<GeneradorCuadru
                 return Ok(generador);
             }
 
+            None if recuperando => {
+                // Error inmediatamente después de recuperar: no se reporta de nuevo,
+                // se descarta el token para garantizar progreso
+                if nivel_verbose >= 2 {
+                    println!("  → Error en cascada, descartando token");
+                }
+                if cursor >= tokens.len() || token_str == "$" {
+                    return Err(errores);
+                }
+                cursor += 1;
+                if !recuperar(tokens, &mut cursor, &mut pila_estados, &mut pila_semantica, &mut pila_spans) {
+                    return Err(errores);
+                }
+            }
+
             None => {
                 // Error de sintaxis
-                let mensaje = if cursor < tokens.len() {
-                    let token = &tokens[cursor];
-                    format!(
-                        "Error de sintaxis en línea {}: token inesperado '{}' (esperado en estado {})",
-                        token.linea, token.valor, estado_actual
-                    )
-                } else {
-                    format!(
-                        "Error de sintaxis: fin inesperado del archivo (estado {})",
-                        estado_actual
-                    )
-                };
+                let diagnostico = match tokens.get(cursor) {
+                    Some(token) if token.tipo != TipoToken::EOF => {
+                        Diagnostico::error(
+                            codigos::TOKEN_INESPERADO,
+                            format!("Error de sintaxis: token inesperado '{}'", token.valor),
+                        ).con_span(token.span())
+                    }
+                    token => {
+                        let diagnostico = Diagnostico::error(
+                            codigos::FIN_INESPERADO,
+                            "Error de sintaxis: fin inesperado del archivo",
+                        );
+                        match token {
+                            Some(eof) => diagnostico.con_span(eof.span()),
+                            None => diagnostico,
+                        }
+                    }
+                }.con_nota(formatear_esperados(&terminales_esperados(&pila_estados)));
 
                 if nivel_verbose >= 2 {
-                    println!("  → Error detectado");
+                    println!("  → Error detectado en estado {}, iniciando recuperación", estado_actual);
                 }
 
-                return Err(mensaje);
+                errores.push(diagnostico);
+                recuperando = true;
+
+                if !recuperar(tokens, &mut cursor, &mut pila_estados, &mut pila_semantica, &mut pila_spans) {
+                    return Err(errores);
+                }
+            }
+        }
+    }
+}
+
+/// Recuperación en modo pánico
+///
+/// Descarta tokens hasta uno de sincronización (saltando bloques `{ }`
+/// completos), consume el `;` que termina el estatuto erróneo y saca estados
+/// de la pila hasta uno donde el análisis pueda continuar con el token actual.
+/// Retorna `false` si no existe tal estado o se llegó al fin del archivo.
+fn recuperar(
+    tokens: &[Token],
+    cursor: &mut usize,
+    pila_estados: &mut Vec<usize>,
+    pila_semantica: &mut Vec<String>,
+    pila_spans: &mut Vec<Option<Span>>,
+) -> bool {
+    // 1. Descartar tokens hasta uno de sincronización fuera de bloques anidados
+    let mut profundidad = 0;
+    let sincronizacion = loop {
+        let Some(token) = tokens.get(*cursor) else { return false };
+        let terminal = token.tipo.as_grammar();
+        match terminal {
+            "$" => return false,
+            "{" => profundidad += 1,
+            "}" if profundidad > 0 => profundidad -= 1,
+            _ if profundidad == 0 && TOKENS_SINCRONIZACION.contains(&terminal) => break terminal,
+            _ => {}
+        }
+        *cursor += 1;
+    };
+
+    // 2. ';' cierra el estatuto erróneo: se consume y se reanuda con lo que sigue
+    if sincronizacion == ";" {
+        *cursor += 1;
+    }
+    let lookahead = tokens.get(*cursor).map_or("$", |t| t.tipo.as_grammar());
+
+    // 3. Sacar estados hasta uno de reanudación que acepte el lookahead
+    loop {
+        let estado = *pila_estados.last().unwrap();
+        let tiene_accion = TABLA_ACTION.contains_key(&(estado, lookahead.to_string()));
+        let es_reanudacion = lookahead == "fin" || NO_TERMINALES_SINCRONIZACION
+            .iter()
+            .any(|nt| TABLA_GOTO.contains_key(&(estado, nt.to_string())));
+
+        if tiene_accion && es_reanudacion {
+            return true;
+        }
+        if pila_estados.len() == 1 {
+            return false;
+        }
+
+        pila_estados.pop();
+        pila_semantica.pop();
+        pila_spans.pop();
+    }
+}
+
+/// Terminales que el parser aceptaría en la configuración actual, ordenados
+///
+/// Una entrada Reduce del estado tope solo indica que el terminal está en el
+/// FOLLOW de la producción; el terminal se lista si después de las reducciones
+/// se llega a un Shift (o Accept)
+fn terminales_esperados(pila_estados: &[usize]) -> Vec<String> {
+    let estado = *pila_estados.last().unwrap();
+    let mut terminales: Vec<String> = TABLA_ACTION
+        .keys()
+        .filter(|(e, terminal)| *e == estado && se_desplazaria(pila_estados, terminal))
+        .map(|(_, terminal)| terminal.clone())
+        .collect();
+    terminales.sort();
+    terminales
+}
+
+/// Simula sobre una copia de la pila las reducciones con `terminal` como
+/// lookahead; true si terminan en Shift o Accept
+fn se_desplazaria(pila_estados: &[usize], terminal: &str) -> bool {
+    let mut pila = pila_estados.to_vec();
+    loop {
+        let estado = *pila.last().unwrap();
+        match TABLA_ACTION.get(&(estado, terminal.to_string())) {
+            Some(Accion::Shift(_)) | Some(Accion::Accept) => return true,
+            Some(Accion::Reduce(num_regla)) => {
+                let regla = &PRODUCCIONES[*num_regla];
+                if regla.longitud_cuerpo >= pila.len() {
+                    return false;
+                }
+                pila.truncate(pila.len() - regla.longitud_cuerpo);
+                match TABLA_GOTO.get(&(*pila.last().unwrap(), regla.cabeza.clone())) {
+                    Some(nuevo_estado) => pila.push(*nuevo_estado),
+                    None => return false,
+                }
             }
+            None => return false,
         }
     }
 }
 
+/// Nota con la lista de terminales esperados
+fn formatear_esperados(terminales: &[String]) -> String {
+    let lista: Vec<String> = terminales
+        .iter()
+        .map(|t| if t == "$" { "fin de archivo".to_string() } else { format!("'{}'", t) })
+        .collect();
+    match lista.len() {
+        1 => format!("se esperaba {}", lista[0]),
+        _ => format!("se esperaba uno de: {}", lista.join(", ")),
+    }
+}
+
 /// Extrae N atributos semánticos de la pila
-fn extraer_atributos(pila: &mut Vec<String>, n: usize) -> Vec<String> {
+fn extraer_atributos<T: Default>(pila: &mut Vec<T>, n: usize) -> Vec<T> {
     let mut atributos = Vec::new();
     for _ in 0..n {
         if let Some(val) = pila.pop() {
             atributos.insert(0, val);
         } else {
-            atributos.insert(0, String::new());
+            atributos.insert(0, T::default());
         }
     }
     atributos
 }
+
+/// Span que cubre todos los símbolos de una producción (None si es vacía)
+fn unir_spans(spans: &[Option<Span>]) -> Option<Span> {
+    spans.iter()
+        .flatten()
+        .fold(None, |acc: Option<Span>, span| Some(acc.map_or(*span, |a| a.unir(span))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostico::codigos;
+    use crate::lexico;
+    use crate::intermedio::cuadruplo::{OperadorCuadruplo, Operando};
+
+    fn errores_de(fuente: &str) -> Vec<Diagnostico> {
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+        analyze(&tokens, 0).err().unwrap()
+    }
+
+    #[test]
+    fn test_escribe_genera_una_escritura_por_argumento() {
+        let tokens = lexico::analyze("programa p; inicio { escribe(1 + 2, 'a', \"hola\"); } fin", 0).unwrap();
+        let generador = analyze(&tokens, 0).ok().unwrap();
+        let programa = generador.exportar_programa("p".to_string()).unwrap();
+
+        let escrituras: Vec<String> = programa.cuadruplos.iter()
+            .filter(|c| c.operador == crate::intermedio::OperadorCuadruplo::Escritura)
+            .map(|c| c.resultado.to_string())
+            .collect();
+        assert_eq!(escrituras, vec!["@21000", "@35000", "STR[0]"]);
+    }
+
+    #[test]
+    fn test_signo_ante_cualquier_factor() {
+        let fuente = "programa p; vars a : entero; inicio { a = 2; escribe(-(a + 1), - -3, -a % 2); } fin";
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+        let programa = analyze(&tokens, 0).ok().unwrap().exportar_programa("p".to_string()).unwrap();
+
+        let cuadruplos: Vec<String> = programa.cuadruplos.iter().map(|c| c.to_string()).collect();
+        assert!(cuadruplos.contains(&"(neg, @21000, -, @21000)".to_string()), "{:?}", cuadruplos);
+        assert!(cuadruplos.contains(&"(neg, @1000, -, @21000)".to_string()), "{:?}", cuadruplos);
+        assert!(cuadruplos.iter().any(|c| c.starts_with("(%, @21000")), "{:?}", cuadruplos);
+        // - -3 se pliega a la constante 3 sin cuádruplos
+        assert!(programa.mapa_constantes.values().any(|v| *v == crate::vm::memoria::Valor::Entero(3)));
+        assert_eq!(cuadruplos.iter().filter(|c| c.starts_with("(neg")).count(), 2);
+
+        let errores = errores_de("programa p; vars b : booleano; inicio { b = -verdadero; } fin");
+        assert!(errores[0].mensaje.contains("tipos incompatibles"));
+    }
+
+    #[test]
+    fn test_constante_entera_minima() {
+        let fuente = "programa p; vars n : entero; inicio { n = -2147483648; escribe(-2147483648 + 1); } fin";
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+        let programa = analyze(&tokens, 0).ok().unwrap().exportar_programa("p".to_string()).unwrap();
+        assert!(programa.mapa_constantes.values().any(|v| *v == crate::vm::memoria::Valor::Entero(i32::MIN)));
+        assert!(!programa.cuadruplos.iter().any(|c| c.operador == OperadorCuadruplo::Negativo));
+
+        // Sin el `-` inmediato (o negada dos veces) la magnitud no cabe en 32 bits
+        for expresion in ["2147483648", "3 - 2147483648", "- -2147483648", "-(2147483648)"] {
+            let errores = errores_de(&format!("programa p; vars n : entero; inicio {{ n = {}; }} fin", expresion));
+            assert!(errores[0].mensaje.contains("2147483648 no cabe en un entero de 32 bits"), "{}: {:?}", expresion, errores);
+        }
+    }
+
+    #[test]
+    fn test_aritmetica_asocia_a_la_izquierda() {
+        let fuente = "programa p; vars a, b, c : entero; inicio { a = 1; b = 2; c = 3; escribe(a - b - c, a + b * c); } fin";
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+        let programa = analyze(&tokens, 0).ok().unwrap().exportar_programa("p".to_string()).unwrap();
+
+        // Los temporales salen de un pool sin orden fijo: se revisa la forma, no las direcciones
+        let aritmeticos: Vec<_> = programa.cuadruplos.iter()
+            .filter(|c| matches!(c.operador,
+                OperadorCuadruplo::Suma | OperadorCuadruplo::Resta | OperadorCuadruplo::Multiplicacion))
+            .collect();
+        let operadores: Vec<_> = aritmeticos.iter().map(|c| c.operador).collect();
+        assert_eq!(operadores, [
+            OperadorCuadruplo::Resta,
+            OperadorCuadruplo::Resta,
+            OperadorCuadruplo::Multiplicacion,
+            OperadorCuadruplo::Suma,
+        ]);
+
+        // (a - b) - c: la segunda resta consume el resultado de la primera
+        let (resta1, resta2) = (aritmeticos[0], aritmeticos[1]);
+        assert_eq!((&resta1.operando_izq, &resta1.operando_der), (&Operando::Direccion(1000), &Operando::Direccion(1001)));
+        assert_eq!((&resta2.operando_izq, &resta2.operando_der), (&resta1.resultado, &Operando::Direccion(1002)));
+
+        // a + (b * c): el * se genera antes que el + y el + consume su resultado
+        let (mult, suma) = (aritmeticos[2], aritmeticos[3]);
+        assert_eq!((&mult.operando_izq, &mult.operando_der), (&Operando::Direccion(1001), &Operando::Direccion(1002)));
+        assert_eq!((&suma.operando_izq, &suma.operando_der), (&Operando::Direccion(1000), &mult.resultado));
+    }
+
+    #[test]
+    fn test_llamada_sin_argumentos() {
+        let fuente = "programa p; vars n : entero; entero siete() { regresa 7; }; \
+                      inicio { n = siete() + siete(); } fin";
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+        let programa = analyze(&tokens, 0).ok().unwrap().exportar_programa("p".to_string()).unwrap();
+        let gosubs = programa.cuadruplos.iter().filter(|c| c.operador == OperadorCuadruplo::GoSub).count();
+        assert_eq!(gosubs, 2);
+
+        let errores = errores_de("programa p; vars n : entero; entero doble(x : entero) { regresa x * 2; }; \
+                                  inicio { n = doble(); } fin");
+        assert!(errores[0].mensaje.contains("La función 'doble' recibe 1 parámetro(s), se pasaron 0"), "{}", errores[0].mensaje);
+    }
+
+    #[test]
+    fn test_comparacion_encadenada_es_error() {
+        let errores = errores_de("programa p; vars a : entero; inicio { si (1 < a <= 3) entonces { a = 0; }; } fin");
+
+        assert_eq!(errores.len(), 1);
+        assert!(errores[0].mensaje.contains("las comparaciones no se encadenan ('<' seguido de '<=')"), "{}", errores[0].mensaje);
+        // Se detecta antes de generar la comparación interna `1 == verdadero`
+        let errores = errores_de("programa p; vars b : booleano; inicio { b = 1 == 1 == verdadero; } fin");
+        assert_eq!(errores.len(), 1);
+        assert!(errores[0].mensaje.contains("las comparaciones no se encadenan ('==' seguido de '==')"), "{}", errores[0].mensaje);
+        // Entre paréntesis la comparación interna es un factor y solo falla por tipos
+        let errores = errores_de("programa p; vars b : booleano; inicio { b = 1 == (1 == verdadero); } fin");
+        assert!(errores[0].mensaje.contains("tipos incompatibles"), "{}", errores[0].mensaje);
+        let tokens = lexico::analyze("programa p; vars b : booleano; inicio { b = verdadero == (1 < 2); } fin", 0).unwrap();
+        assert!(analyze(&tokens, 0).is_ok());
+        let tokens = lexico::analyze("programa p; vars a : entero; inicio { si (1 < a y a <= 3) entonces { a = 0; }; } fin", 0).unwrap();
+        assert!(analyze(&tokens, 0).is_ok());
+    }
+
+    #[test]
+    fn test_asignacion_compuesta_valida_como_la_forma_expandida() {
+        let expandida = errores_de("programa p; vars i : entero; inicio { i = i + 1.5; } fin");
+        let compuesta = errores_de("programa p; vars i : entero; inicio { i += 1.5; } fin");
+        assert_eq!(compuesta[0].mensaje, expandida[0].mensaje);
+
+        let errores = errores_de("programa p; vars b : booleano; inicio { b++; } fin");
+        assert!(errores[0].mensaje.contains("tipos incompatibles"));
+
+        let tokens = lexico::analyze("programa p; vars i : entero; inicio { i = 1; i += 2; i++; } fin", 0).unwrap();
+        let programa = analyze(&tokens, 0).ok().unwrap().exportar_programa("p".to_string()).unwrap();
+        let cuadruplos: Vec<String> = programa.cuadruplos.iter().map(|c| c.to_string()).collect();
+        assert_eq!(cuadruplos.iter().filter(|c| c.starts_with("(+, @1000")).count(), 2, "{:?}", cuadruplos);
+    }
+
+    #[test]
+    fn test_cadena_sino_si_es_plana() {
+        let fuente = "programa p; vars n, r : entero; inicio { n = 2; \
+                      si (n == 0) entonces { r = 0; } \
+                      sino si (n == 1) entonces { r = 1; } \
+                      sino si (n == 2) entonces { r = 2; } \
+                      sino { r = 3; } \
+                      escribe(r); } fin";
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+        let programa = analyze(&tokens, 0).ok().unwrap().exportar_programa("p".to_string()).unwrap();
+
+        // Las tres salidas (GOTO) saltan directo al `escribe` que sigue a la cadena
+        let fin = programa.cuadruplos.iter()
+            .position(|c| c.operador == OperadorCuadruplo::Escritura)
+            .unwrap();
+        let salidas: Vec<_> = programa.cuadruplos.iter().skip(1)
+            .filter(|c| c.operador == OperadorCuadruplo::Goto)
+            .map(|c| c.resultado.clone())
+            .collect();
+        assert_eq!(salidas, vec![Operando::Etiqueta(fin); 3]);
+
+        // Cada GOTOF salta a la condición siguiente (justo después de un GOTO)
+        for (i, c) in programa.cuadruplos.iter().enumerate() {
+            if c.operador == OperadorCuadruplo::GotoF
+                && let Operando::Etiqueta(destino) = c.resultado
+            {
+                assert!(destino > i);
+                assert_eq!(programa.cuadruplos[destino - 1].operador, OperadorCuadruplo::Goto);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reporta_varios_errores_sintacticos() {
+        let fuente = "programa p; vars x, z : entero;\n\
+                      inicio {\n\
+                      x = ;\n\
+                      z = 2;\n\
+                      x = 3 +;\n\
+                      escribe(z);\n\
+                      } fin";
+        let errores = errores_de(fuente);
+
+        assert_eq!(errores.len(), 2);
+        assert!(errores.iter().all(|e| e.codigo == codigos::TOKEN_INESPERADO));
+        let lineas: Vec<usize> = errores.iter().map(|e| e.span.unwrap().linea).collect();
+        assert_eq!(lineas, vec![3, 5]);
+    }
+
+    #[test]
+    fn test_lista_terminales_esperados() {
+        let errores = errores_de("programa p; inicio { x = ; } fin");
+
+        let nota = &errores[0].notas[0];
+        assert!(nota.starts_with("se esperaba uno de:"));
+        assert!(nota.contains("'id'"));
+        assert!(nota.contains("'cte_ent'"));
+        assert!(!nota.contains("';'"));
+
+        // Tras un factor solo se listan los terminales que la pila acepta: ')' y
+        // ',' están en el FOLLOW de <FACTOR> pero aquí no hay paréntesis ni lista
+        let errores = errores_de("programa p; vars x : entero; inicio { x = 3 3; } fin");
+        let nota = &errores[0].notas[0];
+        assert!(nota.contains("'+'") && nota.contains("';'") && nota.contains("'<'"), "{}", nota);
+        assert!(!nota.contains("')'") && !nota.contains("','"), "{}", nota);
+
+        let errores = errores_de("programa p; vars x : entero; inicio { escribe(x 3); } fin");
+        let nota = &errores[0].notas[0];
+        assert!(nota.contains("')'") && nota.contains("','") && !nota.contains("';'"), "{}", nota);
+    }
+
+    #[test]
+    fn test_suprime_acciones_semanticas_tras_error() {
+        // 'z' no declarada después del error sintáctico no debe reportarse
+        let fuente = "programa p; vars x : entero; inicio { x = * 2; z = 1; x = 1.5; } fin";
+        let errores = errores_de(fuente);
+
+        assert_eq!(errores.len(), 1);
+        assert_eq!(errores[0].codigo, codigos::TOKEN_INESPERADO);
+    }
+
+    #[test]
+    fn test_error_en_bloque_anidado() {
+        let fuente = "programa p; vars x : entero; inicio {\n\
+                      si (x > ) entonces { x = 1; };\n\
+                      mientras (x < 3) haz { x = x + ; }\n\
+                      x = 2;\n\
+                      } fin";
+        let errores = errores_de(fuente);
+
+        let lineas: Vec<usize> = errores.iter().map(|e| e.span.unwrap().linea).collect();
+        assert_eq!(lineas, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_rompe_fuera_de_ciclo() {
+        let fuente = "programa p; vars x : entero; inicio {\n\
+                      mientras (x < 3) haz { x = x + 1; }\n\
+                      rompe;\n\
+                      } fin";
+        let errores = errores_de(fuente);
+
+        assert_eq!(errores.len(), 1);
+        assert_eq!(errores[0].codigo, codigos::SEMANTICO);
+        assert!(errores[0].mensaje.contains("'rompe'"));
+        assert_eq!(errores[0].span.unwrap().linea, 3);
+    }
+
+    #[test]
+    fn test_errores_de_tipos_senalan_el_operando() {
+        let fragmento = |fuente: &str, diagnostico: &Diagnostico| {
+            let span = diagnostico.span.unwrap();
+            fuente[span.inicio..span.fin].to_string()
+        };
+
+        // Asignación: el lado derecho, no el estatuto completo
+        let fuente = "programa p; vars a : entero; vars f : flotante; inicio { a = f * 2; } fin";
+        let errores = errores_de(fuente);
+        assert_eq!(errores[0].codigo, codigos::TIPOS_INCOMPATIBLES);
+        assert_eq!(fragmento(fuente, &errores[0]), "f * 2");
+
+        // Condición no booleana: la expresión, no `entonces`
+        let fuente = "programa p; vars a : entero; inicio { si (a + 1) entonces { a = 1; }; } fin";
+        let errores = errores_de(fuente);
+        assert_eq!(fragmento(fuente, &errores[0]), "a + 1");
+
+        // Operación binaria: la expresión, con el tipo de cada operando
+        let fuente = "programa p; vars a : entero; vars b : booleano; inicio { a = a + b * 2; } fin";
+        let errores = errores_de(fuente);
+        assert_eq!(fragmento(fuente, &errores[0]), "b * 2");
+        let etiquetas: Vec<(&str, &str)> = errores[0].spans_secundarios.iter()
+            .map(|(span, etiqueta)| (&fuente[span.inicio..span.fin], etiqueta.as_str()))
+            .collect();
+        assert_eq!(etiquetas, [("b", "booleano"), ("2", "entero")]);
+
+        // Argumento de una llamada
+        let fuente = "programa p; entero f(x : entero) { regresa x; }; inicio { escribe(f(verdadero)); } fin";
+        let errores = errores_de(fuente);
+        assert_eq!(fragmento(fuente, &errores[0]), "verdadero");
+    }
+}
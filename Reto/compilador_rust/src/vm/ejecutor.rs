@@ -3,12 +3,12 @@
 //! Máquina Virtual que ejecuta cuádruplos.
 
 use std::collections::HashMap;
+use std::rc::Rc;
 use crate::intermedio::cuadruplo::{Cuadruplo, OperadorCuadruplo, Operando};
 use crate::intermedio::programa::ProgramaObjeto;
-use super::memoria::{
-    SegmentoMemoria, MarcoMemoria, Valor, TipoSegmento,
-    traducir_direccion
-};
+use crate::intermedio::distribucion::{DistribucionMemoria, TipoSegmento};
+use crate::semantico::tipos::TipoDato;
+use super::memoria::{SegmentoMemoria, MarcoMemoria, Valor, ajustar_a_tipo};
 use super::io::SistemaIO;
 
 /// Información de una función en la tabla de funciones
@@ -19,6 +19,27 @@ pub struct InfoFuncion {
     pub tiene_retorno: bool,
 }
 
+/// Qué hace la VM cuando una operación entre enteros no cabe en 32 bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Desbordamiento {
+    /// Error de ejecución que indica el cuádruplo (por defecto)
+    #[default]
+    Error,
+    /// Aritmética modular en complemento a dos
+    Envolver,
+}
+
+impl Desbordamiento {
+    /// Interpreta el nombre que recibe `--desbordamiento`
+    pub fn desde_nombre(nombre: &str) -> Option<Self> {
+        match nombre {
+            "error" => Some(Desbordamiento::Error),
+            "envolver" => Some(Desbordamiento::Envolver),
+            _ => None,
+        }
+    }
+}
+
 /// Máquina Virtual - Ejecuta código intermedio
 pub struct MaquinaVirtual {
     /// Cuádruplos a ejecutar
@@ -39,8 +60,9 @@ pub struct MaquinaVirtual {
     /// Tabla de funciones (nombre -> dirección de inicio)
     tabla_funciones: HashMap<String, InfoFuncion>,
 
-    /// Marco temporal para parámetros (antes de GoSub)
-    marco_temporal: Option<MarcoMemoria>,
+    /// Marcos creados por ERA que esperan su GoSub (pila: un argumento puede
+    /// contener otra llamada)
+    marcos_pendientes: Vec<MarcoMemoria>,
 
     /// Flag de ejecución
     ejecutando: bool,
@@ -48,13 +70,25 @@ pub struct MaquinaVirtual {
     /// Sistema de E/S (inyectado)
     io: Box<dyn SistemaIO>,
 
-    /// Tabla de strings literales (letreros)
-    tabla_strings: Vec<String>,
+    /// Tabla de strings del programa (`Operando::Letrero` es un índice en
+    /// ella); las concatenaciones no se agregan aquí, viven en su `Valor`
+    letreros: Vec<Rc<str>>,
+
+    /// Esquema de direcciones que entiende esta VM
+    distribucion: DistribucionMemoria,
+
+    /// Manejo de desbordamientos en la aritmética entera
+    desbordamiento: Desbordamiento,
 }
 
 impl MaquinaVirtual {
     /// Crea una nueva máquina virtual con sistema de E/S inyectado
     pub fn new(io: Box<dyn SistemaIO>) -> Self {
+        Self::con_distribucion(io, DistribucionMemoria::default())
+    }
+
+    /// Crea una máquina virtual para programas compilados con otra distribución de memoria
+    pub fn con_distribucion(io: Box<dyn SistemaIO>, distribucion: DistribucionMemoria) -> Self {
         MaquinaVirtual {
             cuadruplos: Vec::new(),
             ip: 0,
@@ -62,15 +96,31 @@ impl MaquinaVirtual {
             memoria_constantes: SegmentoMemoria::new(),
             pila_marcos: Vec::new(),
             tabla_funciones: HashMap::new(),
-            marco_temporal: None,
+            marcos_pendientes: Vec::new(),
             ejecutando: true,
             io,
-            tabla_strings: Vec::new(),
+            letreros: Vec::new(),
+            distribucion,
+            desbordamiento: Desbordamiento::default(),
         }
     }
 
+    /// Elige si un desbordamiento entero es error de ejecución o envuelve
+    pub fn con_desbordamiento(mut self, desbordamiento: Desbordamiento) -> Self {
+        self.desbordamiento = desbordamiento;
+        self
+    }
+
     /// Carga un programa objeto en la VM
     pub fn cargar_programa(&mut self, programa: ProgramaObjeto) -> Result<(), String> {
+        // Las direcciones solo tienen sentido con el mismo esquema con que se compiló
+        if programa.distribucion != self.distribucion {
+            return Err(format!(
+                "Error: El programa se compiló con la distribución de memoria [{}], pero la VM usa [{}]",
+                programa.distribucion, self.distribucion
+            ));
+        }
+
         // Cargar cuádruplos
         self.cuadruplos = programa.cuadruplos;
 
@@ -89,8 +139,7 @@ impl MaquinaVirtual {
             self.cargar_constante(direccion, valor)?;
         }
 
-        // Cargar tabla de strings
-        self.tabla_strings = programa.tabla_strings;
+        self.letreros = programa.tabla_strings.into_iter().map(Rc::from).collect();
 
         Ok(())
     }
@@ -116,13 +165,18 @@ impl MaquinaVirtual {
     fn ejecutar_cuadruplo(&mut self, cuad: &Cuadruplo) -> Result<(), String> {
         match cuad.operador {
             OperadorCuadruplo::Suma | OperadorCuadruplo::Resta |
-            OperadorCuadruplo::Multiplicacion | OperadorCuadruplo::Division => {
+            OperadorCuadruplo::Multiplicacion | OperadorCuadruplo::Division |
+            OperadorCuadruplo::Modulo | OperadorCuadruplo::Negativo => {
                 self.ejecutar_aritmetica(cuad)?;
             },
             OperadorCuadruplo::MayorQue | OperadorCuadruplo::MenorQue |
+            OperadorCuadruplo::MayorIgual | OperadorCuadruplo::MenorIgual |
             OperadorCuadruplo::Diferente | OperadorCuadruplo::Igual => {
                 self.ejecutar_relacional(cuad)?;
             },
+            OperadorCuadruplo::Y | OperadorCuadruplo::O | OperadorCuadruplo::No => {
+                self.ejecutar_logica(cuad)?;
+            },
             OperadorCuadruplo::Asignacion => {
                 self.ejecutar_asignacion(cuad)?;
             },
@@ -135,6 +189,12 @@ impl MaquinaVirtual {
             OperadorCuadruplo::GotoV => {
                 self.ejecutar_gotov(cuad)?;
             },
+            OperadorCuadruplo::Verifica => {
+                self.ejecutar_verifica(cuad)?;
+            },
+            OperadorCuadruplo::VerificaPaso => {
+                self.ejecutar_verifica_paso(cuad)?;
+            },
             OperadorCuadruplo::Escritura => {
                 self.ejecutar_escribe(cuad)?;
             },
@@ -164,8 +224,8 @@ impl MaquinaVirtual {
         match operando {
             Operando::Direccion(dir) => Ok(*dir),
             Operando::Temporal(num) => {
-                // Los temporales están en el rango TEMPORAL_INICIO + num
-                Ok(10000 + num)
+                // Los temporales se numeran desde el primer temporal entero
+                Ok(*self.distribucion.rango(TipoSegmento::Temporal, TipoDato::Entero).start() + num)
             },
             Operando::ConstanteEntera(_) | Operando::ConstanteFlotante(_) => {
                 Err("Se esperaba dirección, se encontró constante literal".to_string())
@@ -180,6 +240,12 @@ impl MaquinaVirtual {
             Operando::Letrero(_) => {
                 Err("Los letreros no tienen dirección de memoria".to_string())
             },
+            Operando::Apuntador(dir_apuntador) => {
+                // Direccionamiento indirecto: el temporal contiene la dirección real
+                let direccion = self.leer_memoria(*dir_apuntador)?.a_entero();
+                usize::try_from(direccion)
+                    .map_err(|_| format!("Error de ejecución: Apuntador a dirección inválida {}", direccion))
+            },
         }
     }
 
@@ -188,7 +254,7 @@ impl MaquinaVirtual {
         match operando {
             Operando::ConstanteEntera(v) => Ok(Valor::Entero(*v)),
             Operando::ConstanteFlotante(v) => Ok(Valor::Flotante(*v)),
-            Operando::Letrero(idx) => Ok(Valor::Letrero(*idx)),
+            Operando::Letrero(idx) => Ok(Valor::Letrero(self.letrero(*idx)?)),
             _ => {
                 let dir = self.extraer_direccion(operando)?;
                 self.leer_memoria(dir)
@@ -196,23 +262,52 @@ impl MaquinaVirtual {
         }
     }
 
-    /// Ejecuta operación aritmética: resultado = op1 OPERADOR op2
+    /// Ejecuta operación aritmética: resultado = op1 OPERADOR op2, o resultado = -op1
+    ///
+    /// Entre enteros se opera en `i32`: `/` y `%` truncan hacia cero y un
+    /// desbordamiento se maneja según `Desbordamiento`; dividir entre cero es
+    /// un error de ejecución que indica el cuádruplo.
     fn ejecutar_aritmetica(&mut self, cuad: &Cuadruplo) -> Result<(), String> {
         let valor1 = self.leer_operando(&cuad.operando_izq)?;
-        let valor2 = self.leer_operando(&cuad.operando_der)?;
         let resultado_dir = self.extraer_direccion(&cuad.resultado)?;
 
+        if cuad.operador == OperadorCuadruplo::Negativo {
+            let resultado = match valor1 {
+                Valor::Entero(v) => Valor::Entero(self.operar_enteros(cuad.operador, v, 0)?),
+                Valor::Flotante(v) => Valor::Flotante(-v),
+                otro => return Err(format!(
+                    "Error de ejecución: No se puede negar {} (cuádruplo {})",
+                    otro.descripcion(), self.ip
+                )),
+            };
+            return self.escribir_memoria(resultado_dir, resultado);
+        }
+
+        let valor2 = self.leer_operando(&cuad.operando_der)?;
+
         // Aplicar operación
-        let resultado = match cuad.operador {
-            OperadorCuadruplo::Suma => valor1.operar_aritmetica(&valor2, |a, b| a + b),
-            OperadorCuadruplo::Resta => valor1.operar_aritmetica(&valor2, |a, b| a - b),
-            OperadorCuadruplo::Multiplicacion => valor1.operar_aritmetica(&valor2, |a, b| a * b),
-            OperadorCuadruplo::Division => {
-                if valor2.a_flotante() == 0.0 {
-                    return Err("Error: División por cero".to_string());
-                }
-                valor1.operar_aritmetica(&valor2, |a, b| a / b)
+        let resultado = match (cuad.operador, &valor1, &valor2) {
+            (OperadorCuadruplo::Suma, Valor::Letrero(a), Valor::Letrero(b)) => {
+                // Concatenación: el letrero anterior de la celda destino se
+                // libera al sobrescribirla si nadie más lo guarda
+                Valor::Letrero(Rc::from(format!("{}{}", a, b)))
+            },
+            (_, Valor::Letrero(_), _) | (_, _, Valor::Letrero(_)) => {
+                return Err(format!(
+                    "Error de ejecución: Operación {:?} no válida con letreros",
+                    cuad.operador
+                ));
             },
+            (OperadorCuadruplo::Division | OperadorCuadruplo::Modulo, ..) if valor2.a_flotante() == 0.0 => {
+                let operacion = if cuad.operador == OperadorCuadruplo::Division { "División" } else { "Residuo" };
+                return Err(format!("Error de ejecución: {} entre cero en el cuádruplo {}", operacion, self.ip));
+            },
+            (operador, Valor::Entero(a), Valor::Entero(b)) => Valor::Entero(self.operar_enteros(operador, *a, *b)?),
+            (OperadorCuadruplo::Suma, ..) => valor1.operar_aritmetica(&valor2, |a, b| a + b),
+            (OperadorCuadruplo::Resta, ..) => valor1.operar_aritmetica(&valor2, |a, b| a - b),
+            (OperadorCuadruplo::Multiplicacion, ..) => valor1.operar_aritmetica(&valor2, |a, b| a * b),
+            (OperadorCuadruplo::Division, ..) => valor1.operar_aritmetica(&valor2, |a, b| a / b),
+            (OperadorCuadruplo::Modulo, ..) => valor1.operar_aritmetica(&valor2, |a, b| a % b),
             _ => return Err(format!("Operador aritmético no reconocido: {:?}", cuad.operador)),
         };
 
@@ -222,15 +317,64 @@ impl MaquinaVirtual {
         Ok(())
     }
 
+    /// Operación entre enteros en `i32` (`b` se ignora en `Negativo`)
+    ///
+    /// El divisor ya se verificó distinto de cero, así que `None` en la
+    /// versión verificada solo significa desbordamiento (incluido `MIN / -1`).
+    fn operar_enteros(&self, operador: OperadorCuadruplo, a: i32, b: i32) -> Result<i32, String> {
+        let (verificado, envuelto) = match operador {
+            OperadorCuadruplo::Suma => (a.checked_add(b), a.wrapping_add(b)),
+            OperadorCuadruplo::Resta => (a.checked_sub(b), a.wrapping_sub(b)),
+            OperadorCuadruplo::Multiplicacion => (a.checked_mul(b), a.wrapping_mul(b)),
+            OperadorCuadruplo::Division => (a.checked_div(b), a.wrapping_div(b)),
+            OperadorCuadruplo::Modulo => (a.checked_rem(b), a.wrapping_rem(b)),
+            OperadorCuadruplo::Negativo => (a.checked_neg(), a.wrapping_neg()),
+            _ => return Err(format!("Operador aritmético no reconocido: {:?}", operador)),
+        };
+
+        match (verificado, self.desbordamiento) {
+            (Some(resultado), _) => Ok(resultado),
+            (None, Desbordamiento::Envolver) => Ok(envuelto),
+            (None, Desbordamiento::Error) => {
+                let operacion = if operador == OperadorCuadruplo::Negativo {
+                    format!("-{}", a)
+                } else {
+                    format!("{} {} {}", a, operador, b)
+                };
+                Err(format!(
+                    "Error de ejecución: Desbordamiento de entero en el cuádruplo {} ({})",
+                    self.ip, operacion
+                ))
+            },
+        }
+    }
+
     /// Ejecuta operación relacional: resultado = op1 COMPARADOR op2
     fn ejecutar_relacional(&mut self, cuad: &Cuadruplo) -> Result<(), String> {
         let valor1 = self.leer_operando(&cuad.operando_izq)?;
         let valor2 = self.leer_operando(&cuad.operando_der)?;
         let resultado_dir = self.extraer_direccion(&cuad.resultado)?;
 
+        // Los letreros se comparan por contenido (solo == y !=)
+        if let (Valor::Letrero(a), Valor::Letrero(b)) = (&valor1, &valor2) {
+            let iguales = a == b;
+            let resultado = match cuad.operador {
+                OperadorCuadruplo::Igual => iguales,
+                OperadorCuadruplo::Diferente => !iguales,
+                _ => return Err(format!(
+                    "Error de ejecución: Operación {:?} no válida con letreros",
+                    cuad.operador
+                )),
+            };
+            return self.escribir_memoria(resultado_dir, Valor::Booleano(resultado));
+        }
+
         let resultado = match cuad.operador {
             OperadorCuadruplo::MayorQue => valor1.operar_relacional(&valor2, |a, b| a > b),
             OperadorCuadruplo::MenorQue => valor1.operar_relacional(&valor2, |a, b| a < b),
+            // Con la misma tolerancia que `==`, para que `a == b` implique `a >= b`
+            OperadorCuadruplo::MayorIgual => valor1.operar_relacional(&valor2, |a, b| a > b || (a - b).abs() <= f64::EPSILON),
+            OperadorCuadruplo::MenorIgual => valor1.operar_relacional(&valor2, |a, b| a < b || (a - b).abs() <= f64::EPSILON),
             OperadorCuadruplo::Diferente => valor1.operar_relacional(&valor2, |a, b| (a - b).abs() > f64::EPSILON),
             OperadorCuadruplo::Igual => valor1.operar_relacional(&valor2, |a, b| (a - b).abs() <= f64::EPSILON),
             _ => return Err(format!("Operador relacional no reconocido: {:?}", cuad.operador)),
@@ -241,6 +385,24 @@ impl MaquinaVirtual {
         Ok(())
     }
 
+    /// Ejecuta operación lógica: resultado = op1 y/o op2, o resultado = no op1
+    /// (el compilador garantiza operandos booleanos; el resultado es un `Valor::Booleano`)
+    fn ejecutar_logica(&mut self, cuad: &Cuadruplo) -> Result<(), String> {
+        let valor1 = self.leer_operando(&cuad.operando_izq)?.a_entero() != 0;
+        let resultado_dir = self.extraer_direccion(&cuad.resultado)?;
+
+        let resultado = match cuad.operador {
+            OperadorCuadruplo::No => !valor1,
+            OperadorCuadruplo::Y => valor1 && self.leer_operando(&cuad.operando_der)?.a_entero() != 0,
+            OperadorCuadruplo::O => valor1 || self.leer_operando(&cuad.operando_der)?.a_entero() != 0,
+            _ => return Err(format!("Operador lógico no reconocido: {:?}", cuad.operador)),
+        };
+
+        self.escribir_memoria(resultado_dir, Valor::Booleano(resultado))?;
+
+        Ok(())
+    }
+
     /// Ejecuta asignación: destino = fuente
     fn ejecutar_asignacion(&mut self, cuad: &Cuadruplo) -> Result<(), String> {
         let valor = self.leer_operando(&cuad.operando_izq)?;
@@ -289,6 +451,41 @@ impl MaquinaVirtual {
         Ok(())
     }
 
+    /// Ejecuta VER: verifica que 0 <= índice < dimensión antes de acceder al arreglo
+    fn ejecutar_verifica(&mut self, cuad: &Cuadruplo) -> Result<(), String> {
+        let indice = self.leer_operando(&cuad.operando_izq)?.a_entero();
+        let dimension = self.leer_operando(&cuad.operando_der)?.a_entero();
+
+        if indice < 0 || indice >= dimension {
+            let arreglo = match &cuad.resultado {
+                Operando::Variable(nombre) => nombre.as_str(),
+                _ => "?",
+            };
+            return Err(format!(
+                "Error de ejecución: Índice {} fuera de rango para el arreglo '{}' (rango válido: 0 a {})",
+                indice, arreglo, dimension - 1
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Ejecuta VERPASO: un ciclo `para` con paso 0 nunca terminaría
+    fn ejecutar_verifica_paso(&mut self, cuad: &Cuadruplo) -> Result<(), String> {
+        if self.leer_operando(&cuad.operando_izq)?.a_entero() == 0 {
+            let variable = match &cuad.resultado {
+                Operando::Variable(nombre) => nombre.as_str(),
+                _ => "?",
+            };
+            return Err(format!(
+                "Error de ejecución: El paso del ciclo para de '{}' vale 0",
+                variable
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Ejecuta escritura a consola
     fn ejecutar_escribe(&mut self, cuad: &Cuadruplo) -> Result<(), String> {
         let valor = self.leer_operando(&cuad.resultado)?;
@@ -296,13 +493,9 @@ impl MaquinaVirtual {
         let msg = match valor {
             Valor::Entero(v) => v.to_string(),
             Valor::Flotante(v) => v.to_string(),
-            Valor::Letrero(idx) => {
-                // Buscar el string en la tabla
-                self.tabla_strings.get(idx)
-                    .ok_or(format!("Error: Índice de string inválido: {}", idx))?
-                    .trim_matches('"')
-                    .to_string()
-            },
+            Valor::Char(c) => c.to_string(),
+            Valor::Letrero(s) => s.to_string(),
+            Valor::Booleano(b) => if b { "verdadero" } else { "falso" }.to_string(),
         };
 
         self.io.escribir_linea(&msg);
@@ -310,27 +503,62 @@ impl MaquinaVirtual {
         Ok(())
     }
 
+    /// Letrero `idx` de la tabla de strings
+    fn letrero(&self, idx: usize) -> Result<Rc<str>, String> {
+        self.letreros.get(idx)
+            .cloned()
+            .ok_or(format!("Error: Índice de string inválido: {}", idx))
+    }
+
     /// Ejecuta lectura desde entrada
+    ///
+    /// El tipo esperado se deduce del rango de la dirección destino, de modo que
+    /// leer `3.5` en una variable `entero` es un error de ejecución.
     fn ejecutar_lectura(&mut self, cuad: &Cuadruplo) -> Result<(), String> {
         let destino_dir = self.extraer_direccion(&cuad.resultado)?;
+        let tipo_destino = self.distribucion.tipo_de(destino_dir)?;
 
-        // Leer línea de entrada
+        // Leer línea de entrada (un char conserva los espacios: solo se quita el salto de línea)
         let linea = self.io.leer_linea()?;
+        let texto = if tipo_destino == TipoDato::Char {
+            linea.trim_end_matches(['\r', '\n'])
+        } else {
+            linea.trim()
+        };
 
-        // Intentar parsear como entero primero
-        if let Ok(valor_entero) = linea.parse::<i32>() {
-            self.escribir_memoria(destino_dir, Valor::Entero(valor_entero))?;
-            return Ok(());
-        }
+        let valor = match tipo_destino {
+            TipoDato::Entero => texto.parse::<i32>()
+                .map(Valor::Entero)
+                .map_err(|_| format!(
+                    "Error de ejecución: Se esperaba un valor entero en lectura, se recibió '{}'",
+                    texto
+                ))?,
+            TipoDato::Flotante => texto.parse::<f64>()
+                .map(Valor::Flotante)
+                .map_err(|_| format!(
+                    "Error de ejecución: Se esperaba un valor flotante en lectura, se recibió '{}'",
+                    texto
+                ))?,
+            TipoDato::Char => {
+                let mut chars = texto.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Valor::Char(c),
+                    _ => return Err(format!(
+                        "Error de ejecución: Se esperaba un solo carácter en lectura, se recibió '{}'",
+                        texto
+                    )),
+                }
+            },
+            otro => return Err(format!(
+                "Error de ejecución: Lectura no soportada para variables de tipo {:?}",
+                otro
+            )),
+        };
 
-        // Intentar parsear como flotante
-        if let Ok(valor_flotante) = linea.parse::<f64>() {
-            self.escribir_memoria(destino_dir, Valor::Flotante(valor_flotante))?;
-            return Ok(());
-        }
+        self.escribir_memoria(destino_dir, valor)
+    }
 
-        Err(format!("No se pudo convertir '{}' a número", linea))
-    }    /// Ejecuta ERA: crea registro de activación temporal
+    /// Ejecuta ERA: crea registro de activación temporal
     fn ejecutar_era(&mut self, cuad: &Cuadruplo) -> Result<(), String> {
         let nombre_funcion = match &cuad.operando_izq {
             Operando::Variable(nombre) => nombre.clone(),
@@ -338,7 +566,7 @@ impl MaquinaVirtual {
         };
 
         // Crear marco temporal para la función que será llamada
-        self.marco_temporal = Some(MarcoMemoria::new(nombre_funcion, self.ip + 1));
+        self.marcos_pendientes.push(MarcoMemoria::new(nombre_funcion, self.ip + 1));
 
         Ok(())
     }
@@ -347,15 +575,19 @@ impl MaquinaVirtual {
     fn ejecutar_parametro(&mut self, cuad: &Cuadruplo) -> Result<(), String> {
         let valor = self.leer_operando(&cuad.operando_izq)?;
 
-        let param_num = match &cuad.resultado {
-            Operando::ConstanteEntera(n) => *n as usize,
-            _ => return Err("Parametro requiere número de parámetro en resultado".to_string()),
+        // El resultado es la dirección local del parámetro formal en la función llamada
+        let dir_param = match &cuad.resultado {
+            Operando::Direccion(dir) => *dir,
+            _ => return Err("Parametro requiere la dirección del parámetro en resultado".to_string()),
         };
+        let (tipo_segmento, offset) = self.distribucion.traducir(dir_param)?;
+        if tipo_segmento != TipoSegmento::Local {
+            return Err(format!("Parametro: la dirección {} no es local", dir_param));
+        }
+        let valor = ajustar_a_tipo(valor, self.distribucion.tipo_de(dir_param)?, dir_param)?;
 
         // Agregarlo al marco temporal
-        if let Some(ref mut marco) = self.marco_temporal {
-            // Escribir en la dirección local correspondiente del marco temporal
-            let offset = param_num;
+        if let Some(marco) = self.marcos_pendientes.last_mut() {
             marco.memoria_local.escribir_valor(offset, valor);
         } else {
             return Err("No hay marco temporal (falta Era antes de Parametro)".to_string());
@@ -377,7 +609,7 @@ impl MaquinaVirtual {
             .clone();
 
         // Tomar el marco temporal y configurarlo con IP de retorno
-        let mut marco = self.marco_temporal.take()
+        let mut marco = self.marcos_pendientes.pop()
             .ok_or("No hay marco temporal (falta Era antes de GoSub)")?;
 
         // Guardar el IP de retorno (siguiente instrucción después del GOSUB)
@@ -447,67 +679,641 @@ impl MaquinaVirtual {
         Err("RETURN: No se encontró ENDFUNC correspondiente".to_string())
     }
 
-    /// Lee un valor de memoria (resuelve segmento y offset)
-    fn leer_memoria(&self, dir: usize) -> Result<Valor, String> {
-        let (tipo_segmento, offset) = traducir_direccion(dir)?;
-
+    /// Segmento de memoria que contiene una dirección (local y temporal del marco activo)
+    fn segmento(&self, tipo_segmento: TipoSegmento) -> Result<&SegmentoMemoria, String> {
         match tipo_segmento {
-            TipoSegmento::Global => {
-                // Asumimos que son enteros por defecto, ajustar según necesidad
-                self.memoria_global.leer_valor(offset, false)
-            },
-            TipoSegmento::Local => {
-                let marco = self.pila_marcos.last()
-                    .ok_or("No hay marco activo para leer memoria local")?;
-                marco.memoria_local.leer_valor(offset, false)
-            },
-            TipoSegmento::Temporal => {
+            TipoSegmento::Global => Ok(&self.memoria_global),
+            TipoSegmento::Constante => Ok(&self.memoria_constantes),
+            TipoSegmento::Local | TipoSegmento::Temporal => {
                 let marco = self.pila_marcos.last()
-                    .ok_or("No hay marco activo para leer memoria temporal")?;
-                marco.memoria_temporal.leer_valor(offset, false)
-            },
-            TipoSegmento::Constante => {
-                self.memoria_constantes.leer_valor(offset, false)
+                    .ok_or("No hay marco activo para leer memoria local/temporal")?;
+                Ok(if tipo_segmento == TipoSegmento::Local {
+                    &marco.memoria_local
+                } else {
+                    &marco.memoria_temporal
+                })
             },
         }
     }
 
-    /// Escribe un valor en memoria (resuelve segmento y offset)
-    fn escribir_memoria(&mut self, dir: usize, valor: Valor) -> Result<(), String> {
-        let (tipo_segmento, offset) = traducir_direccion(dir)?;
-
+    /// Versión mutable de `segmento`
+    fn segmento_mut(&mut self, tipo_segmento: TipoSegmento) -> Result<&mut SegmentoMemoria, String> {
         match tipo_segmento {
-            TipoSegmento::Global => {
-                self.memoria_global.escribir_valor(offset, valor);
-            },
-            TipoSegmento::Local => {
-                let marco = self.pila_marcos.last_mut()
-                    .ok_or("No hay marco activo para escribir memoria local")?;
-                marco.memoria_local.escribir_valor(offset, valor);
-            },
-            TipoSegmento::Temporal => {
+            TipoSegmento::Global => Ok(&mut self.memoria_global),
+            TipoSegmento::Constante => Ok(&mut self.memoria_constantes),
+            TipoSegmento::Local | TipoSegmento::Temporal => {
                 let marco = self.pila_marcos.last_mut()
-                    .ok_or("No hay marco activo para escribir memoria temporal")?;
-                marco.memoria_temporal.escribir_valor(offset, valor);
-            },
-            TipoSegmento::Constante => {
-                // No se debería escribir en constantes, pero lo permitimos por flexibilidad
-                self.memoria_constantes.escribir_valor(offset, valor);
+                    .ok_or("No hay marco activo para escribir memoria local/temporal")?;
+                Ok(if tipo_segmento == TipoSegmento::Local {
+                    &mut marco.memoria_local
+                } else {
+                    &mut marco.memoria_temporal
+                })
             },
         }
+    }
+
+    /// Lee un valor de memoria (resuelve segmento, offset y tipo)
+    ///
+    /// El tipo se decodifica del rango de la dirección con la misma
+    /// `DistribucionMemoria` que usó el generador.
+    fn leer_memoria(&self, dir: usize) -> Result<Valor, String> {
+        let (tipo_segmento, offset) = self.distribucion.traducir(dir)?;
+        let tipo = self.distribucion.tipo_de(dir)?;
+
+        self.segmento(tipo_segmento)?
+            .leer_valor(offset, tipo)
+            .ok_or_else(|| format!(
+                "Error de ejecución: Lectura de la dirección {} ({} {:?}) sin inicializar",
+                dir, tipo, tipo_segmento
+            ))
+    }
+
+    /// Escribe un valor en memoria (resuelve segmento, offset y tipo)
+    fn escribir_memoria(&mut self, dir: usize, valor: Valor) -> Result<(), String> {
+        let (tipo_segmento, offset) = self.distribucion.traducir(dir)?;
+        let valor = ajustar_a_tipo(valor, self.distribucion.tipo_de(dir)?, dir)?;
+
+        // No se debería escribir en constantes, pero lo permitimos por flexibilidad
+        self.segmento_mut(tipo_segmento)?.escribir_valor(offset, valor);
 
         Ok(())
     }
 
     /// Carga una constante en memoria (llamar antes de ejecutar)
     pub fn cargar_constante(&mut self, direccion: usize, valor: Valor) -> Result<(), String> {
-        let (tipo_segmento, offset) = traducir_direccion(direccion)?;
+        let (tipo_segmento, offset) = self.distribucion.traducir(direccion)?;
 
         if tipo_segmento != TipoSegmento::Constante {
             return Err("Solo se pueden cargar constantes en el segmento de constantes".to_string());
         }
 
+        let valor = ajustar_a_tipo(valor, self.distribucion.tipo_de(direccion)?, direccion)?;
         self.memoria_constantes.escribir_valor(offset, valor);
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::io::MockIO;
+    use crate::{lexico, sintactico};
+
+    /// Compila un programa fuente y lo carga en una VM con la entrada dada
+    fn preparar_vm(fuente: &str, entrada: &[&str]) -> MaquinaVirtual {
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+        let generador = sintactico::analyze(&tokens, 0).unwrap();
+        let programa = generador.exportar_programa("prueba".to_string()).unwrap();
+
+        let io = MockIO::new(entrada.iter().map(|s| s.to_string()).collect());
+        let mut vm = MaquinaVirtual::new(Box::new(io));
+        vm.cargar_programa(programa).unwrap();
+        vm
+    }
+
+    #[test]
+    fn test_lectura_multiple_en_orden() {
+        let fuente = "programa p; vars a, b, c : entero; inicio { lee(a, b, c); } fin";
+        let mut vm = preparar_vm(fuente, &["1", "2", "3"]);
+        vm.ejecutar().unwrap();
+
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(1));
+        assert_eq!(vm.leer_memoria(1001).unwrap(), Valor::Entero(2));
+        assert_eq!(vm.leer_memoria(1002).unwrap(), Valor::Entero(3));
+    }
+
+    #[test]
+    fn test_lectura_rechaza_flotante_en_entero() {
+        let fuente = "programa p; vars a : entero; inicio { lee(a); } fin";
+        let mut vm = preparar_vm(fuente, &["3.5"]);
+
+        let error = vm.ejecutar().unwrap_err();
+        assert!(error.contains("entero"));
+        assert!(error.contains("3.5"));
+    }
+
+    #[test]
+    fn test_lectura_variable_no_declarada() {
+        let fuente = "programa p; vars a : entero; inicio { lee(a, z); } fin";
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+
+        let error = sintactico::analyze(&tokens, 0).err().unwrap();
+        assert_eq!(error.len(), 1);
+        assert!(error[0].mensaje.contains("'z' no declarada"));
+    }
+
+    #[test]
+    fn test_arreglos_lectura_escritura() {
+        let fuente = "programa p; vars v[3], m[2][3], x : entero; inicio { \
+                      v[0] = 4; v[2] = v[0] * 2; m[1][2] = v[2] + 1; x = m[1][2]; } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        // v: 1000-1002, m: 1003-1008 (m[1][2] = base + 1*3 + 2), x: 1009
+        assert_eq!(vm.leer_memoria(1002).unwrap(), Valor::Entero(8));
+        assert_eq!(vm.leer_memoria(1008).unwrap(), Valor::Entero(9));
+        assert_eq!(vm.leer_memoria(1009).unwrap(), Valor::Entero(9));
+    }
+
+    #[test]
+    fn test_arreglo_indice_fuera_de_rango() {
+        let fuente = "programa p; vars v[3], x : entero; inicio { x = 3; v[x] = 1; } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+
+        let error = vm.ejecutar().unwrap_err();
+        assert!(error.contains("'v'"));
+        assert!(error.contains("Índice 3"));
+        // No debe escribir en la variable vecina
+        assert_eq!(vm.leer_memoria(1003).unwrap(), Valor::Entero(3));
+    }
+
+    #[test]
+    fn test_arreglo_sin_indices_es_error_semantico() {
+        let fuente = "programa p; vars v[3], x : entero; inicio { x = v; } fin";
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+
+        let error = sintactico::analyze(&tokens, 0).err().unwrap();
+        assert_eq!(error.len(), 1);
+        assert!(error[0].mensaje.contains("debe usarse con índices"));
+    }
+
+    #[test]
+    fn test_logicos_cortocircuito() {
+        // Si se evaluara el lado derecho habría división por cero
+        let fuente = "programa p; vars x, a, b : entero; vars c : booleano; inicio { \
+                      x = 0; a = 5; b = 5; c = falso; \
+                      si (x != 0 y 10 / x > 1) entonces { a = 1; } sino { a = 2; }; \
+                      si (x == 0 o 10 / x > 1) entonces { b = 1; } sino { b = 2; }; \
+                      c = no (x > 0) y (x < 1 o x > 5); } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+
+        // Cortocircuito con saltos, sin cuádruplos y/o que evalúen ambos lados
+        let operadores: Vec<_> = vm.cuadruplos.iter().map(|c| c.operador).collect();
+        assert!(operadores.contains(&OperadorCuadruplo::GotoV));
+        assert!(operadores.contains(&OperadorCuadruplo::No));
+        assert!(!operadores.contains(&OperadorCuadruplo::Y));
+        assert!(!operadores.contains(&OperadorCuadruplo::O));
+
+        vm.ejecutar().unwrap();
+
+        // x: 1000, a: 1001, b: 1002, c: 7000 (rango booleano global)
+        assert_eq!(vm.leer_memoria(1001).unwrap(), Valor::Entero(2));
+        assert_eq!(vm.leer_memoria(1002).unwrap(), Valor::Entero(1));
+        assert_eq!(vm.leer_memoria(7000).unwrap(), Valor::Booleano(true));
+    }
+
+    #[test]
+    fn test_logicos_precedencia_en_ciclo() {
+        // y liga más fuerte que o: i < 3 o (i < 10 y no (i == 4))
+        let fuente = "programa p; vars i, n : entero; inicio { \
+                      i = 0; n = 0; \
+                      mientras (i < 3 o i < 10 y no i == 4) haz { n = n + 1; i = i + 1; } } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(4));
+        assert_eq!(vm.leer_memoria(1001).unwrap(), Valor::Entero(4));
+    }
+
+    #[test]
+    fn test_logicos_requieren_booleanos() {
+        for fuente in [
+            "programa p; vars f : flotante; inicio { f = 1.5; si (f y 1) entonces { f = 2.0; }; } fin",
+            "programa p; vars n : entero; inicio { n = 1; si (n o n > 0) entonces { n = 2; }; } fin",
+            "programa p; vars n : entero; inicio { n = 1; si (no n) entonces { n = 2; }; } fin",
+        ] {
+            let tokens = lexico::analyze(fuente, 0).unwrap();
+
+            let error = sintactico::analyze(&tokens, 0).err().unwrap();
+            assert_eq!(error.len(), 1, "{}", fuente);
+            assert!(error[0].mensaje.contains("tipos incompatibles"), "{}", fuente);
+        }
+    }
+
+    #[test]
+    fn test_para_ascendente_y_descendente() {
+        // El límite se evalúa una sola vez aunque n cambie dentro del cuerpo
+        let fuente = "programa p; vars i, n, suma, cuenta : entero; inicio { \
+                      n = 4; suma = 0; cuenta = 0; \
+                      para i = 1 hasta n haz { suma = suma + i; n = n + 1; }; \
+                      para i = 10 hasta 1 paso -3 haz { cuenta = cuenta + i; }; } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        // i: 1000, n: 1001, suma: 1002, cuenta: 1003
+        assert_eq!(vm.leer_memoria(1002).unwrap(), Valor::Entero(10));
+        assert_eq!(vm.leer_memoria(1003).unwrap(), Valor::Entero(10 + 7 + 4 + 1));
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(-2));
+    }
+
+    #[test]
+    fn test_para_anidado_sin_iteraciones() {
+        let fuente = "programa p; vars i, j, n : entero; inicio { n = 0; \
+                      para i = 1 hasta 3 haz { para j = i hasta 3 haz { n = n + 1; }; }; \
+                      para i = 5 hasta 1 haz { n = 100; }; } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        assert_eq!(vm.leer_memoria(1002).unwrap(), Valor::Entero(6));
+    }
+
+    #[test]
+    fn test_para_paso_grande_y_limite_maximo() {
+        // Sin multiplicaciones: un paso grande no desborda al evaluar la condición,
+        // y con el límite en i32::MAX el último incremento no se ejecuta
+        let fuente = "programa p; vars i, n, a, b, c : entero; inicio { a = 0; b = 0; c = 0; \
+                      para i = 0 hasta 100000 paso 50000 haz { a = a + 1; }; \
+                      para i = 2147483630 hasta 2147483647 paso 10 haz { b = b + 1; }; \
+                      n = 0 - 1000000000; \
+                      para i = 0 - 2000000000 hasta 0 - 2147483647 paso n haz { c = c + 1; }; } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        // i: 1000, n: 1001, a: 1002, b: 1003, c: 1004
+        assert_eq!(vm.leer_memoria(1002).unwrap(), Valor::Entero(3));
+        assert_eq!(vm.leer_memoria(1003).unwrap(), Valor::Entero(2));
+        assert_eq!(vm.leer_memoria(1004).unwrap(), Valor::Entero(1));
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(-2000000000));
+    }
+
+    #[test]
+    fn test_para_paso_cero() {
+        // Una constante 0 se rechaza al compilar
+        let fuente = "programa p; vars i : entero; inicio { para i = 1 hasta 3 paso 0 haz { escribe(i); }; } fin";
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+        let error = sintactico::analyze(&tokens, 0).err().unwrap();
+        assert!(error[0].mensaje.contains("El paso del ciclo para de 'i' no puede ser 0"), "{}", error[0].mensaje);
+
+        // Un paso que vale 0 al ejecutar es error de ejecución en lugar de un ciclo infinito
+        let fuente = "programa p; vars i, n : entero; inicio { n = 0; para i = 1 hasta 3 paso n haz { escribe(i); }; } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        let error = vm.ejecutar().unwrap_err();
+        assert!(error.contains("El paso del ciclo para de 'i' vale 0"), "{}", error);
+    }
+
+    #[test]
+    fn test_para_variable_de_control_entera() {
+        let fuente = "programa p; vars f : flotante; inicio { para f = 1 hasta 3 haz { escribe(f); }; } fin";
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+
+        let error = sintactico::analyze(&tokens, 0).err().unwrap();
+        assert_eq!(error.len(), 1);
+        assert!(error[0].mensaje.contains("variable de control 'f'"));
+        // La nota habla del ciclo, no del cubo semántico
+        assert_eq!(error[0].codigo, crate::diagnostico::codigos::TIPOS_INCOMPATIBLES);
+        assert_eq!(error[0].notas, ["la variable de control de un ciclo para debe declararse entero"]);
+        let span = error[0].span.unwrap();
+        assert_eq!(&fuente[span.inicio..span.fin], "f");
+
+        let fuente = "programa p; vars i : entero; inicio { para i = 1 hasta 2.5 haz { escribe(i); }; } fin";
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+        let error = sintactico::analyze(&tokens, 0).err().unwrap();
+        assert_eq!(error[0].notas, ["el límite de un ciclo para debe ser una expresión entera"]);
+        let span = error[0].span.unwrap();
+        assert_eq!(&fuente[span.inicio..span.fin], "2.5");
+    }
+
+    #[test]
+    fn test_rompe_y_continua_en_mientras() {
+        // Suma 1..10 saltando el 3 y deteniéndose al llegar a 6: 1 + 2 + 4 + 5 = 12
+        let fuente = "programa p; vars i, suma : entero; inicio { i = 0; suma = 0; \
+                      mientras (i < 10) haz { i = i + 1; \
+                      si (i == 3) entonces { continua; }; \
+                      si (i == 6) entonces { rompe; }; \
+                      suma = suma + i; } } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(6));
+        assert_eq!(vm.leer_memoria(1001).unwrap(), Valor::Entero(12));
+    }
+
+    #[test]
+    fn test_continua_en_para_ejecuta_incremento() {
+        // continua debe pasar por el incremento (si no, sería un ciclo infinito)
+        let fuente = "programa p; vars i, j, n : entero; inicio { n = 0; \
+                      para i = 1 hasta 4 haz { \
+                      para j = 1 hasta 4 haz { si (j == 2) entonces { continua; }; \
+                      si (j == 4) entonces { rompe; }; n = n + 1; }; \
+                      si (i == 3) entonces { rompe; }; }; } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        // Cada ciclo interno cuenta j = 1, 3; el externo se rompe en i = 3
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(3));
+        assert_eq!(vm.leer_memoria(1002).unwrap(), Valor::Entero(6));
+    }
+
+    #[test]
+    fn test_flotantes_globales_y_locales() {
+        let fuente = "programa p; vars x : flotante; vars n : entero; \
+                      flotante mitad(v : flotante, k : entero) { vars w : flotante; \
+                      w = v / 2.0; regresa w + k; }; \
+                      inicio { x = 7; n = 2; x = mitad(x, n); } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        // Las direcciones 3000+ son flotantes aunque se les asigne un entero
+        assert_eq!(vm.leer_memoria(3000).unwrap(), Valor::Flotante(5.5));
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(2));
+    }
+
+    #[test]
+    fn test_llamadas_anidadas_con_parametros() {
+        let fuente = "programa p; vars r : entero; \
+                      entero resta(a : entero, b : entero) { regresa a - b; }; \
+                      inicio { r = resta(resta(20, 5), resta(4, 1)); } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(12));
+    }
+
+    #[test]
+    fn test_lectura_sin_inicializar() {
+        let fuente = "programa p; vars a : entero; vars f : flotante; inicio { escribe(f); } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+
+        let error = vm.ejecutar().unwrap_err();
+        assert!(error.contains("dirección 3000"));
+        assert!(error.contains("sin inicializar"));
+    }
+
+    #[test]
+    fn test_escritura_verifica_tipo_de_la_celda() {
+        let fuente = "programa p; vars a : entero; inicio { a = 1; } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+
+        let error = vm.escribir_memoria(1000, Valor::Flotante(1.5)).unwrap_err();
+        assert!(error.contains("un valor flotante"));
+        assert!(error.contains("dirección 1000 de tipo entero"));
+
+        vm.escribir_memoria(3000, Valor::Entero(2)).unwrap();
+        assert_eq!(vm.leer_memoria(3000).unwrap(), Valor::Flotante(2.0));
+        assert!(vm.leer_memoria(5000).is_err());
+
+        // Booleanos y letreros tienen su propio rango: no caben en celdas entero/char
+        let error = vm.escribir_memoria(1000, Valor::Booleano(true)).unwrap_err();
+        assert!(error.contains("dirección 1000 de tipo entero"));
+        assert!(vm.escribir_memoria(5000, Valor::Letrero("a".into())).is_err());
+        assert!(vm.escribir_memoria(7000, Valor::Entero(1)).is_err());
+        vm.escribir_memoria(7000, Valor::Booleano(true)).unwrap();
+        assert_eq!(vm.leer_memoria(7000).unwrap(), Valor::Booleano(true));
+    }
+
+    #[test]
+    fn test_distribucion_configurable_con_muchas_globales() {
+        let fuente = "programa p; vars v[3000], x : entero; inicio { v[2999] = 7; x = v[2999] + 1; } fin";
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+
+        // Con 2000 casillas por tipo el arreglo no cabe
+        assert!(sintactico::analyze(&tokens, 0).is_err());
+
+        let distribucion = DistribucionMemoria::default().con_casillas(TipoSegmento::Global, 5000);
+        let generador = sintactico::analyze_con_distribucion(&tokens, 0, distribucion).unwrap();
+        let programa = generador.exportar_programa("prueba".to_string()).unwrap();
+        assert_eq!(programa.distribucion, distribucion);
+
+        let mut vm = MaquinaVirtual::con_distribucion(Box::new(MockIO::new(vec![])), distribucion);
+        vm.cargar_programa(programa).unwrap();
+        vm.ejecutar().unwrap();
+
+        // v: 1000-3999, x: 4000; los temporales empiezan después de los segmentos
+        // global (5 tipos × 5000) y local (5 tipos × 2000)
+        assert_eq!(vm.leer_memoria(4000).unwrap(), Valor::Entero(8));
+        assert_eq!(distribucion.inicio_segmento(TipoSegmento::Temporal), 36000);
+    }
+
+    #[test]
+    fn test_rechaza_programa_con_otra_distribucion() {
+        let fuente = "programa p; vars a : entero; inicio { a = 1; } fin";
+        let tokens = lexico::analyze(fuente, 0).unwrap();
+        let distribucion = DistribucionMemoria::default().con_casillas(TipoSegmento::Local, 4000);
+        let programa = sintactico::analyze_con_distribucion(&tokens, 0, distribucion).unwrap()
+            .exportar_programa("prueba".to_string()).unwrap();
+
+        let mut vm = MaquinaVirtual::new(Box::new(MockIO::new(vec![])));
+        let error = vm.cargar_programa(programa).unwrap_err();
+        assert!(error.contains("local=4000"));
+        assert!(error.contains("local=2000"));
+    }
+
+    #[test]
+    fn test_char_variables_comparacion_y_escritura() {
+        let fuente = "programa p; vars c, d : char; vars menor : entero; inicio { \
+                      c = 'b'; d = '\\''; lee(d); \
+                      si (c > 'a' y d == 'z') entonces { menor = 1; } sino { menor = 0; }; \
+                      escribe(c, '\\n', d); } fin";
+        let mut vm = preparar_vm(fuente, &["z"]);
+        vm.ejecutar().unwrap();
+
+        // c: 5000, d: 5001 (rango char global)
+        assert_eq!(vm.leer_memoria(5000).unwrap(), Valor::Char('b'));
+        assert_eq!(vm.leer_memoria(5001).unwrap(), Valor::Char('z'));
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(1));
+    }
+
+    #[test]
+    fn test_char_no_se_mezcla_con_numeros() {
+        for fuente in [
+            "programa p; vars c : char; inicio { c = 1; } fin",
+            "programa p; vars n : entero; inicio { n = 'a'; } fin",
+            "programa p; vars c : char; inicio { c = 'a' + 'b'; } fin",
+            "programa p; vars n : entero; inicio { n = 1; si ('a' == n) entonces { n = 2; }; } fin",
+        ] {
+            let tokens = lexico::analyze(fuente, 0).unwrap();
+            assert!(sintactico::analyze(&tokens, 0).is_err(), "{}", fuente);
+        }
+    }
+
+    #[test]
+    fn test_lectura_char_rechaza_varios_caracteres() {
+        let fuente = "programa p; vars c : char; inicio { lee(c); } fin";
+        let mut vm = preparar_vm(fuente, &["ab"]);
+
+        assert!(vm.ejecutar().unwrap_err().contains("un solo carácter"));
+    }
+
+    #[test]
+    fn test_letreros_asignacion_concatenacion_y_comparacion() {
+        let fuente = "programa p; vars s, t : letrero; vars iguales : entero; \
+                      letrero envuelve(x : letrero) { regresa \"[\" + x + \"]\"; }; \
+                      inicio { s = \"ab\"; t = envuelve(s + \"c\"); \
+                      si (t == \"[abc]\" y s != t) entonces { iguales = 1; } sino { iguales = 0; }; \
+                      escribe(t); } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        // s: 9000, t: 9001 (rango letrero global)
+        assert_eq!(vm.leer_memoria(9001).unwrap(), Valor::Letrero("[abc]".into()));
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(1));
+    }
+
+    #[test]
+    fn test_concatenacion_en_ciclo_no_acumula_letreros() {
+        let fuente = "programa p; vars s : letrero; vars i : entero; inicio { \
+                      s = \"\"; i = 0; \
+                      mientras (i < 5000) haz { s = s + \"x\"; i = i + 1; } } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        let tabla_strings = vm.letreros.len();
+        vm.ejecutar().unwrap();
+
+        // Cada concatenación reemplaza a la anterior: la tabla de strings no
+        // crece y el letrero final solo lo guardan s y, a lo más, su temporal
+        assert_eq!(vm.letreros.len(), tabla_strings);
+        let Valor::Letrero(s) = vm.leer_memoria(9000).unwrap() else {
+            panic!("se esperaba un letrero");
+        };
+        assert_eq!(s.len(), 5000);
+        assert!(Rc::strong_count(&s) <= 3, "{} referencias", Rc::strong_count(&s));
+    }
+
+    #[test]
+    fn test_letreros_no_se_mezclan_con_otros_tipos() {
+        for fuente in [
+            "programa p; vars s : letrero; inicio { s = 'a'; } fin",
+            "programa p; vars c : char; inicio { c = \"a\"; } fin",
+            "programa p; vars s : letrero; inicio { s = \"a\" + 1; } fin",
+            "programa p; vars s : letrero; inicio { s = \"a\" - \"b\"; } fin",
+            "programa p; vars n : entero; inicio { n = 0; si (\"a\" < \"b\") entonces { n = 1; }; } fin",
+            "programa p; vars s : letrero; inicio { lee(s); } fin",
+            "programa p; vars n : entero; entero f(x : entero) { regresa x; }; inicio { n = f(\"a\"); } fin",
+            "programa p; vars s : letrero; letrero f(x : entero) { regresa x; }; inicio { s = f(1); } fin",
+        ] {
+            let tokens = lexico::analyze(fuente, 0).unwrap();
+            assert!(sintactico::analyze(&tokens, 0).is_err(), "{}", fuente);
+        }
+    }
+
+    #[test]
+    fn test_booleanos_variables_literales_y_escritura() {
+        let fuente = "programa p; vars b, c : booleano; vars n : entero; inicio { \
+                      n = 3; b = n > 2; c = falso; \
+                      si (b == verdadero y no c) entonces { n = 1; }; \
+                      escribe(b, c, n < 0); } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        // b: 7000, c: 7001 (rango booleano global), n: 1000
+        assert_eq!(vm.leer_memoria(7000).unwrap(), Valor::Booleano(true));
+        assert_eq!(vm.leer_memoria(7001).unwrap(), Valor::Booleano(false));
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(1));
+    }
+
+    #[test]
+    fn test_condiciones_deben_ser_booleanas() {
+        for fuente in [
+            "programa p; vars n : entero; inicio { n = 1; si (n) entonces { n = 2; }; } fin",
+            "programa p; vars n : entero; inicio { n = 1; mientras (n + 1) haz { n = 2; } } fin",
+            "programa p; vars n : entero; inicio { n = 2 > 1 + 1; } fin",
+            "programa p; vars b : booleano; inicio { b = 1; } fin",
+            "programa p; vars b : booleano; inicio { b = verdadero + falso; } fin",
+            "programa p; vars b : booleano; inicio { b = verdadero > falso; } fin",
+            "programa p; vars b : booleano; inicio { lee(b); } fin",
+        ] {
+            let tokens = lexico::analyze(fuente, 0).unwrap();
+            let error = sintactico::analyze(&tokens, 0).err().unwrap();
+            assert!(error[0].mensaje.contains("tipos incompatibles") || error[0].mensaje.contains("lee"), "{}", fuente);
+        }
+    }
+
+    #[test]
+    fn test_mayor_o_igual_y_menor_o_igual() {
+        let fuente = "programa p; vars a, b, c, d : booleano; vars n : entero; vars f : flotante; inicio { \
+                      n = 3; f = 3.0; a = n >= f; b = n <= 2; c = 'a' <= 'b'; d = f >= 3.5; } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        assert_eq!(vm.leer_memoria(7000).unwrap(), Valor::Booleano(true));
+        assert_eq!(vm.leer_memoria(7001).unwrap(), Valor::Booleano(false));
+        assert_eq!(vm.leer_memoria(7002).unwrap(), Valor::Booleano(true));
+        assert_eq!(vm.leer_memoria(7003).unwrap(), Valor::Booleano(false));
+    }
+
+    #[test]
+    fn test_asignacion_compuesta_e_incremento() {
+        let fuente = "programa p; vars i, s : entero; vars v[3] : entero; vars f : flotante; inicio { \
+                      i = 0; s = 0; mientras (i < 3) haz { v[i] = i * 10; s += v[i]; i++; } \
+                      v[1] += 5; v[2]--; s *= 2; s /= 7; s %= 5; f = 1.0; f += 1; f *= 2.5; i--; } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        // i: 1000, s: 1001, v: 1002..1004, f: 3000
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(2));
+        assert_eq!(vm.leer_memoria(1001).unwrap(), Valor::Entero(3));
+        assert_eq!(vm.leer_memoria(1003).unwrap(), Valor::Entero(15));
+        assert_eq!(vm.leer_memoria(1004).unwrap(), Valor::Entero(19));
+        assert_eq!(vm.leer_memoria(3000).unwrap(), Valor::Flotante(5.0));
+    }
+
+    #[test]
+    fn test_modulo_division_entera_y_negativo() {
+        let fuente = "programa p; vars a, b, r, d : entero; vars f : flotante; inicio { \
+                      a = -7; b = 2; r = a % b; d = a / b; f = -(7.5 % b); } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        assert_eq!(vm.leer_memoria(1002).unwrap(), Valor::Entero(-1));
+        assert_eq!(vm.leer_memoria(1003).unwrap(), Valor::Entero(-3));
+        assert_eq!(vm.leer_memoria(3000).unwrap(), Valor::Flotante(-1.5));
+    }
+
+    #[test]
+    fn test_division_y_residuo_entre_cero_indican_el_cuadruplo() {
+        for (fuente, operacion) in [
+            ("programa p; vars a, b : entero; inicio { a = 1; b = 0; a = a / b; } fin", "División"),
+            ("programa p; vars a, b : entero; inicio { a = 1; b = 0; a = a % b; } fin", "Residuo"),
+            ("programa p; vars f : flotante; inicio { f = 1.0; f = f / 0; } fin", "División"),
+        ] {
+            let mut vm = preparar_vm(fuente, &[]);
+            let error = vm.ejecutar().unwrap_err();
+
+            let indice = vm.cuadruplos.iter()
+                .position(|c| matches!(c.operador, OperadorCuadruplo::Division | OperadorCuadruplo::Modulo))
+                .unwrap();
+            assert_eq!(error, format!("Error de ejecución: {} entre cero en el cuádruplo {}", operacion, indice));
+        }
+    }
+
+    #[test]
+    fn test_aritmetica_entera_en_i32() {
+        let fuente = "programa p; vars a, b : entero; inicio {
+            a = 2147483647 - 1;
+            b = 123456 * 10000;
+        } fin";
+        let mut vm = preparar_vm(fuente, &[]);
+        vm.ejecutar().unwrap();
+
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(2147483646));
+        assert_eq!(vm.leer_memoria(1001).unwrap(), Valor::Entero(1234560000));
+    }
+
+    #[test]
+    fn test_desbordamiento_entero_es_error_o_envuelve() {
+        for (operacion, esperado) in [
+            ("a * 2", "(2147483647 * 2)"),
+            ("a + a", "(2147483647 + 2147483647)"),
+            ("-a - 2", "(-2147483647 - 2)"),
+            ("(-a - 1) / -1", "(-2147483648 / -1)"),
+            ("-(-a - 1)", "(--2147483648)"),
+        ] {
+            let fuente = format!(
+                "programa p; vars a, b : entero; inicio {{ a = 2147483647; b = {}; }} fin", operacion
+            );
+
+            let mut vm = preparar_vm(&fuente, &[]);
+            let error = vm.ejecutar().unwrap_err();
+            assert!(error.starts_with("Error de ejecución: Desbordamiento de entero en el cuádruplo"), "{}", error);
+            assert!(error.ends_with(esperado), "{}", error);
+
+            let mut vm = preparar_vm(&fuente, &[]).con_desbordamiento(Desbordamiento::Envolver);
+            vm.ejecutar().unwrap();
+        }
+
+        let fuente = "programa p; vars a : entero; inicio { a = 2147483647; a = a + 1; } fin";
+        let mut vm = preparar_vm(fuente, &[]).con_desbordamiento(Desbordamiento::Envolver);
+        vm.ejecutar().unwrap();
+        assert_eq!(vm.leer_memoria(1000).unwrap(), Valor::Entero(i32::MIN));
+    }
+}
@@ -17,6 +17,12 @@ pub trait SistemaIO {
 /// Implementación de E/S usando consola estándar (stdin/stdout)
 pub struct ConsolaIO;
 
+impl Default for ConsolaIO {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ConsolaIO {
     pub fn new() -> Self {
         ConsolaIO
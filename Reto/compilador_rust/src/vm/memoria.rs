@@ -1,36 +1,35 @@
 //! # Memoria
 //!
 //! Gestión de memoria segmentada para la Máquina Virtual.
-//! Maneja segmentos GLOBAL, LOCAL, TEMPORAL y CONSTANTE.
+//! Maneja segmentos GLOBAL, LOCAL, TEMPORAL y CONSTANTE; sus rangos de
+//! direcciones los define `DistribucionMemoria`.
 
 use std::collections::HashMap;
-
-/// Rangos de direcciones virtuales
-/// Deben coincidir con los rangos en memoria_virtual.rs del generador
-pub const GLOBAL_INICIO: usize = 1000;
-pub const GLOBAL_FIN: usize = 6999;
-pub const LOCAL_INICIO: usize = 7000;
-pub const LOCAL_FIN: usize = 12999;
-pub const TEMPORAL_INICIO: usize = 13000;
-pub const TEMPORAL_FIN: usize = 18999;
-pub const CONSTANTE_INICIO: usize = 19000;
-pub const CONSTANTE_FIN: usize = 24999;
-
-/// Valor en la VM (entero o flotante)
-#[derive(Debug, Clone, Copy, PartialEq)]
+use std::rc::Rc;
+use crate::semantico::tipos::TipoDato;
+
+/// Valor en la VM (entero, flotante, char, booleano o letrero)
+///
+/// Un letrero comparte su contenido por conteo de referencias: copiarlo entre
+/// celdas no duplica el texto y se libera cuando ninguna celda lo guarda.
+#[derive(Debug, Clone, PartialEq)]
 pub enum Valor {
     Entero(i32),
     Flotante(f64),
-    Letrero(usize),
+    Char(char),
+    Letrero(Rc<str>),
+    Booleano(bool),
 }
 
 impl Valor {
-    /// Convierte a entero (con cast si es flotante)
+    /// Convierte a entero (con cast si es flotante; un char da su código y un booleano 0 o 1)
     pub fn a_entero(&self) -> i32 {
         match self {
             Valor::Entero(v) => *v,
             Valor::Flotante(v) => *v as i32,
+            Valor::Char(c) => *c as i32,
             Valor::Letrero(_) => 0, // Placeholder
+            Valor::Booleano(b) => *b as i32,
         }
     }
 
@@ -39,7 +38,20 @@ impl Valor {
         match self {
             Valor::Entero(v) => *v as f64,
             Valor::Flotante(v) => *v,
+            Valor::Char(c) => *c as u32 as f64,
             Valor::Letrero(_) => 0.0, // Placeholder
+            Valor::Booleano(b) => *b as i32 as f64,
+        }
+    }
+
+    /// Describe el valor para mensajes de error (`un valor entero`, ...)
+    pub fn descripcion(&self) -> &'static str {
+        match self {
+            Valor::Entero(_) => "un valor entero",
+            Valor::Flotante(_) => "un valor flotante",
+            Valor::Char(_) => "un valor char",
+            Valor::Letrero(_) => "un letrero",
+            Valor::Booleano(_) => "un valor booleano",
         }
     }
 
@@ -58,19 +70,15 @@ impl Valor {
         Err(format!("No se puede convertir '{}' a valor numérico", s))
     }
 
-    /// Aplica operación aritmética entre dos valores
+    /// Aplica una operación aritmética en punto flotante
+    ///
+    /// Es el caso con al menos un operando flotante; entre dos enteros la VM
+    /// opera en `i32` para detectar desbordamientos
     pub fn operar_aritmetica<F>(&self, otro: &Valor, op: F) -> Valor
     where
         F: Fn(f64, f64) -> f64,
     {
-        let resultado = op(self.a_flotante(), otro.a_flotante());
-
-        // Si ambos son enteros, devolver entero
-        if matches!(self, Valor::Entero(_)) && matches!(otro, Valor::Entero(_)) {
-            Valor::Entero(resultado as i32)
-        } else {
-            Valor::Flotante(resultado)
-        }
+        Valor::Flotante(op(self.a_flotante(), otro.a_flotante()))
     }
 
     /// Aplica operación relacional entre dos valores
@@ -78,8 +86,7 @@ impl Valor {
     where
         F: Fn(f64, f64) -> bool,
     {
-        let resultado = op(self.a_flotante(), otro.a_flotante());
-        Valor::Entero(if resultado { 1 } else { 0 })
+        Valor::Booleano(op(self.a_flotante(), otro.a_flotante()))
     }
 }
 
@@ -88,6 +95,15 @@ impl Valor {
 pub struct SegmentoMemoria {
     enteros: HashMap<usize, i32>,
     flotantes: HashMap<usize, f64>,
+    chars: HashMap<usize, char>,
+    booleanos: HashMap<usize, bool>,
+    letreros: HashMap<usize, Rc<str>>,
+}
+
+impl Default for SegmentoMemoria {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SegmentoMemoria {
@@ -95,48 +111,67 @@ impl SegmentoMemoria {
         SegmentoMemoria {
             enteros: HashMap::new(),
             flotantes: HashMap::new(),
+            chars: HashMap::new(),
+            booleanos: HashMap::new(),
+            letreros: HashMap::new(),
         }
     }
 
-    pub fn leer_entero(&self, offset: usize) -> Result<i32, String> {
-        self.enteros
-            .get(&offset)
-            .copied()
-            .ok_or_else(|| format!("Error: Dirección {} no inicializada (entero)", offset))
-    }
-
-    pub fn leer_flotante(&self, offset: usize) -> Result<f64, String> {
-        self.flotantes
-            .get(&offset)
-            .copied()
-            .ok_or_else(|| format!("Error: Dirección {} no inicializada (flotante)", offset))
-    }
-
-    pub fn escribir_entero(&mut self, offset: usize, valor: i32) {
-        self.enteros.insert(offset, valor);
-    }
-
-    pub fn escribir_flotante(&mut self, offset: usize, valor: f64) {
-        self.flotantes.insert(offset, valor);
-    }
-
-    pub fn leer_valor(&self, offset: usize, es_flotante: bool) -> Result<Valor, String> {
-        if es_flotante {
-            Ok(Valor::Flotante(self.leer_flotante(offset)?))
-        } else {
-            Ok(Valor::Entero(self.leer_entero(offset)?))
+    /// Lee la celda `offset` como el tipo indicado
+    ///
+    /// Retorna `None` si la celda de ese tipo no se ha escrito.
+    pub fn leer_valor(&self, offset: usize, tipo: TipoDato) -> Option<Valor> {
+        match tipo {
+            TipoDato::Entero => self.enteros.get(&offset).copied().map(Valor::Entero),
+            TipoDato::Flotante => self.flotantes.get(&offset).copied().map(Valor::Flotante),
+            TipoDato::Char => self.chars.get(&offset).copied().map(Valor::Char),
+            TipoDato::Booleano => self.booleanos.get(&offset).copied().map(Valor::Booleano),
+            TipoDato::Letrero => self.letreros.get(&offset).cloned().map(Valor::Letrero),
         }
     }
 
+    /// Escribe un valor ya ajustado al tipo de su celda (ver `ajustar_a_tipo`)
     pub fn escribir_valor(&mut self, offset: usize, valor: Valor) {
         match valor {
-            Valor::Entero(v) => self.escribir_entero(offset, v),
-            Valor::Flotante(v) => self.escribir_flotante(offset, v),
-            Valor::Letrero(_) => {}, // Los letreros no se escriben en memoria, solo se imprimen
+            Valor::Entero(v) => {
+                self.enteros.insert(offset, v);
+            },
+            Valor::Flotante(v) => {
+                self.flotantes.insert(offset, v);
+            },
+            Valor::Char(c) => {
+                self.chars.insert(offset, c);
+            },
+            Valor::Booleano(b) => {
+                self.booleanos.insert(offset, b);
+            },
+            Valor::Letrero(s) => {
+                self.letreros.insert(offset, s);
+            },
         }
     }
 }
 
+/// Ajusta un valor al tipo de la celda `dir` antes de escribirlo
+///
+/// Un entero en una celda flotante se promueve (igual que `flotante = entero`
+/// en el cubo semántico). Cualquier otra combinación de tipos distintos es un
+/// error de ejecución: cada tipo tiene su propio rango de direcciones.
+pub fn ajustar_a_tipo(valor: Valor, tipo: TipoDato, dir: usize) -> Result<Valor, String> {
+    match (tipo, &valor) {
+        (TipoDato::Entero, Valor::Entero(_))
+        | (TipoDato::Flotante, Valor::Flotante(_))
+        | (TipoDato::Char, Valor::Char(_))
+        | (TipoDato::Booleano, Valor::Booleano(_))
+        | (TipoDato::Letrero, Valor::Letrero(_)) => Ok(valor),
+        (TipoDato::Flotante, Valor::Entero(v)) => Ok(Valor::Flotante(*v as f64)),
+        _ => Err(format!(
+            "Error de ejecución: No se puede guardar {} en la dirección {} de tipo {}",
+            valor.descripcion(), dir, tipo
+        )),
+    }
+}
+
 /// Marco de Memoria (Stack Frame) - contexto de ejecución de una función
 #[derive(Debug, Clone)]
 pub struct MarcoMemoria {
@@ -181,27 +216,3 @@ impl MarcoMemoria {
         self.parametros.push(valor);
     }
 }
-
-/// Traduce una dirección virtual a un segmento y offset
-pub fn traducir_direccion(dir: usize) -> Result<(TipoSegmento, usize), String> {
-    if dir >= GLOBAL_INICIO && dir <= GLOBAL_FIN {
-        Ok((TipoSegmento::Global, dir - GLOBAL_INICIO))
-    } else if dir >= LOCAL_INICIO && dir <= LOCAL_FIN {
-        Ok((TipoSegmento::Local, dir - LOCAL_INICIO))
-    } else if dir >= TEMPORAL_INICIO && dir <= TEMPORAL_FIN {
-        Ok((TipoSegmento::Temporal, dir - TEMPORAL_INICIO))
-    } else if dir >= CONSTANTE_INICIO && dir <= CONSTANTE_FIN {
-        Ok((TipoSegmento::Constante, dir - CONSTANTE_INICIO))
-    } else {
-        Err(format!("Dirección virtual {} fuera de rango", dir))
-    }
-}
-
-/// Tipo de segmento de memoria
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum TipoSegmento {
-    Global,
-    Local,
-    Temporal,
-    Constante,
-}
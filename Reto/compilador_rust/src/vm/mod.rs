@@ -4,7 +4,8 @@
 //!
 //! ## Arquitectura
 //!
-//! - **Memoria Segmentada**: GLOBAL (1000-4999), LOCAL (5000-9999), TEMPORAL (10000-14999), CONSTANTE (15000-24999)
+//! - **Memoria Segmentada**: GLOBAL, LOCAL, TEMPORAL y CONSTANTE, con los rangos de la
+//!   `DistribucionMemoria` del programa (por defecto 1000-10999, 11000-20999, 21000-30999, 31000-40999)
 //! - **Stack Frames**: Cada función tiene su propio marco de memoria con contexto local y temporal
 //! - **Tabla de Funciones**: Mapea nombres de funciones a direcciones de código
 //!
@@ -19,6 +20,7 @@ pub mod ejecutor;
 pub mod io;
 
 // Re-exportar tipos principales para facilitar el uso
-pub use memoria::{Valor, SegmentoMemoria, MarcoMemoria, TipoSegmento};
-pub use ejecutor::{MaquinaVirtual, InfoFuncion};
+pub use memoria::{Valor, SegmentoMemoria, MarcoMemoria};
+pub use crate::intermedio::distribucion::TipoSegmento;
+pub use ejecutor::{MaquinaVirtual, InfoFuncion, Desbordamiento};
 pub use io::{SistemaIO, ConsolaIO, MockIO};